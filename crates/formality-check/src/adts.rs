@@ -5,6 +5,8 @@ use formality_prove::Env;
 use formality_rust::grammar::{Adt, AdtBoundData, Field, Variant};
 use formality_types::grammar::Fallible;
 
+use crate::CheckError;
+
 impl super::Check<'_> {
     pub(super) fn check_adt(&self, adt: &Adt) -> Fallible<()> {
         let Adt { id: _, binder } = adt;
@@ -13,12 +15,17 @@ impl super::Check<'_> {
         let mut names = HashSet::new();
         for Variant { name, fields } in &adt.binder.peek().variants {
             if !names.insert((name, None)) {
-                bail!("variant \"{name:?}\" defined multiple times");
+                bail!(CheckError::DuplicateVariant {
+                    variant: name.clone()
+                });
             }
             let vname = name;
             for Field { name, ty: _ } in fields {
                 if !names.insert((vname, Some(name))) {
-                    bail!("field \"{name:?}\" of variant \"{vname:?}\" defined multiple times");
+                    bail!(CheckError::DuplicateField {
+                        variant: vname.clone(),
+                        field: name.clone(),
+                    });
                 }
             }
         }
@@ -30,6 +37,12 @@ impl super::Check<'_> {
             variants,
         } = env.instantiate_universally(binder);
 
+        self.check_dyn_compatible(
+            &env,
+            &where_clauses,
+            variants.iter().flat_map(|v| v.fields.iter().map(|f| &f.ty)),
+        )?;
+
         self.prove_where_clauses_well_formed(&env, &where_clauses, &where_clauses)?;
 
         for Variant { name: _, fields } in &variants {
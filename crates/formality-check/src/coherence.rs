@@ -1,15 +1,19 @@
-use anyhow::bail;
+use anyhow::{bail, Context};
 use fn_error_context::context;
 use formality_core::Downcasted;
 use formality_prove::Env;
-use formality_rust::grammar::{Crate, NegTraitImpl, TraitImpl};
+use formality_rust::grammar::{Crate, ImplItem, InherentImpl, NegTraitImpl, TraitImpl};
 use formality_types::grammar::{Fallible, Wc, Wcs};
 use itertools::Itertools;
 
-use crate::Check;
+use crate::{Check, CheckError, Stopped};
 
 impl Check<'_> {
-    pub(crate) fn check_coherence(&self, current_crate: &Crate) -> Fallible<()> {
+    pub(crate) fn check_coherence(
+        &self,
+        current_crate: &Crate,
+        errors: &mut Vec<anyhow::Error>,
+    ) -> Result<(), Stopped> {
         let all_crate_impls: Vec<TraitImpl> =
             self.program.items_from_all_crates().downcasted().collect();
         let current_crate_impls: Vec<TraitImpl> = current_crate.items.iter().downcasted().collect();
@@ -17,11 +21,11 @@ impl Check<'_> {
             current_crate.items.iter().downcasted().collect();
 
         for impl_a in &current_crate_impls {
-            self.orphan_check(impl_a)?;
+            self.report(self.orphan_check(impl_a), errors)?;
         }
 
         for impl_a in &current_crate_neg_impls {
-            self.orphan_check_neg(impl_a)?;
+            self.report(self.orphan_check_neg(impl_a), errors)?;
         }
 
         // check for duplicate impls in the current crate;
@@ -29,18 +33,32 @@ impl Check<'_> {
         // as overlapping with itself.
         for (impl_a, i) in current_crate_impls.iter().zip(0..) {
             if current_crate_impls[i + 1..].contains(impl_a) {
-                bail!("duplicate impl in current crate: {:?}", impl_a)
+                let error = CheckError::DuplicateImpl {
+                    impl_a: impl_a.clone(),
+                };
+                self.report(Err(error.into()), errors)?;
             }
         }
 
-        // check each impl in current crate against impls in all other crates
-        for (impl_a, impl_b) in current_crate_impls
+        // check each impl in current crate against impls in all other crates;
+        // a pair of impls in the current crate is checked only once, with the earlier impl first.
+        for ((impl_a, _), impl_b) in current_crate_impls
             .iter()
+            .zip(0..)
             .cartesian_product(&all_crate_impls)
-            .filter(|(impl_a, impl_b)| impl_a != impl_b)
-            .filter(|(impl_a, impl_b)| impl_a.trait_id() == impl_b.trait_id())
+            .filter(|((impl_a, _), impl_b)| impl_a != impl_b)
+            .filter(|((_, i), impl_b)| !current_crate_impls[..*i].contains(impl_b))
+            .filter(|((impl_a, _), impl_b)| impl_a.trait_id() == impl_b.trait_id())
         {
-            self.overlap_check(impl_a, impl_b)?;
+            self.report(self.overlap_check(impl_a, impl_b), errors)?;
+        }
+
+        // inherent impls can only be for types of the current crate,
+        // so they can only overlap with other inherent impls in the current crate.
+        let current_crate_inherent_impls: Vec<InherentImpl> =
+            current_crate.items.iter().downcasted().collect();
+        for (impl_a, impl_b) in current_crate_inherent_impls.iter().tuple_combinations() {
+            self.report(self.inherent_overlap_check(impl_a, impl_b), errors)?;
         }
 
         Ok(())
@@ -61,6 +79,9 @@ impl Check<'_> {
         // self.prove_not_goal(&env, &(Wcs::wf))
 
         self.prove_goal(&env, &a.where_clauses, trait_ref.is_local())
+            .context(CheckError::OrphanImpl {
+                impl_a: impl_a.clone(),
+            })
     }
 
     #[context("orphan_check_neg({impl_a:?})")]
@@ -71,6 +92,42 @@ impl Check<'_> {
         let trait_ref = a.trait_ref();
 
         self.prove_goal(&env, &a.where_clauses, trait_ref.is_local())
+            .context(CheckError::OrphanNegImpl {
+                impl_a: impl_a.clone(),
+            })
+    }
+
+    /// Two inherent impls may overlap as long as they do not both define an item
+    /// of the same name, like rustc's `inherent_impls_overlap` check.
+    #[tracing::instrument(level = "Debug", skip(self))]
+    fn inherent_overlap_check(&self, impl_a: &InherentImpl, impl_b: &InherentImpl) -> Fallible<()> {
+        let Some(name) = shared_item_name(impl_a, impl_b) else {
+            return Ok(());
+        };
+
+        let mut env = Env::default();
+
+        let a = env.instantiate_universally(&impl_a.binder);
+        let b = env.instantiate_universally(&impl_b.binder);
+
+        // ∀P_a, ∀P_b. ⌐ (coherence_mode => (T_a = T_b && WC_a && WC_b))
+        if let Ok(()) = self.prove_not_goal(
+            &env,
+            (),
+            (
+                Wcs::all_eq(vec![&a.self_ty], vec![&b.self_ty]),
+                &a.where_clauses,
+                &b.where_clauses,
+            ),
+        ) {
+            return Ok(());
+        }
+
+        bail!(CheckError::DuplicateInherentItem {
+            name,
+            impl_a: impl_a.clone(),
+            impl_b: impl_b.clone(),
+        })
     }
 
     #[tracing::instrument(level = "Debug", skip(self))]
@@ -155,6 +212,24 @@ impl Check<'_> {
 
             return Ok(());
         }
-        bail!("impls may overlap:\n{impl_a:?}\n{impl_b:?}")
+        bail!(CheckError::OverlappingImpls {
+            impl_a: impl_a.clone(),
+            impl_b: impl_b.clone(),
+        })
     }
 }
+
+/// The name of some item of the same kind defined by both inherent impls, if any.
+fn shared_item_name(impl_a: &InherentImpl, impl_b: &InherentImpl) -> Option<String> {
+    let items_a = &impl_a.binder.peek().impl_items;
+    let items_b = &impl_b.binder.peek().impl_items;
+    items_a.iter().find_map(|item_a| {
+        items_b.iter().find_map(|item_b| match (item_a, item_b) {
+            (ImplItem::Fn(a), ImplItem::Fn(b)) if a.id == b.id => Some(format!("{:?}", a.id)),
+            (ImplItem::AssociatedTyValue(a), ImplItem::AssociatedTyValue(b)) if a.id == b.id => {
+                Some(format!("{:?}", a.id))
+            }
+            _ => None,
+        })
+    })
+}
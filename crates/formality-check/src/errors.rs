@@ -0,0 +1,464 @@
+use formality_core::Downcast;
+use formality_prove::LangItem;
+use formality_rust::grammar::{
+    mir::{LocalId, Place},
+    FieldName, InherentImpl, NegTraitImpl, TraitImpl, VariantId,
+};
+use formality_types::grammar::{
+    AssociatedItemId, FnId, Parameter, ParameterKind, RigidName, RigidTy, TraitId, Ty, Wc,
+};
+
+/// An error found by the checker that corresponds to a rustc error code.
+///
+/// Check errors are [`anyhow::Error`]s like any other error: a `CheckError` is either
+/// the error itself or context added to the error that caused it. Use [`error_code`]
+/// to find the code of an error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckError {
+    /// Two items with the same name in a crate.
+    DuplicateDefinition {
+        /// What kind of item it is, e.g., "function".
+        kind: &'static str,
+        name: String,
+    },
+
+    /// Two associated items with the same name in a trait.
+    DuplicateAssociatedItem {
+        kind: &'static str,
+        name: String,
+    },
+
+    DuplicateVariant {
+        variant: VariantId,
+    },
+
+    DuplicateField {
+        variant: VariantId,
+        field: FieldName,
+    },
+
+    DuplicateImpl {
+        impl_a: TraitImpl,
+    },
+
+    OverlappingImpls {
+        impl_a: TraitImpl,
+        impl_b: TraitImpl,
+    },
+
+    /// The orphan check could not show that the trait or some type of the impl is local.
+    OrphanImpl {
+        impl_a: TraitImpl,
+    },
+
+    OrphanNegImpl {
+        impl_a: NegTraitImpl,
+    },
+
+    UnsafeNegImpl,
+
+    UnsafeImplOfSafeTrait {
+        trait_id: TraitId,
+    },
+
+    SafeImplOfUnsafeTrait {
+        trait_id: TraitId,
+    },
+
+    /// An explicit impl of a built-in trait for a type that gets its impls
+    /// from the prover's built-in rules (or not at all).
+    BuiltinTraitImpl {
+        lang_item: LangItem,
+        trait_id: TraitId,
+        self_ty: Parameter,
+    },
+
+    /// An impl of `Copy` for an ADT with a field whose type is not `Copy`.
+    NonCopyField {
+        trait_id: TraitId,
+        self_ty: Parameter,
+        field: FieldName,
+    },
+
+    FnNotInTrait {
+        fn_id: FnId,
+    },
+
+    /// An inherent impl for a type that is not an ADT of the current crate.
+    InherentImplForNonLocalType {
+        self_ty: Ty,
+    },
+
+    /// Two inherent impls that may apply to the same type both define an item of the same name.
+    DuplicateInherentItem {
+        name: String,
+        impl_a: InherentImpl,
+        impl_b: InherentImpl,
+    },
+
+    AssociatedTyNotInTrait {
+        item_id: AssociatedItemId,
+    },
+
+    /// An impl that gives no value for trait items without a default.
+    MissingTraitItems {
+        trait_id: TraitId,
+        missing: Vec<String>,
+    },
+
+    WrongNumberOfArguments {
+        impl_args: usize,
+        trait_args: usize,
+    },
+
+    /// An impl item whose generic parameters differ from those of the trait item.
+    GenericParametersMismatch {
+        item: String,
+        impl_kinds: Vec<ParameterKind>,
+        trait_kinds: Vec<ParameterKind>,
+    },
+
+    /// An impl item whose where-clauses are not implied by those of the trait item.
+    ImplStricterThanTrait {
+        item: String,
+    },
+
+    /// A generic associated type that lacks an outlives bound which the signature
+    /// of a fn of its trait implies, see `check_gat_required_bounds`.
+    MissingRequiredBound {
+        associated_ty: AssociatedItemId,
+        fn_id: FnId,
+        bound: Wc,
+    },
+
+    AutoTraitWithGenerics,
+
+    AutoTraitWithWhereClauses,
+
+    AutoTraitWithItems,
+
+    UseOfMoved {
+        moved: Place,
+        place: Place,
+    },
+
+    MutableBorrowOfImmutableLocal {
+        place: Place,
+        local_id: LocalId,
+    },
+
+    MutableBorrowBehindSharedRef {
+        place: Place,
+    },
+
+    /// An assignment to part of a local that is not declared as mutable.
+    AssignToImmutableLocal {
+        place: Place,
+        local_id: LocalId,
+    },
+
+    /// An assignment to a local that is not declared as mutable and may already be initialized.
+    AssignTwiceToImmutableLocal {
+        local_id: LocalId,
+    },
+
+    AssignBehindSharedRef {
+        place: Place,
+    },
+
+    UseWhileMutablyBorrowed {
+        place: Place,
+    },
+
+    /// A shared borrow conflicting with a mutable one, or the reverse.
+    ConflictingBorrow {
+        place: Place,
+        mutable: bool,
+    },
+
+    MultipleMutableBorrows {
+        place: Place,
+    },
+
+    MoveWhileBorrowed {
+        place: Place,
+    },
+
+    AssignWhileBorrowed {
+        place: Place,
+    },
+
+    DropWhileBorrowed {
+        place: Place,
+    },
+
+    /// A trait object type `dyn Trait` whose trait is not dyn-compatible.
+    DynIncompatibleTrait {
+        trait_id: TraitId,
+    },
+}
+
+impl CheckError {
+    /// The code of the rustc error that corresponds to this one, e.g., `"E0119"`,
+    /// if rustc gives it a code.
+    pub fn code(&self) -> Option<&'static str> {
+        let code = match self {
+            CheckError::DuplicateDefinition { .. } => "E0428",
+            CheckError::DuplicateAssociatedItem { .. } => "E0201",
+            CheckError::DuplicateVariant { .. } => "E0428",
+            CheckError::DuplicateField { .. } => "E0124",
+            CheckError::DuplicateImpl { .. } => "E0119",
+            CheckError::OverlappingImpls { .. } => "E0119",
+            CheckError::OrphanImpl { .. } => "E0117",
+            CheckError::OrphanNegImpl { .. } => "E0117",
+            CheckError::UnsafeNegImpl => "E0198",
+            CheckError::UnsafeImplOfSafeTrait { .. } => "E0199",
+            CheckError::SafeImplOfUnsafeTrait { .. } => "E0200",
+            CheckError::BuiltinTraitImpl { lang_item, .. } => match lang_item {
+                LangItem::Sized => "E0322",
+                LangItem::Copy => "E0206",
+                LangItem::Drop => "E0120",
+                LangItem::FnOnce | LangItem::FnMut | LangItem::Fn => "E0183",
+                // Implementing a foreign trait for a foreign type.
+                LangItem::Clone | LangItem::None => "E0117",
+            },
+            CheckError::NonCopyField { .. } => "E0204",
+            CheckError::FnNotInTrait { .. } => "E0407",
+            CheckError::InherentImplForNonLocalType { self_ty } => match self_ty.downcast() {
+                Some(RigidTy {
+                    name: RigidName::AdtId(_),
+                    ..
+                }) => "E0116",
+                _ => "E0390",
+            },
+            CheckError::DuplicateInherentItem { .. } => "E0592",
+            CheckError::AssociatedTyNotInTrait { .. } => "E0437",
+            CheckError::MissingTraitItems { .. } => "E0046",
+            CheckError::WrongNumberOfArguments { .. } => "E0050",
+            CheckError::GenericParametersMismatch {
+                impl_kinds,
+                trait_kinds,
+                ..
+            } => {
+                let lts = |kinds: &[ParameterKind]| {
+                    kinds.iter().filter(|k| **k == ParameterKind::Lt).count()
+                };
+                if lts(impl_kinds) != lts(trait_kinds) {
+                    "E0195"
+                } else {
+                    "E0049"
+                }
+            }
+            CheckError::ImplStricterThanTrait { .. } => "E0276",
+            CheckError::MissingRequiredBound { .. } => return None,
+            CheckError::AutoTraitWithGenerics => "E0567",
+            CheckError::AutoTraitWithWhereClauses => "E0568",
+            CheckError::AutoTraitWithItems => "E0380",
+            CheckError::UseOfMoved { .. } => "E0382",
+            CheckError::MutableBorrowOfImmutableLocal { .. } => "E0596",
+            CheckError::MutableBorrowBehindSharedRef { .. } => "E0596",
+            CheckError::AssignToImmutableLocal { .. } => "E0594",
+            CheckError::AssignTwiceToImmutableLocal { .. } => "E0384",
+            CheckError::AssignBehindSharedRef { .. } => "E0594",
+            CheckError::UseWhileMutablyBorrowed { .. } => "E0503",
+            CheckError::ConflictingBorrow { .. } => "E0502",
+            CheckError::MultipleMutableBorrows { .. } => "E0499",
+            CheckError::MoveWhileBorrowed { .. } => "E0505",
+            CheckError::AssignWhileBorrowed { .. } => "E0506",
+            CheckError::DropWhileBorrowed { .. } => "E0597",
+            CheckError::DynIncompatibleTrait { .. } => "E0038",
+        };
+        Some(code)
+    }
+}
+
+impl std::fmt::Display for CheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckError::DuplicateDefinition { kind, name } => {
+                write!(f, "the {kind} name `{name}` is defined multiple times")
+            }
+            CheckError::DuplicateAssociatedItem { kind, name } => {
+                write!(f, "the {kind} name `{name}` is defined multiple times")
+            }
+            CheckError::DuplicateVariant { variant } => {
+                write!(f, "variant \"{variant:?}\" defined multiple times")
+            }
+            CheckError::DuplicateField { variant, field } => {
+                write!(
+                    f,
+                    "field \"{field:?}\" of variant \"{variant:?}\" defined multiple times"
+                )
+            }
+            CheckError::DuplicateImpl { impl_a } => {
+                write!(f, "duplicate impl in current crate: {impl_a:?}")
+            }
+            CheckError::OverlappingImpls { impl_a, impl_b } => {
+                write!(f, "impls may overlap:\n{impl_a:?}\n{impl_b:?}")
+            }
+            CheckError::OrphanImpl { impl_a } => {
+                write!(f, "the impl `{impl_a:?}` violates the orphan rules")
+            }
+            CheckError::OrphanNegImpl { impl_a } => {
+                write!(f, "the impl `{impl_a:?}` violates the orphan rules")
+            }
+            CheckError::UnsafeNegImpl => write!(f, "negative impls cannot be unsafe"),
+            CheckError::UnsafeImplOfSafeTrait { trait_id } => {
+                write!(f, "implementing the trait `{trait_id:?}` is not unsafe")
+            }
+            CheckError::SafeImplOfUnsafeTrait { trait_id } => {
+                write!(
+                    f,
+                    "the trait `{trait_id:?}` requires an `unsafe impl` declaration"
+                )
+            }
+            CheckError::BuiltinTraitImpl {
+                lang_item: LangItem::Sized,
+                trait_id,
+                self_ty: _,
+            } => write!(
+                f,
+                "explicit impls for the `{trait_id:?}` trait are not permitted"
+            ),
+            CheckError::BuiltinTraitImpl {
+                lang_item: _,
+                trait_id,
+                self_ty,
+            } => write!(
+                f,
+                "the trait `{trait_id:?}` cannot be implemented for `{self_ty:?}`"
+            ),
+            CheckError::NonCopyField {
+                trait_id,
+                self_ty,
+                field,
+            } => write!(
+                f,
+                "the trait `{trait_id:?}` cannot be implemented for `{self_ty:?}`: the field `{field:?}` does not implement `{trait_id:?}`"
+            ),
+            CheckError::FnNotInTrait { fn_id } => write!(f, "no fn `{fn_id:?}` in the trait"),
+            CheckError::InherentImplForNonLocalType { self_ty } => write!(
+                f,
+                "cannot define an inherent impl for `{self_ty:?}`, which is not a type of the current crate"
+            ),
+            CheckError::DuplicateInherentItem {
+                name,
+                impl_a,
+                impl_b,
+            } => write!(
+                f,
+                "duplicate definitions with name `{name}`:\n{impl_a:?}\n{impl_b:?}"
+            ),
+            CheckError::AssociatedTyNotInTrait { item_id } => {
+                write!(f, "no associated type `{item_id:?}` in the trait")
+            }
+            CheckError::MissingTraitItems { trait_id, missing } => write!(
+                f,
+                "not all items of the trait `{trait_id:?}` are implemented, missing: `{}`",
+                missing.join("`, `")
+            ),
+            CheckError::WrongNumberOfArguments {
+                impl_args,
+                trait_args,
+            } => write!(
+                f,
+                "impl has {impl_args} function arguments but trait has {trait_args} function arguments"
+            ),
+            CheckError::GenericParametersMismatch {
+                item,
+                impl_kinds,
+                trait_kinds,
+            } => write!(
+                f,
+                "`{item}` has generic parameters {impl_kinds:?} but its trait declaration has {trait_kinds:?}"
+            ),
+            CheckError::ImplStricterThanTrait { item } => {
+                write!(f, "impl has stricter requirements than trait for `{item}`")
+            }
+            CheckError::MissingRequiredBound {
+                associated_ty,
+                fn_id,
+                bound,
+            } => write!(
+                f,
+                "missing required bound on `{associated_ty:?}`: the signature of `{fn_id:?}` implies `{bound:?}`, which must be a where-clause of `{associated_ty:?}`"
+            ),
+            CheckError::AutoTraitWithGenerics => {
+                write!(f, "auto traits cannot have generic parameters")
+            }
+            CheckError::AutoTraitWithWhereClauses => {
+                write!(f, "auto traits cannot have super traits or lifetime bounds")
+            }
+            CheckError::AutoTraitWithItems => {
+                write!(f, "auto traits cannot have associated items")
+            }
+            CheckError::UseOfMoved { moved, place } => write!(
+                f,
+                "use of moved or uninitialized value: `{moved:?}` is not initialized when `{place:?}` is used"
+            ),
+            CheckError::MutableBorrowOfImmutableLocal { place, local_id } => write!(
+                f,
+                "cannot borrow `{place:?}` as mutable, as `{local_id:?}` is not declared as mutable"
+            ),
+            CheckError::MutableBorrowBehindSharedRef { place } => write!(
+                f,
+                "cannot borrow `{place:?}` as mutable, as it is behind a `&` reference"
+            ),
+            CheckError::AssignToImmutableLocal { place, local_id } => write!(
+                f,
+                "cannot assign to `{place:?}`, as `{local_id:?}` is not declared as mutable"
+            ),
+            CheckError::AssignTwiceToImmutableLocal { local_id } => {
+                write!(f, "cannot assign twice to immutable variable `{local_id:?}`")
+            }
+            CheckError::AssignBehindSharedRef { place } => {
+                write!(f, "cannot assign to `{place:?}`, which is behind a `&` reference")
+            }
+            CheckError::UseWhileMutablyBorrowed { place } => {
+                write!(f, "cannot use `{place:?}` because it was mutably borrowed")
+            }
+            CheckError::ConflictingBorrow {
+                place,
+                mutable: false,
+            } => write!(
+                f,
+                "cannot borrow `{place:?}` as immutable because it is also borrowed as mutable"
+            ),
+            CheckError::ConflictingBorrow {
+                place,
+                mutable: true,
+            } => write!(
+                f,
+                "cannot borrow `{place:?}` as mutable because it is also borrowed as immutable"
+            ),
+            CheckError::MultipleMutableBorrows { place } => {
+                write!(
+                    f,
+                    "cannot borrow `{place:?}` as mutable more than once at a time"
+                )
+            }
+            CheckError::MoveWhileBorrowed { place } => {
+                write!(f, "cannot move out of `{place:?}` because it is borrowed")
+            }
+            CheckError::AssignWhileBorrowed { place } => {
+                write!(f, "cannot assign to `{place:?}` because it is borrowed")
+            }
+            CheckError::DropWhileBorrowed { place } => {
+                write!(f, "cannot drop `{place:?}` because it is borrowed")
+            }
+            CheckError::DynIncompatibleTrait { trait_id } => {
+                write!(f, "the trait `{trait_id:?}` is not dyn compatible")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CheckError {}
+
+/// The rustc error code of `error`, if it or any of the context
+/// it was raised in is a [`CheckError`].
+pub fn error_code(error: &anyhow::Error) -> Option<&'static str> {
+    error
+        .downcast_ref::<CheckError>()
+        .and_then(CheckError::code)
+}
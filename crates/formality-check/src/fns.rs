@@ -1,22 +1,27 @@
-use formality_prove::Env;
+use formality_core::{visit::CoreVisit, Upcasted};
+use formality_prove::{Env, LangItem};
 use formality_rust::{
-    grammar::{Fn, FnBoundData},
+    grammar::{Fn, FnBody, FnBoundData, MaybeFnBody},
     prove::ToWcs,
 };
-use formality_types::grammar::{Fallible, Wcs};
+use formality_types::grammar::{
+    AliasTy, Fallible, Parameter, Relation, RigidName, RigidTy, Ty, TyData, Wcs,
+};
 
 use crate::Check;
 
 impl Check<'_> {
     pub(crate) fn check_free_fn(&self, f: &Fn) -> Fallible<()> {
-        self.check_fn(&Env::default(), Wcs::t(), f)
+        self.check_fn(&Env::default(), Wcs::t(), f, false)
     }
 
+    /// Check the fn `f`, which is declared within a trait if `in_trait` is true.
     pub(crate) fn check_fn(
         &self,
         in_env: &Env,
         in_assumptions: impl ToWcs,
         f: &Fn,
+        in_trait: bool,
     ) -> Fallible<()> {
         let in_assumptions = in_assumptions.to_wcs();
         assert!(in_env.only_universal_variables() && in_env.encloses((&in_assumptions, f)));
@@ -29,10 +34,22 @@ impl Check<'_> {
             input_tys,
             output_ty,
             where_clauses,
-            body: _,
+            body,
         } = env.instantiate_universally(binder);
 
-        let fn_assumptions: Wcs = (in_assumptions, &where_clauses).to_wcs();
+        // Callers prove the input types well-formed, so the fn may assume what that implies.
+        let fn_assumptions: Wcs = (
+            in_assumptions,
+            &where_clauses,
+            implied_outlives_bounds(&input_tys),
+        )
+            .to_wcs();
+
+        self.check_dyn_compatible(
+            &env,
+            &fn_assumptions,
+            input_tys.iter().chain(Some(&output_ty)),
+        )?;
 
         self.prove_where_clauses_well_formed(&env, &fn_assumptions, &where_clauses)?;
 
@@ -42,6 +59,71 @@ impl Check<'_> {
 
         self.prove_goal(&env, &fn_assumptions, output_ty.well_formed())?;
 
+        // Only a body needs the arguments and return value to be `Sized`, so a trait
+        // may declare fns taking or returning unsized types, like `Self`. Trusted bodies
+        // in traits are not checked either; MIR bodies check the types of all their locals.
+        match &body {
+            MaybeFnBody::NoFnBody => {}
+            MaybeFnBody::FnBody(FnBody::TrustedFnBody) => {
+                if !in_trait {
+                    self.prove_goal(
+                        &env,
+                        &fn_assumptions,
+                        self.sized_goals(input_tys.iter().chain(Some(&output_ty))),
+                    )?;
+                }
+            }
+            MaybeFnBody::FnBody(FnBody::MirFnBody(mir_body)) => {
+                self.check_mir_fn_body(&env, &fn_assumptions, &input_tys, &output_ty, mir_body)?
+            }
+        }
+
         Ok(())
     }
+
+    /// Goals requiring each of `tys` to be `Sized`, as the types of values must be.
+    /// Programs that do not declare the `Sized` lang item impose no such requirement.
+    pub(crate) fn sized_goals<'t>(&self, tys: impl IntoIterator<Item = &'t Ty>) -> Wcs {
+        let Some(sized) = self.decls.lang_item_trait(LangItem::Sized) else {
+            return Wcs::t();
+        };
+        tys.into_iter()
+            .map(|ty| sized.with(ty, Vec::<Parameter>::new()))
+            .upcasted()
+            .collect()
+    }
+}
+
+/// The outlives bounds implied by `tys` being well-formed:
+/// `T: 'a` for each reference type `&'a T` that appears within them, unless `T` has no variables.
+pub(crate) fn implied_outlives_bounds<'a>(tys: impl IntoIterator<Item = &'a Ty>) -> Wcs {
+    fn push_bounds(ty: &Ty, bounds: &mut Vec<Relation>) {
+        let parameters = match ty.data() {
+            TyData::RigidTy(RigidTy { name, parameters }) => {
+                // Types without variables, like `u32`, outlive everything anyway.
+                if let (RigidName::Ref(_), [lt, referent]) = (name, &parameters[..]) {
+                    if !referent.free_variables().is_empty() {
+                        bounds.push(referent.outlives(lt));
+                    }
+                }
+                parameters
+            }
+            TyData::AliasTy(AliasTy {
+                name: _,
+                parameters,
+            }) => parameters,
+            TyData::PredicateTy(_) | TyData::Variable(_) => return,
+        };
+        for parameter in parameters {
+            if let Parameter::Ty(ty) = parameter {
+                push_bounds(ty, bounds);
+            }
+        }
+    }
+
+    let mut bounds = vec![];
+    for ty in tys {
+        push_bounds(ty, &mut bounds);
+    }
+    bounds.into_iter().upcasted().collect()
 }
@@ -1,21 +1,27 @@
-use anyhow::bail;
+use anyhow::{bail, Context};
 
 use fn_error_context::context;
-use formality_core::Downcasted;
-use formality_prove::{Env, Safety};
+use formality_core::{Downcast, Downcasted, Set};
+use formality_prove::{Env, LangItem, Safety};
 use formality_rust::{
     grammar::{
-        AssociatedTy, AssociatedTyBoundData, AssociatedTyValue, AssociatedTyValueBoundData, Fn,
-        FnBoundData, ImplItem, NegTraitImpl, NegTraitImplBoundData, Trait, TraitBoundData,
-        TraitImpl, TraitImplBoundData, TraitItem,
+        AdtBoundData, AssociatedTy, AssociatedTyBoundData, AssociatedTyValue,
+        AssociatedTyValueBoundData, Field, Fn, FnBoundData, ImplItem, InherentImpl,
+        InherentImplBoundData, MaybeAssociatedTyDefault, MaybeFnBody, NegTraitImpl,
+        NegTraitImplBoundData, Trait, TraitBoundData, TraitImpl, TraitImplBoundData, TraitItem,
+        Variant,
     },
     prove::ToWcs,
 };
 use formality_types::{
-    grammar::{Binder, Fallible, Relation, Substitution, Wcs},
+    grammar::{
+        Binder, Fallible, Parameter, Relation, RigidName, RigidTy, Substitution, TraitId, Wcs,
+    },
     rust::Term,
 };
 
+use crate::{fns::implied_outlives_bounds, CheckError};
+
 impl super::Check<'_> {
     #[context("check_trait_impl({trait_impl:?})")]
     pub(super) fn check_trait_impl(&self, trait_impl: &TraitImpl) -> Fallible<()> {
@@ -33,6 +39,8 @@ impl super::Check<'_> {
 
         let trait_ref = trait_id.with(self_ty, trait_parameters);
 
+        self.check_dyn_compatible(&env, &where_clauses, &trait_ref.parameters)?;
+
         self.prove_where_clauses_well_formed(&env, &where_clauses, &where_clauses)?;
 
         self.prove_goal(&env, &where_clauses, trait_ref.is_implemented())?;
@@ -47,10 +55,16 @@ impl super::Check<'_> {
 
         self.check_safety_matches(trait_decl, trait_impl)?;
 
+        self.check_lang_item_impl(trait_decl, &trait_ref.parameters[0])?;
+
+        self.check_copy_impl_fields(&env, &where_clauses, trait_decl, &trait_ref.parameters[0])?;
+
         for impl_item in &impl_items {
             self.check_trait_impl_item(&env, &where_clauses, &trait_items, impl_item)?;
         }
 
+        self.check_trait_items_provided(&trait_ref.trait_id, &trait_items, &impl_items)?;
+
         Ok(())
     }
 
@@ -71,9 +85,11 @@ impl super::Check<'_> {
 
         // Negative impls are always safe (rustc E0198) regardless of the trait's safety.
         if *safety == Safety::Unsafe {
-            bail!("negative impls cannot be unsafe");
+            bail!(CheckError::UnsafeNegImpl);
         }
 
+        self.check_dyn_compatible(&env, &where_clauses, &trait_ref.parameters)?;
+
         self.prove_where_clauses_well_formed(&env, &where_clauses, &where_clauses)?;
 
         self.prove_goal(&env, &where_clauses, trait_ref.not_implemented())?;
@@ -81,17 +97,235 @@ impl super::Check<'_> {
         Ok(())
     }
 
+    #[context("check_inherent_impl({inherent_impl:?})")]
+    pub(super) fn check_inherent_impl(&self, inherent_impl: &InherentImpl) -> Fallible<()> {
+        let InherentImpl { binder } = inherent_impl;
+
+        let mut env = Env::default();
+
+        let InherentImplBoundData {
+            self_ty,
+            where_clauses,
+            impl_items,
+        } = env.instantiate_universally(binder);
+
+        // Inherent impls can only be given by the crate that defines the type (rustc E0116/E0390).
+        match self_ty.downcast() {
+            Some(RigidTy {
+                name: RigidName::AdtId(adt_id),
+                ..
+            }) if self.decls.is_local_adt_id(&adt_id) => {}
+            _ => bail!(CheckError::InherentImplForNonLocalType { self_ty }),
+        }
+
+        self.prove_where_clauses_well_formed(&env, &where_clauses, &where_clauses)?;
+
+        self.prove_goal(&env, &where_clauses, self_ty.well_formed())?;
+
+        self.check_impl_items_have_unique_names(&impl_items)?;
+
+        for impl_item in &impl_items {
+            match impl_item {
+                ImplItem::Fn(f) => self.check_fn(&env, &where_clauses, f, false)?,
+                ImplItem::AssociatedTyValue(v) => {
+                    self.check_inherent_associated_ty_value(&env, &where_clauses, v)?
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_impl_items_have_unique_names(&self, impl_items: &[ImplItem]) -> Fallible<()> {
+        let mut functions = Set::new();
+        let mut associated_types = Set::new();
+        for impl_item in impl_items {
+            match impl_item {
+                ImplItem::Fn(f) => {
+                    if !functions.insert(&f.id) {
+                        bail!(CheckError::DuplicateAssociatedItem {
+                            kind: "function",
+                            name: format!("{:?}", f.id),
+                        });
+                    }
+                }
+                ImplItem::AssociatedTyValue(v) => {
+                    if !associated_types.insert(&v.id) {
+                        bail!(CheckError::DuplicateAssociatedItem {
+                            kind: "associated type",
+                            name: format!("{:?}", v.id),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[context("check_inherent_associated_ty_value({impl_value:?})")]
+    fn check_inherent_associated_ty_value(
+        &self,
+        impl_env: &Env,
+        impl_assumptions: impl ToWcs,
+        impl_value: &AssociatedTyValue,
+    ) -> Fallible<()> {
+        let impl_assumptions: Wcs = impl_assumptions.to_wcs();
+
+        let mut env = impl_env.clone();
+
+        let AssociatedTyValueBoundData { where_clauses, ty } =
+            env.instantiate_universally(&impl_value.binder);
+
+        self.prove_where_clauses_well_formed(
+            &env,
+            (&impl_assumptions, &where_clauses),
+            &where_clauses,
+        )?;
+
+        self.prove_goal(&env, (&impl_assumptions, &where_clauses), ty.well_formed())?;
+
+        Ok(())
+    }
+
     /// Validate that the declared safety of an impl matches the one from the trait declaration.
     fn check_safety_matches(&self, trait_decl: &Trait, trait_impl: &TraitImpl) -> Fallible<()> {
         if trait_decl.safety != trait_impl.safety {
             match trait_decl.safety {
-                Safety::Safe => bail!("implementing the trait `{:?}` is not unsafe", trait_decl.id),
-                Safety::Unsafe => bail!(
-                    "the trait `{:?}` requires an `unsafe impl` declaration",
-                    trait_decl.id
-                ),
+                Safety::Safe => bail!(CheckError::UnsafeImplOfSafeTrait {
+                    trait_id: trait_decl.id.clone()
+                }),
+                Safety::Unsafe => bail!(CheckError::SafeImplOfUnsafeTrait {
+                    trait_id: trait_decl.id.clone()
+                }),
+            }
+        }
+        Ok(())
+    }
+
+    /// Built-in traits may only be implemented explicitly for ADTs; other types
+    /// get their impls from the prover's built-in rules (or not at all).
+    fn check_lang_item_impl(&self, trait_decl: &Trait, self_ty: &Parameter) -> Fallible<()> {
+        match trait_decl.lang_item {
+            LangItem::None => {}
+            LangItem::Sized => bail!(CheckError::BuiltinTraitImpl {
+                lang_item: trait_decl.lang_item,
+                trait_id: trait_decl.id.clone(),
+                self_ty: self_ty.clone(),
+            }),
+            LangItem::Copy
+            | LangItem::Clone
+            | LangItem::Drop
+            | LangItem::FnOnce
+            | LangItem::FnMut
+            | LangItem::Fn => {
+                if !matches!(
+                    self_ty.downcast(),
+                    Some(RigidTy {
+                        name: RigidName::AdtId(_),
+                        ..
+                    })
+                ) {
+                    bail!(CheckError::BuiltinTraitImpl {
+                        lang_item: trait_decl.lang_item,
+                        trait_id: trait_decl.id.clone(),
+                        self_ty: self_ty.clone(),
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// An ADT can only implement `Copy` if the types of all of its fields do (rustc E0204).
+    fn check_copy_impl_fields(
+        &self,
+        env: &Env,
+        assumptions: impl ToWcs,
+        trait_decl: &Trait,
+        self_ty: &Parameter,
+    ) -> Fallible<()> {
+        if trait_decl.lang_item != LangItem::Copy {
+            return Ok(());
+        }
+
+        let Some(RigidTy {
+            name: RigidName::AdtId(adt_id),
+            parameters,
+        }) = self_ty.downcast()
+        else {
+            return Ok(());
+        };
+
+        let AdtBoundData {
+            where_clauses: _,
+            variants,
+        } = self
+            .program
+            .adt_named(&adt_id)?
+            .binder
+            .instantiate_with(&parameters)?;
+
+        let assumptions = assumptions.to_wcs();
+        for Variant { name: _, fields } in &variants {
+            for Field { name, ty } in fields {
+                self.prove_goal(
+                    env,
+                    &assumptions,
+                    trait_decl.id.with(ty, Vec::<Parameter>::new()),
+                )
+                .context(CheckError::NonCopyField {
+                    trait_id: trait_decl.id.clone(),
+                    self_ty: self_ty.clone(),
+                    field: name.clone(),
+                })?;
             }
         }
+
+        Ok(())
+    }
+
+    /// Validate that the impl gives a value for each trait item that has no default,
+    /// i.e., each function without a body and each associated type without a default type.
+    fn check_trait_items_provided(
+        &self,
+        trait_id: &TraitId,
+        trait_items: &[TraitItem],
+        impl_items: &[ImplItem],
+    ) -> Fallible<()> {
+        let missing: Vec<String> = trait_items
+            .iter()
+            .filter_map(|trait_item| match trait_item {
+                TraitItem::Fn(Fn { id, binder }) => {
+                    let provided = impl_items
+                        .iter()
+                        .downcasted::<Fn>()
+                        .any(|ii_fn| ii_fn.id == *id);
+                    let has_default = match &binder.peek().body {
+                        MaybeFnBody::NoFnBody => false,
+                        MaybeFnBody::FnBody(_) => true,
+                    };
+                    (!provided && !has_default).then(|| format!("{id:?}"))
+                }
+                TraitItem::AssociatedTy(AssociatedTy { id, binder }) => {
+                    let provided = impl_items
+                        .iter()
+                        .downcasted::<AssociatedTyValue>()
+                        .any(|ii_value| ii_value.id == *id);
+                    let has_default = match &binder.peek().default {
+                        MaybeAssociatedTyDefault::NoDefault => false,
+                        MaybeAssociatedTyDefault::DefaultTy(_) => true,
+                    };
+                    (!provided && !has_default).then(|| format!("{id:?}"))
+                }
+            })
+            .collect();
+
+        if !missing.is_empty() {
+            bail!(CheckError::MissingTraitItems {
+                trait_id: trait_id.clone(),
+                missing,
+            });
+        }
         Ok(())
     }
 
@@ -134,12 +368,14 @@ impl super::Check<'_> {
             .find(|trait_f| trait_f.id == ii_fn.id)
         {
             Some(trait_f) => trait_f,
-            None => bail!("no fn `{:?}` in the trait", ii_fn.id),
+            None => bail!(CheckError::FnNotInTrait {
+                fn_id: ii_fn.id.clone()
+            }),
         };
 
         tracing::debug!(?ti_fn);
 
-        self.check_fn(env, &impl_assumptions, ii_fn)?;
+        self.check_fn(env, &impl_assumptions, ii_fn, false)?;
 
         let mut env = env.clone();
         let (
@@ -155,33 +391,42 @@ impl super::Check<'_> {
                 where_clauses: ti_where_clauses,
                 body: _,
             },
-        ) = env.instantiate_universally(&self.merge_binders(&ii_fn.binder, &ti_fn.binder)?);
+        ) = env.instantiate_universally(&self.merge_binders(
+            &ii_fn.id,
+            &ii_fn.binder,
+            &ti_fn.binder,
+        )?);
+
+        // The signature of the trait's fn is well-formed, so whatever it implies holds as well.
+        let implied_bounds = implied_outlives_bounds(&ti_input_tys);
 
         self.prove_goal(
             &env,
-            (&impl_assumptions, &ti_where_clauses),
+            (&impl_assumptions, &ti_where_clauses, &implied_bounds),
             &ii_where_clauses,
-        )?;
+        )
+        .context(CheckError::ImplStricterThanTrait {
+            item: format!("{:?}", ii_fn.id),
+        })?;
 
         if ii_input_tys.len() != ti_input_tys.len() {
-            bail!(
-                "impl has {} function arguments but trait has {} function arguments",
-                ii_input_tys.len(),
-                ti_input_tys.len()
-            )
+            bail!(CheckError::WrongNumberOfArguments {
+                impl_args: ii_input_tys.len(),
+                trait_args: ti_input_tys.len(),
+            })
         }
 
         for (ii_input_ty, ti_input_ty) in ii_input_tys.iter().zip(&ti_input_tys) {
             self.prove_goal(
                 &env,
-                (&impl_assumptions, &ii_where_clauses),
+                (&impl_assumptions, &ii_where_clauses, &implied_bounds),
                 Relation::sub(ti_input_ty, ii_input_ty),
             )?;
         }
 
         self.prove_goal(
             &env,
-            (&impl_assumptions, &ii_where_clauses),
+            (&impl_assumptions, &ii_where_clauses, &implied_bounds),
             Relation::sub(ii_output_ty, ti_output_ty),
         )?;
 
@@ -211,7 +456,9 @@ impl super::Check<'_> {
             .find(|trait_associated_ty| trait_associated_ty.id == *id)
         {
             Some(trait_associated_ty) => trait_associated_ty,
-            None => bail!("no associated type `{:?}` in the trait", id),
+            None => bail!(CheckError::AssociatedTyNotInTrait {
+                item_id: id.clone()
+            }),
         };
 
         let mut env = impl_env.clone();
@@ -224,8 +471,13 @@ impl super::Check<'_> {
             AssociatedTyBoundData {
                 ensures: ti_ensures,
                 where_clauses: ti_where_clauses,
+                default: _,
             },
-        ) = env.instantiate_universally(&self.merge_binders(binder, &trait_associated_ty.binder)?);
+        ) = env.instantiate_universally(&self.merge_binders(
+            id,
+            binder,
+            &trait_associated_ty.binder,
+        )?);
 
         self.prove_where_clauses_well_formed(
             &env,
@@ -237,7 +489,10 @@ impl super::Check<'_> {
             &env,
             (&impl_assumptions, &ti_where_clauses),
             &ii_where_clauses,
-        )?;
+        )
+        .context(CheckError::ImplStricterThanTrait {
+            item: format!("{id:?}"),
+        })?;
 
         self.prove_goal(
             &env,
@@ -256,15 +511,16 @@ impl super::Check<'_> {
     /// into a single binder over `(I, T)`
     fn merge_binders<I: Term, T: Term>(
         &self,
+        item: &impl std::fmt::Debug,
         impl_binder: &Binder<I>,
         trait_binder: &Binder<T>,
     ) -> Fallible<Binder<(I, T)>> {
         if impl_binder.kinds() != trait_binder.kinds() {
-            bail!(
-                "distinct binder kinds: impl {:?} vs trait {:?}",
-                impl_binder.kinds(),
-                trait_binder.kinds()
-            );
+            bail!(CheckError::GenericParametersMismatch {
+                item: format!("{item:?}"),
+                impl_kinds: impl_binder.kinds().to_vec(),
+                trait_kinds: trait_binder.kinds().to_vec(),
+            });
         }
 
         let (impl_names, impl_value) = impl_binder.open();
@@ -1,9 +1,13 @@
 #![allow(dead_code)]
 
-use std::{collections::VecDeque, fmt::Debug};
+use std::{cell::Cell, collections::VecDeque, fmt::Debug};
 
-use anyhow::bail;
-use formality_core::Set;
+use anyhow::{anyhow, bail, Context};
+use formality_core::{
+    judgment::{clear_judgment_caches, FailedJudgment},
+    parse::{Span, Spans},
+    Set,
+};
 use formality_prove::{is_definitely_not_proveable, Decls, Env};
 use formality_rust::{
     grammar::{Crate, CrateItem, Program, Test, TestBoundData},
@@ -14,93 +18,190 @@ use formality_types::grammar::{Fallible, Wcs};
 /// Check all crates in the program. The crates must be in dependency order
 /// such that any prefix of the crates is a complete program.
 pub fn check_all_crates(program: &Program) -> Fallible<()> {
-    let Program { crates } = program;
-    let mut crates: VecDeque<_> = crates.iter().cloned().collect();
+    check_all_crates_with_spans(program, &Spans::default())
+}
+
+/// Like [`check_all_crates`], but errors in crate items and where-clauses whose spans
+/// were recorded in `spans` report where they are found in the input.
+pub fn check_all_crates_with_spans(program: &Program, spans: &Spans) -> Fallible<()> {
+    match check_crates(program, spans, false).into_iter().next() {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Like [`check_all_crates_with_spans`], but rather than stopping at the first error,
+/// checks all items of all crates and returns every error found. Items that fail
+/// to check are assumed to be valid when checking the rest.
+pub fn check_all_crates_collecting_errors(program: &Program, spans: &Spans) -> Vec<anyhow::Error> {
+    check_crates(program, spans, true)
+}
 
+/// Checks each crate in turn, stopping at the first error unless `keep_going` is true.
+fn check_crates(program: &Program, spans: &Spans, keep_going: bool) -> Vec<anyhow::Error> {
+    // Results cached while checking earlier programs will not be needed again.
+    clear_judgment_caches();
+
+    // The items of all crates appear in the input in order.
+    let mut item_spans = spans
+        .spans_of(program.crates.iter().flat_map(|c| &c.items))
+        .into_iter();
+
+    let Program { crates } = program.with_implicit_sized_bounds();
+    let mut crates: VecDeque<_> = crates.into();
+
+    let mut errors = vec![];
     let mut prefix_program = Program { crates: vec![] };
     while let Some(c) = crates.pop_front() {
+        let c_item_spans = item_spans.by_ref().take(c.items.len()).collect();
         prefix_program.crates.push(c);
-        check_current_crate(&prefix_program)?;
+        if let Err(Stopped) = check_current_crate(
+            &prefix_program,
+            spans,
+            c_item_spans,
+            keep_going,
+            &mut errors,
+        ) {
+            break;
+        }
     }
 
-    Ok(())
+    errors
 }
 
 /// Checks the current crate in the program, assuming all other crates are valid.
-fn check_current_crate(program: &Program) -> Fallible<()> {
+/// `item_spans` are the spans of the items of the current crate in the input, if known.
+fn check_current_crate(
+    program: &Program,
+    spans: &Spans,
+    item_spans: Vec<Option<Span>>,
+    keep_going: bool,
+    errors: &mut Vec<anyhow::Error>,
+) -> Result<(), Stopped> {
     let decls = program.to_prove_decls();
     Check {
         program,
         decls: &decls,
+        spans,
+        item_spans,
+        current_item_span: Cell::new(None),
+        keep_going,
     }
-    .check()
+    .check(errors)
 }
 
 mod adts;
 mod coherence;
+mod errors;
 mod fns;
 mod impls;
+mod mir;
 mod traits;
 mod where_clauses;
 
+pub use errors::{error_code, CheckError};
+
 struct Check<'p> {
     program: &'p Program,
     decls: &'p Decls,
+
+    /// The spans recorded when parsing the input.
+    spans: &'p Spans,
+
+    /// The spans of the items of the current crate in the input, if known.
+    item_spans: Vec<Option<Span>>,
+
+    /// The span of the item being checked, if known.
+    current_item_span: Cell<Option<Span>>,
+
+    /// If true, checking continues after an error, see [`Self::report`].
+    keep_going: bool,
 }
 
+/// Indicates that checking stopped at an error, see [`Check::report`].
+struct Stopped;
+
 impl Check<'_> {
-    fn check(&self) -> Fallible<()> {
+    fn check(&self, errors: &mut Vec<anyhow::Error>) -> Result<(), Stopped> {
         let Program { crates } = &self.program;
         if let Some(current_crate) = crates.last() {
-            self.check_current_crate(current_crate)?;
+            self.check_current_crate(current_crate, errors)?;
+        }
+        Ok(())
+    }
+
+    /// Adds the error of `result`, if any, to `errors`.
+    /// Returns `Err` if checking should then stop, i.e., unless `keep_going` is set.
+    fn report(&self, result: Fallible<()>, errors: &mut Vec<anyhow::Error>) -> Result<(), Stopped> {
+        if let Err(error) = result {
+            errors.push(error);
+            if !self.keep_going {
+                return Err(Stopped);
+            }
         }
         Ok(())
     }
 
-    fn check_current_crate(&self, c: &Crate) -> Fallible<()> {
+    fn check_current_crate(
+        &self,
+        c: &Crate,
+        errors: &mut Vec<anyhow::Error>,
+    ) -> Result<(), Stopped> {
         let Crate { id: _, items } = c;
 
-        self.check_for_duplicate_items()?;
+        self.check_for_duplicate_items(c, errors)?;
 
-        for item in items {
-            self.check_crate_item(item)?;
+        for (item, span) in items.iter().zip(&self.item_spans) {
+            self.current_item_span.set(*span);
+            let result = match span {
+                Some(span) => self
+                    .check_crate_item(item)
+                    .with_context(|| format!("in the item at {}", self.spans.location(*span))),
+                None => self.check_crate_item(item),
+            };
+            self.current_item_span.set(None);
+            self.report(result, errors)?;
         }
 
-        self.check_coherence(c)?;
+        self.check_coherence(c, errors)?;
 
         Ok(())
     }
 
-    fn check_for_duplicate_items(&self) -> Fallible<()> {
-        let Program { crates } = &self.program;
-        for c in crates.iter() {
-            let mut items = Set::new();
-            let mut traits = Set::new();
-            let mut functions = Set::new();
-            for item in c.items.iter() {
-                match item {
-                    CrateItem::Struct(s) => {
-                        if !items.insert(&s.id) {
-                            bail!("the item name `{:?}` is defined multiple times", s.id);
-                        }
-                    }
-                    CrateItem::Enum(e) => {
-                        if !items.insert(&e.id) {
-                            bail!("the item name `{:?}` is defined multiple times", e.id);
-                        }
-                    }
-                    CrateItem::Trait(t) => {
-                        if !traits.insert(&t.id) {
-                            bail!("the trait name `{:?}` is defined multiple times", t.id);
-                        }
-                    }
-                    CrateItem::Fn(f) => {
-                        if !functions.insert(&f.id) {
-                            bail!("the function name `{:?}` is defined multiple times", f.id);
-                        }
-                    }
-                    CrateItem::TraitImpl(_) | CrateItem::NegTraitImpl(_) | CrateItem::Test(_) => {}
+    /// Checks for items of the same name in the current crate, reporting each duplicate;
+    /// the other crates were checked already.
+    fn check_for_duplicate_items(
+        &self,
+        c: &Crate,
+        errors: &mut Vec<anyhow::Error>,
+    ) -> Result<(), Stopped> {
+        let mut items = Set::new();
+        let mut traits = Set::new();
+        let mut functions = Set::new();
+        for item in c.items.iter() {
+            let duplicate = match item {
+                CrateItem::Struct(s) => {
+                    (!items.insert(&s.id)).then(|| ("item", format!("{:?}", s.id)))
                 }
+                CrateItem::Enum(e) => {
+                    (!items.insert(&e.id)).then(|| ("item", format!("{:?}", e.id)))
+                }
+                CrateItem::Trait(t) => {
+                    (!traits.insert(&t.id)).then(|| ("trait", format!("{:?}", t.id)))
+                }
+                CrateItem::Fn(f) => {
+                    (!functions.insert(&f.id)).then(|| ("function", format!("{:?}", f.id)))
+                }
+                CrateItem::TraitImpl(_)
+                | CrateItem::NegTraitImpl(_)
+                | CrateItem::InherentImpl(_)
+                | CrateItem::Test(_) => None,
+            };
+            if let Some((kind, name)) = duplicate {
+                self.report(
+                    Err(anyhow!(CheckError::DuplicateDefinition { kind, name })),
+                    errors,
+                )?;
             }
         }
 
@@ -115,6 +216,7 @@ impl Check<'_> {
             CrateItem::Enum(e) => self.check_adt(&e.to_adt()),
             CrateItem::Fn(f) => self.check_free_fn(f),
             CrateItem::NegTraitImpl(i) => self.check_neg_trait_impl(i),
+            CrateItem::InherentImpl(i) => self.check_inherent_impl(i),
             CrateItem::Test(t) => self.check_test(t),
         }
     }
@@ -138,7 +240,7 @@ impl Check<'_> {
         assert!(env.encloses((&assumptions, &goal)));
 
         let cs = formality_prove::prove(self.decls, env, &assumptions, &goal);
-        let cs = cs.into_set()?;
+        let cs = cs.into_set().map_err(FailedJudgment::into_summary)?;
         if cs.iter().any(|c| c.unconditionally_true()) {
             return Ok(());
         }
@@ -146,6 +248,48 @@ impl Check<'_> {
         bail!("failed to prove {goal:?} given {assumptions:?}, got {cs:?}")
     }
 
+    /// Like [`Self::prove_goal`], but `env` may contain existential variables
+    /// (e.g., the lifetimes bound by a MIR body) for which the prover must find values.
+    fn prove_goal_with_inference(
+        &self,
+        env: &Env,
+        assumptions: impl ToWcs,
+        goal: impl ToWcs + Debug,
+    ) -> Fallible<()> {
+        let goal: Wcs = goal.to_wcs();
+        let assumptions: Wcs = assumptions.to_wcs();
+
+        assert!(env.encloses((&assumptions, &goal)));
+
+        let cs = formality_prove::prove(self.decls, env, &assumptions, &goal);
+        let cs = cs.into_set().map_err(FailedJudgment::into_summary)?;
+
+        // The remaining outlives relations involve existential lifetimes; check that
+        // some choice of those lifetimes satisfies them given the assumptions.
+        let mut region_error = None;
+        for c in cs.iter().filter(|c| c.known_true) {
+            let outlives = c.universal_outlives()?;
+            let region_cs = formality_prove::prove(self.decls, env, &assumptions, &outlives);
+            match region_cs.into_set() {
+                Ok(region_cs) if region_cs.iter().any(|c| c.unconditionally_true()) => {
+                    return Ok(())
+                }
+                Ok(region_cs) => {
+                    region_error = Some(anyhow::anyhow!(
+                        "failed to prove {outlives:?} given {assumptions:?}, got {region_cs:?}"
+                    ))
+                }
+                Err(e) => region_error = Some(e.into_summary().into()),
+            }
+        }
+
+        if let Some(e) = region_error {
+            return Err(e);
+        }
+
+        bail!("failed to prove {goal:?} given {assumptions:?}, got {cs:?}")
+    }
+
     #[tracing::instrument(level = "Debug", skip(self, assumptions, goal))]
     fn prove_not_goal(&self, env: &Env, assumptions: impl ToWcs, goal: impl ToWcs) -> Fallible<()> {
         let goal: Wcs = goal.to_wcs();
@@ -160,7 +304,7 @@ impl Check<'_> {
             goal.clone(),
             |env, assumptions, goal| formality_prove::prove(self.decls, env, &assumptions, &goal),
         );
-        let cs = cs.into_set()?;
+        let cs = cs.into_set().map_err(FailedJudgment::into_summary)?;
         if cs.iter().any(|c| c.unconditionally_true()) {
             return Ok(());
         }
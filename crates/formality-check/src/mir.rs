@@ -0,0 +1,552 @@
+use anyhow::bail;
+use formality_core::{Downcast, Map, Set, To, Upcast};
+use formality_prove::{Env, LangItem};
+use formality_rust::{
+    grammar::{
+        mir::{
+            AggregateKind, BasicBlockDecl, BasicBlockId, BinaryOp, Constant, LocalDecl, LocalId,
+            LocalsAndBlocks, MirFnBody, Operand, Place, PlaceTy, Projection, Rvalue, Statement,
+            Terminator,
+        },
+        AdtBoundData, Field, FieldName, FnBoundData, Variant, VariantId,
+    },
+    prove::ToWcs,
+};
+use formality_types::grammar::{
+    AdtId, Fallible, Parameter, RefKind, Relation, RigidName, RigidTy, ScalarId, Ty, TyData, Wc,
+    Wcs,
+};
+
+use crate::{Check, CheckError};
+
+mod borrow_check;
+
+impl Check<'_> {
+    /// Type-check the MIR body of a function whose (already instantiated) signature
+    /// is `input_tys -> output_ty`.
+    ///
+    /// By convention, the first local declared in the body is the return place
+    /// and the next `input_tys.len()` locals hold the function arguments.
+    pub(crate) fn check_mir_fn_body(
+        &self,
+        env: &Env,
+        assumptions: impl ToWcs,
+        input_tys: &[Ty],
+        output_ty: &Ty,
+        body: &MirFnBody,
+    ) -> Fallible<()> {
+        let assumptions: Wcs = assumptions.to_wcs();
+
+        // The lifetimes bound by the body are not known up front,
+        // so we leave it to the prover to find values for them.
+        let (env, existentials) = env.existential_substitution(&body.binder);
+        let LocalsAndBlocks {
+            local_decls,
+            basic_block_decls,
+        } = body.binder.instantiate_with(&existentials)?;
+
+        let mut typeck = MirTypeck {
+            check: self,
+            locals: Map::new(),
+            blocks: Set::new(),
+            goals: vec![],
+        };
+
+        for local_decl in &local_decls {
+            if typeck
+                .locals
+                .insert(local_decl.name.clone(), local_decl.clone())
+                .is_some()
+            {
+                bail!(
+                    "the local `{:?}` is declared multiple times",
+                    local_decl.name
+                );
+            }
+            typeck.goals.push(local_decl.ty.well_formed().upcast());
+        }
+        typeck
+            .goals
+            .extend(self.sized_goals(local_decls.iter().map(|l| &l.ty)));
+
+        for BasicBlockDecl { id, .. } in &basic_block_decls {
+            if !typeck.blocks.insert(id.clone()) {
+                bail!("the basic block `{id:?}` is declared multiple times");
+            }
+        }
+
+        if local_decls.len() < 1 + input_tys.len() {
+            bail!(
+                "expected a return place and {} argument local(s), found {} local(s)",
+                input_tys.len(),
+                local_decls.len()
+            );
+        }
+        let return_place_ty = &local_decls[0].ty;
+        for (input_ty, local_decl) in input_tys.iter().zip(&local_decls[1..]) {
+            typeck.push_sub(input_ty, &local_decl.ty);
+        }
+
+        for BasicBlockDecl {
+            id: _,
+            statements,
+            terminator,
+        } in &basic_block_decls
+        {
+            for statement in statements {
+                typeck.check_statement(statement)?;
+            }
+            typeck.check_terminator(terminator, return_place_ty, output_ty)?;
+        }
+
+        let goals: Wcs = typeck.goals.iter().cloned().collect();
+        self.prove_goal_with_inference(&env, &assumptions, goals)?;
+
+        borrow_check::borrow_check(
+            self.decls,
+            &existentials,
+            &local_decls,
+            input_tys.len(),
+            &basic_block_decls,
+            &typeck.goals,
+        )
+    }
+}
+
+/// State accumulated while type-checking a single MIR body.
+///
+/// Type-checking a body produces a set of goals (well-formedness, subtyping, ...)
+/// that are proven all together at the end, so that the values inferred for the
+/// lifetimes bound by the body are consistent across statements.
+struct MirTypeck<'c, 'p> {
+    check: &'c Check<'p>,
+    locals: Map<LocalId, LocalDecl>,
+    blocks: Set<BasicBlockId>,
+    goals: Vec<Wc>,
+}
+
+impl MirTypeck<'_, '_> {
+    fn push_sub(&mut self, a: impl Upcast<Parameter>, b: impl Upcast<Parameter>) {
+        let a: Parameter = a.upcast();
+        let b: Parameter = b.upcast();
+        self.goals.push(Relation::sub(a, b).upcast());
+    }
+
+    fn check_statement(&mut self, statement: &Statement) -> Fallible<()> {
+        match statement {
+            Statement::Assign(place, rvalue) => {
+                let place_ty = self.place_value_ty(place)?;
+                self.check_place_mutability(place, false)?;
+                let rvalue_ty = self.rvalue_ty(rvalue)?;
+                self.push_sub(rvalue_ty, place_ty);
+            }
+            Statement::Noop => {}
+            Statement::FakeRead(place) => {
+                self.place_ty(place)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_terminator(
+        &mut self,
+        terminator: &Terminator,
+        return_place_ty: &Ty,
+        output_ty: &Ty,
+    ) -> Fallible<()> {
+        match terminator {
+            Terminator::Goto(target) => self.check_targets(std::slice::from_ref(target))?,
+            Terminator::Resume | Terminator::Abort | Terminator::Unreachable => {}
+            Terminator::Return => self.push_sub(return_place_ty, output_ty),
+            Terminator::Drop(place, targets) | Terminator::DropAndReplace(place, targets) => {
+                self.place_value_ty(place)?;
+                self.check_targets(targets)?;
+            }
+            Terminator::Call(callee, arguments, destination, targets) => {
+                let callee_ty = self.operand_ty(callee)?;
+                let (input_tys, output_ty) = self.signature(&callee_ty)?;
+                if arguments.len() != input_tys.len() {
+                    bail!(
+                        "`{callee:?}` expects {} argument(s) but {} were supplied",
+                        input_tys.len(),
+                        arguments.len()
+                    );
+                }
+                for (argument, input_ty) in arguments.iter().zip(input_tys) {
+                    let argument_ty = self.operand_ty(argument)?;
+                    self.push_sub(argument_ty, input_ty);
+                }
+                let destination_ty = self.place_value_ty(destination)?;
+                self.check_place_mutability(destination, false)?;
+                self.push_sub(output_ty, destination_ty);
+                self.check_targets(targets)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_targets(&self, targets: &[BasicBlockId]) -> Fallible<()> {
+        for target in targets {
+            if !self.blocks.contains(target) {
+                bail!("no basic block named `{target:?}`");
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the argument and return types of a callable type.
+    fn signature(&mut self, callee_ty: &Ty) -> Fallible<(Vec<Ty>, Ty)> {
+        if let TyData::RigidTy(RigidTy { name, parameters }) = callee_ty.data() {
+            match name {
+                RigidName::FnDef(fn_id) => {
+                    let f = self.check.program.fn_named(fn_id)?;
+                    let FnBoundData {
+                        input_tys,
+                        output_ty,
+                        where_clauses: _,
+                        body: _,
+                    } = f.binder.instantiate_with(parameters)?;
+                    return Ok((input_tys, output_ty));
+                }
+
+                // The parameters of `FnPtr(n)` are the `n` argument types followed by the return type;
+                // those of a closure are followed by the upvar types.
+                RigidName::FnPtr(arity) | RigidName::Closure(_, _, arity) => {
+                    let tys = parameters
+                        .iter()
+                        .take(arity + 1)
+                        .map(|p| p.downcast::<Ty>())
+                        .collect::<Option<Vec<Ty>>>();
+                    if let Some(mut tys) = tys {
+                        if tys.len() == arity + 1 {
+                            let output_ty = tys.pop().unwrap();
+                            return Ok((tys, output_ty));
+                        }
+                    }
+                }
+
+                _ => {}
+            }
+        }
+        bail!("expected a function type, found `{callee_ty:?}`")
+    }
+
+    fn place_ty(&mut self, place: &Place) -> Fallible<PlaceTy> {
+        let Place {
+            local_id,
+            projections,
+        } = place;
+
+        let Some(local_decl) = self.locals.get(local_id) else {
+            bail!("no local named `{local_id:?}`");
+        };
+
+        let mut place_ty: PlaceTy = local_decl.ty.to();
+        for projection in projections {
+            place_ty = self.project(place_ty, projection)?;
+        }
+        Ok(place_ty)
+    }
+
+    /// Type of a place that is read or written as a whole.
+    ///
+    /// A place whose type is a particular enum variant can only be projected from.
+    fn place_value_ty(&mut self, place: &Place) -> Fallible<Ty> {
+        match self.place_ty(place)? {
+            PlaceTy::Ty(ty) => Ok(ty),
+            PlaceTy::VariantTy(ty, variant_id) => {
+                bail!("cannot use variant `{variant_id:?}` of `{ty:?}` as a value")
+            }
+        }
+    }
+
+    /// Check that `place` may be written (or mutably borrowed, if `borrow` is true),
+    /// which it may not be if it is reached through a shared reference.
+    ///
+    /// Whether the local itself is mutable is left to the borrow checker,
+    /// since an immutable local may still be assigned once if it is not yet initialized.
+    fn check_place_mutability(&mut self, place: &Place, borrow: bool) -> Fallible<()> {
+        let Some(local_decl) = self.locals.get(&place.local_id) else {
+            bail!("no local named `{:?}`", place.local_id);
+        };
+
+        let mut place_ty: PlaceTy = local_decl.ty.to();
+        for projection in &place.projections {
+            if let (PlaceTy::Ty(ty), Projection::Deref) = (&place_ty, projection) {
+                if let TyData::RigidTy(RigidTy {
+                    name: RigidName::Ref(RefKind::Shared),
+                    parameters: _,
+                }) = ty.data()
+                {
+                    let place = place.clone();
+                    if borrow {
+                        bail!(CheckError::MutableBorrowBehindSharedRef { place });
+                    }
+                    bail!(CheckError::AssignBehindSharedRef { place });
+                }
+            }
+            place_ty = self.project(place_ty, projection)?;
+        }
+        Ok(())
+    }
+
+    fn project(&mut self, place_ty: PlaceTy, projection: &Projection) -> Fallible<PlaceTy> {
+        match (&place_ty, projection) {
+            (PlaceTy::Ty(ty), Projection::Deref) => {
+                if let TyData::RigidTy(RigidTy {
+                    name: RigidName::Ref(_),
+                    parameters,
+                }) = ty.data()
+                {
+                    if let Some(referent_ty) = parameters[1].downcast::<Ty>() {
+                        return Ok(referent_ty.upcast());
+                    }
+                }
+                bail!("cannot dereference a value of type `{ty:?}`")
+            }
+
+            (PlaceTy::Ty(ty), Projection::Field(field_name)) => {
+                if let TyData::RigidTy(RigidTy { name, parameters }) = ty.data() {
+                    match (name, field_name) {
+                        (RigidName::Tuple(arity), FieldName::Index(index)) if index < arity => {
+                            if let Some(field_ty) = parameters[*index].downcast::<Ty>() {
+                                return Ok(field_ty.upcast());
+                            }
+                        }
+                        (RigidName::AdtId(adt_id), _) => {
+                            let field_ty = self.field_ty(
+                                adt_id,
+                                parameters,
+                                &VariantId::for_struct(),
+                                field_name,
+                            )?;
+                            return Ok(field_ty.upcast());
+                        }
+                        _ => {}
+                    }
+                }
+                bail!("no field `{field_name:?}` in a value of type `{ty:?}`")
+            }
+
+            (PlaceTy::VariantTy(ty, variant_id), Projection::Field(field_name)) => {
+                if let TyData::RigidTy(RigidTy {
+                    name: RigidName::AdtId(adt_id),
+                    parameters,
+                }) = ty.data()
+                {
+                    let field_ty = self.field_ty(adt_id, parameters, variant_id, field_name)?;
+                    return Ok(field_ty.upcast());
+                }
+                bail!("no field `{field_name:?}` in a value of type `{ty:?}`")
+            }
+
+            (PlaceTy::Ty(ty), Projection::Downcast(variant_id)) => {
+                if let TyData::RigidTy(RigidTy {
+                    name: RigidName::AdtId(adt_id),
+                    parameters,
+                }) = ty.data()
+                {
+                    self.variant_fields(adt_id, parameters, variant_id)?;
+                    return Ok(PlaceTy::VariantTy(ty.clone(), variant_id.clone()));
+                }
+                bail!("cannot downcast a value of type `{ty:?}`")
+            }
+
+            (_, Projection::Index(_)) => bail!("index projections are not yet supported"),
+
+            (PlaceTy::VariantTy(..), Projection::Deref | Projection::Downcast(_)) => {
+                bail!("cannot apply `{projection:?}` to `{place_ty:?}`")
+            }
+        }
+    }
+
+    fn variant_fields(
+        &self,
+        adt_id: &AdtId,
+        parameters: &[Parameter],
+        variant_id: &VariantId,
+    ) -> Fallible<Vec<Field>> {
+        let adt = self.check.program.adt_named(adt_id)?;
+        let AdtBoundData {
+            where_clauses: _,
+            variants,
+        } = adt.binder.instantiate_with(parameters)?;
+        match variants.into_iter().find(|v| v.name == *variant_id) {
+            Some(Variant { name: _, fields }) => Ok(fields),
+            None => bail!("no variant `{variant_id:?}` in `{adt_id:?}`"),
+        }
+    }
+
+    fn field_ty(
+        &self,
+        adt_id: &AdtId,
+        parameters: &[Parameter],
+        variant_id: &VariantId,
+        field_name: &FieldName,
+    ) -> Fallible<Ty> {
+        let fields = self.variant_fields(adt_id, parameters, variant_id)?;
+        match fields.into_iter().find(|f| f.name == *field_name) {
+            Some(Field { name: _, ty }) => Ok(ty),
+            None => bail!("no field `{field_name:?}` in `{adt_id:?}`"),
+        }
+    }
+
+    fn operand_ty(&mut self, operand: &Operand) -> Fallible<Ty> {
+        match operand {
+            Operand::Move(place) => self.place_value_ty(place),
+            Operand::Copy(place) => {
+                let ty = self.place_value_ty(place)?;
+                // As with `Sized`, there is nothing to prove if the program declares no `Copy` trait.
+                if let Some(copy) = self.check.decls.lang_item_trait(LangItem::Copy) {
+                    self.goals
+                        .push(copy.with(&ty, Vec::<Parameter>::new()).upcast());
+                }
+                Ok(ty)
+            }
+            Operand::Const(constant) => self.constant_ty(constant),
+        }
+    }
+
+    fn constant_ty(&mut self, constant: &Constant) -> Fallible<Ty> {
+        match constant {
+            Constant::Number(_, ScalarId::Bool) => {
+                bail!("numeric constants cannot have type `bool`")
+            }
+            Constant::Number(_, scalar_id) => Ok(scalar_id.upcast()),
+            Constant::True | Constant::False => Ok(Ty::bool()),
+            Constant::FnPtr(fn_id, parameters) => {
+                let f = self.check.program.fn_named(fn_id)?;
+                let FnBoundData {
+                    input_tys: _,
+                    output_ty: _,
+                    where_clauses,
+                    body: _,
+                } = f.binder.instantiate_with(parameters)?;
+                self.goals.extend(where_clauses.to_wcs());
+                for parameter in parameters {
+                    self.goals.push(parameter.well_formed().upcast());
+                }
+                Ok(Ty::rigid(RigidName::FnDef(fn_id.clone()), parameters))
+            }
+            Constant::Tuple(constants) => {
+                let tys = constants
+                    .iter()
+                    .map(|c| self.constant_ty(c))
+                    .collect::<Fallible<Vec<Ty>>>()?;
+                Ok(Ty::rigid(RigidName::Tuple(tys.len()), tys))
+            }
+        }
+    }
+
+    fn rvalue_ty(&mut self, rvalue: &Rvalue) -> Fallible<Ty> {
+        match rvalue {
+            Rvalue::Use(operand) => self.operand_ty(operand),
+
+            Rvalue::Ref(lt, ref_kind, place) => {
+                let referent_ty = self.place_value_ty(place)?;
+                if let RefKind::Mut = ref_kind {
+                    self.check_place_mutability(place, true)?;
+                }
+                Ok(Ty::rigid(
+                    ref_kind,
+                    vec![lt.to::<Parameter>(), referent_ty.to::<Parameter>()],
+                ))
+            }
+
+            Rvalue::Apply(a, op, b) => {
+                let ty = self.binary_operand_ty(a, op, b)?;
+                match op {
+                    BinaryOp::Math(_) => Ok(ty),
+                    BinaryOp::Comparison(_) => Ok(Ty::bool()),
+                }
+            }
+
+            Rvalue::Checked(a, op, b) => {
+                if let BinaryOp::Comparison(_) = op {
+                    bail!("checked comparisons are not supported: `{rvalue:?}`");
+                }
+                let ty = self.binary_operand_ty(a, op, b)?;
+                Ok(Ty::rigid(RigidName::Tuple(2), vec![ty, Ty::bool()]))
+            }
+
+            Rvalue::Aggregate(AggregateKind::Tuple, operands) => {
+                let tys = operands
+                    .iter()
+                    .map(|o| self.operand_ty(o))
+                    .collect::<Fallible<Vec<Ty>>>()?;
+                Ok(Ty::rigid(RigidName::Tuple(tys.len()), tys))
+            }
+
+            Rvalue::Aggregate(AggregateKind::Struct(adt_id, parameters), operands) => {
+                self.adt_aggregate_ty(adt_id, &VariantId::for_struct(), parameters, operands)
+            }
+
+            Rvalue::Aggregate(AggregateKind::Adt(adt_id, variant_id, parameters), operands) => {
+                self.adt_aggregate_ty(adt_id, variant_id, parameters, operands)
+            }
+
+            Rvalue::Cast(operand, ty) => {
+                let operand_ty = self.operand_ty(operand)?;
+                if scalar_id(&operand_ty).is_none() || scalar_id(ty).is_none() {
+                    bail!("cannot cast `{operand_ty:?}` to `{ty:?}`");
+                }
+                Ok(ty.clone())
+            }
+
+            Rvalue::Repeat(..) | Rvalue::Len(_) => {
+                bail!("array types are not yet supported: `{rvalue:?}`")
+            }
+
+            Rvalue::AddrOf(..) => bail!("raw pointers are not yet supported: `{rvalue:?}`"),
+        }
+    }
+
+    fn adt_aggregate_ty(
+        &mut self,
+        adt_id: &AdtId,
+        variant_id: &VariantId,
+        parameters: &[Parameter],
+        operands: &[Operand],
+    ) -> Fallible<Ty> {
+        let fields = self.variant_fields(adt_id, parameters, variant_id)?;
+        if fields.len() != operands.len() {
+            bail!(
+                "variant `{variant_id:?}` of `{adt_id:?}` has {} field(s) but {} were supplied",
+                fields.len(),
+                operands.len()
+            );
+        }
+        for (Field { name: _, ty }, operand) in fields.into_iter().zip(operands) {
+            let operand_ty = self.operand_ty(operand)?;
+            self.push_sub(operand_ty, ty);
+        }
+        let adt_ty = Ty::rigid(adt_id, parameters);
+        self.goals.push(adt_ty.well_formed().upcast());
+        Ok(adt_ty)
+    }
+
+    /// Both operands of a binary operator must have the same scalar type,
+    /// which must be an integer type for arithmetic.
+    fn binary_operand_ty(&mut self, a: &Operand, op: &BinaryOp, b: &Operand) -> Fallible<Ty> {
+        let a_ty = self.operand_ty(a)?;
+        let b_ty = self.operand_ty(b)?;
+        match (scalar_id(&a_ty), op) {
+            (None, _) | (Some(ScalarId::Bool), BinaryOp::Math(_)) => {
+                bail!("cannot apply `{op:?}` to a value of type `{a_ty:?}`")
+            }
+            (Some(_), _) => {}
+        }
+        self.goals.push(Relation::equals(&a_ty, &b_ty).upcast());
+        Ok(a_ty)
+    }
+}
+
+fn scalar_id(ty: &Ty) -> Option<ScalarId> {
+    match ty.data() {
+        TyData::RigidTy(RigidTy {
+            name: RigidName::ScalarId(scalar_id),
+            parameters: _,
+        }) => Some(scalar_id.clone()),
+        _ => None,
+    }
+}
@@ -0,0 +1,641 @@
+//! A simple, location-sensitive borrow checker for MIR bodies.
+//!
+//! The model is a simplified version of NLL:
+//!
+//! * every `Rvalue::Ref(lt, kind, place)` issues a **loan** of `place` whose region is `lt`;
+//! * the subtyping goals produced by the type checker relate the regions of the body
+//!   (e.g., assigning a `&'a u32` to a local of type `&'b u32` yields `'a: 'b`);
+//! * a region is live at a point if it appears in the type of a local that is live at
+//!   that point, if it outlives such a region, or if it is not one of the regions
+//!   bound by the body (e.g., `'static` or a lifetime parameter of the function);
+//! * a loan is in scope at a point if it may have been issued on some path reaching
+//!   that point and its region is live there.
+//!
+//! Accesses to places are then checked against the loans in scope and against the
+//! set of places that may have been moved out (or never initialized). Writes and
+//! mutable borrows are also checked against the mutability of the local they access,
+//! unless they go through a (mutable) reference; the type checker has already rejected
+//! those that go through a shared reference.
+
+use anyhow::bail;
+use formality_core::{Map, Set, Upcasted};
+use formality_prove::Decls;
+use formality_rust::grammar::mir::{
+    BasicBlockDecl, BasicBlockId, LocalDecl, LocalId, Operand, Place, Projection, Rvalue,
+    Statement, Terminator,
+};
+use formality_types::grammar::{
+    ExistentialVar, Fallible, Lt, LtData, Parameter, RefKind, Relation, RigidTy, Ty, TyData,
+    Variable, Variance, Wc, WcData,
+};
+
+use crate::CheckError;
+
+/// Borrow-check a MIR body whose local declarations and blocks have been instantiated
+/// with the existential lifetimes `existentials`. The `goals` are the goals produced
+/// when type-checking the body, from which we recover the relationships between regions.
+pub(super) fn borrow_check(
+    decls: &Decls,
+    existentials: &[ExistentialVar],
+    local_decls: &[LocalDecl],
+    num_arguments: usize,
+    basic_block_decls: &[BasicBlockDecl],
+    goals: &[Wc],
+) -> Fallible<()> {
+    let Some(entry_block) = basic_block_decls.first() else {
+        return Ok(());
+    };
+
+    let mut outlives = Map::new();
+    for goal in goals {
+        if let WcData::Relation(Relation::Sub(a, b)) = goal.data() {
+            relate_regions(decls, a, b, Variance::Covariant, &mut outlives);
+        }
+    }
+
+    let bck = BorrowCheck {
+        existentials: existentials.iter().upcasted().collect(),
+        return_local: local_decls[0].name.clone(),
+        local_decls: local_decls
+            .iter()
+            .map(|decl| (decl.name.clone(), decl))
+            .collect(),
+        blocks: basic_block_decls
+            .iter()
+            .map(|block| (block.id.clone(), block))
+            .collect(),
+        outlives,
+    };
+
+    let liveness = bck.liveness();
+
+    // Only the arguments are initialized on entry.
+    let uninitialized = local_decls
+        .iter()
+        .enumerate()
+        .filter(|&(index, _)| index == 0 || index > num_arguments)
+        .map(|(_, decl)| Place {
+            local_id: decl.name.clone(),
+            projections: vec![],
+        })
+        .collect();
+    let entry_state = FlowState {
+        moved: uninitialized,
+        assigned: local_decls[1..=num_arguments]
+            .iter()
+            .map(|decl| decl.name.clone())
+            .collect(),
+        loans: Set::new(),
+    };
+    let entry_states = bck.flow_states(&entry_block.id, entry_state);
+
+    for block in basic_block_decls {
+        // Blocks that are not reachable from the entry have no entry state.
+        if let Some(state) = entry_states.get(&block.id) {
+            bck.check_block(block, &liveness[&block.id], state.clone())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Given that `a` is related to `b` with `variance` (e.g., `a <: b` if covariant),
+/// record the resulting relationships between regions in `outlives`.
+/// An entry `b => {a}` means that `a: b`.
+fn relate_regions(
+    decls: &Decls,
+    a: &Parameter,
+    b: &Parameter,
+    variance: Variance,
+    outlives: &mut Map<Lt, Set<Lt>>,
+) {
+    match (a, b) {
+        (Parameter::Lt(a), Parameter::Lt(b)) => {
+            if variance != Variance::Contravariant {
+                outlives.entry(b.clone()).or_default().insert(a.clone());
+            }
+            if variance != Variance::Covariant {
+                outlives.entry(a.clone()).or_default().insert(b.clone());
+            }
+        }
+        (Parameter::Ty(a), Parameter::Ty(b)) => {
+            let (TyData::RigidTy(a), TyData::RigidTy(b)) = (a.data(), b.data()) else {
+                return;
+            };
+            if a.name != b.name || a.parameters.len() != b.parameters.len() {
+                return;
+            }
+            let variances = decls.variances(&a.name, a.parameters.len());
+            for ((a, b), v) in a.parameters.iter().zip(&b.parameters).zip(variances) {
+                relate_regions(decls, a, b, variance.xform(v), outlives);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Collect the regions that appear in `p`.
+fn regions_in(p: &Parameter, regions: &mut Set<Lt>) {
+    match p {
+        Parameter::Lt(lt) => {
+            regions.insert(lt.clone());
+        }
+        Parameter::Ty(ty) => match ty.data() {
+            TyData::RigidTy(RigidTy {
+                name: _,
+                parameters,
+            }) => parameters.iter().for_each(|p| regions_in(p, regions)),
+            TyData::AliasTy(alias) => alias.parameters.iter().for_each(|p| regions_in(p, regions)),
+            TyData::PredicateTy(_) | TyData::Variable(_) => {}
+        },
+        Parameter::Const(_) => {}
+    }
+}
+
+struct BorrowCheck<'b> {
+    /// The lifetimes bound by the MIR body; all other lifetimes outlive the body.
+    existentials: Set<Variable>,
+    /// The first local, which holds the return value.
+    return_local: LocalId,
+    local_decls: Map<LocalId, &'b LocalDecl>,
+    blocks: Map<BasicBlockId, &'b BasicBlockDecl>,
+    /// `outlives[b]` is the set of regions `a` where `a: b`.
+    outlives: Map<Lt, Set<Lt>>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+struct Loan {
+    block: BasicBlockId,
+    statement_index: usize,
+    place: Place,
+    kind: RefKind,
+    region: Lt,
+}
+
+/// The forward dataflow state at some point in the body.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+struct FlowState {
+    /// Places that may have been moved out of (or not yet initialized).
+    moved: Set<Place>,
+    /// Locals that may have been assigned (even if they have since been moved out of).
+    assigned: Set<LocalId>,
+    /// Loans that may have been issued.
+    loans: Set<Loan>,
+}
+
+impl FlowState {
+    fn union(&mut self, other: &FlowState) -> bool {
+        let len = (self.moved.len(), self.assigned.len(), self.loans.len());
+        self.moved.extend(other.moved.iter().cloned());
+        self.assigned.extend(other.assigned.iter().cloned());
+        self.loans.extend(other.loans.iter().cloned());
+        len != (self.moved.len(), self.assigned.len(), self.loans.len())
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum AccessKind {
+    Copy,
+    Move,
+    Borrow(RefKind),
+    Write,
+    Drop,
+}
+
+/// Accesses performed as part of a statement or terminator are checked either
+/// at the start of it (e.g., reading operands) or at its end (e.g., writing the destination).
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum AccessPoint {
+    Start,
+    End,
+}
+
+struct Access {
+    place: Place,
+    kind: AccessKind,
+    point: AccessPoint,
+}
+
+/// The locals that are live before each statement (and before the terminator) of a block,
+/// followed by the locals live on exit from the block.
+type BlockLiveness = Vec<Set<LocalId>>;
+
+impl BorrowCheck<'_> {
+    fn successors(terminator: &Terminator) -> Vec<BasicBlockId> {
+        match terminator {
+            Terminator::Goto(target) => vec![target.clone()],
+            Terminator::Drop(_, targets)
+            | Terminator::DropAndReplace(_, targets)
+            | Terminator::Call(_, _, _, targets) => targets.clone(),
+            Terminator::Resume
+            | Terminator::Abort
+            | Terminator::Return
+            | Terminator::Unreachable => vec![],
+        }
+    }
+
+    /// The accesses performed by each statement of `block`, followed by those performed
+    /// by its terminator.
+    fn accesses(&self, block: &BasicBlockDecl) -> Vec<Vec<Access>> {
+        let mut result = vec![];
+        for statement in &block.statements {
+            let mut accesses = vec![];
+            match statement {
+                Statement::Assign(place, rvalue) => {
+                    rvalue_accesses(rvalue, &mut accesses);
+                    accesses.push(Access {
+                        place: place.clone(),
+                        kind: AccessKind::Write,
+                        point: AccessPoint::End,
+                    });
+                }
+                Statement::FakeRead(place) => accesses.push(Access {
+                    place: place.clone(),
+                    kind: AccessKind::Copy,
+                    point: AccessPoint::Start,
+                }),
+                Statement::Noop => {}
+            }
+            result.push(accesses);
+        }
+
+        let mut accesses = vec![];
+        match &block.terminator {
+            Terminator::Goto(_)
+            | Terminator::Resume
+            | Terminator::Abort
+            | Terminator::Unreachable => {}
+            Terminator::Return => accesses.push(Access {
+                place: Place {
+                    local_id: self.return_local.clone(),
+                    projections: vec![],
+                },
+                kind: AccessKind::Copy,
+                point: AccessPoint::Start,
+            }),
+            Terminator::Drop(place, _) | Terminator::DropAndReplace(place, _) => {
+                accesses.push(Access {
+                    place: place.clone(),
+                    kind: AccessKind::Drop,
+                    point: AccessPoint::Start,
+                })
+            }
+            Terminator::Call(callee, arguments, destination, _) => {
+                operand_access(callee, &mut accesses);
+                for argument in arguments {
+                    operand_access(argument, &mut accesses);
+                }
+                accesses.push(Access {
+                    place: destination.clone(),
+                    kind: AccessKind::Write,
+                    point: AccessPoint::End,
+                });
+            }
+        }
+        result.push(accesses);
+
+        result
+    }
+
+    /// Compute the live locals at each point of each block.
+    fn liveness(&self) -> Map<BasicBlockId, BlockLiveness> {
+        let accesses: Map<BasicBlockId, Vec<Vec<Access>>> = self
+            .blocks
+            .iter()
+            .map(|(id, block)| (id.clone(), self.accesses(block)))
+            .collect();
+
+        let mut live_in: Map<BasicBlockId, Set<LocalId>> = Map::new();
+        let mut result = Map::new();
+        loop {
+            let mut changed = false;
+            for (id, block) in &self.blocks {
+                let mut live: Set<LocalId> = Set::new();
+                for successor in Self::successors(&block.terminator) {
+                    live.extend(live_in.get(&successor).into_iter().flatten().cloned());
+                }
+
+                let mut block_liveness = vec![live.clone()];
+                for statement_accesses in accesses[id].iter().rev() {
+                    // A write to a whole local kills it; every other access uses the local.
+                    for access in statement_accesses {
+                        if access.kind == AccessKind::Write && access.place.projections.is_empty() {
+                            live.remove(&access.place.local_id);
+                        }
+                    }
+                    for access in statement_accesses {
+                        if access.kind != AccessKind::Write || !access.place.projections.is_empty()
+                        {
+                            live.insert(access.place.local_id.clone());
+                        }
+                    }
+                    block_liveness.push(live.clone());
+                }
+                block_liveness.reverse();
+
+                if live_in.get(id) != Some(&live) {
+                    live_in.insert(id.clone(), live);
+                    changed = true;
+                }
+                result.insert(id.clone(), block_liveness);
+            }
+            if !changed {
+                return result;
+            }
+        }
+    }
+
+    /// Compute the forward dataflow state on entry to each block reachable from `entry`.
+    fn flow_states(
+        &self,
+        entry: &BasicBlockId,
+        entry_state: FlowState,
+    ) -> Map<BasicBlockId, FlowState> {
+        let mut states: Map<BasicBlockId, FlowState> = Map::new();
+        states.insert(entry.clone(), entry_state);
+        let mut worklist = vec![entry.clone()];
+        while let Some(id) = worklist.pop() {
+            let block = self.blocks[&id];
+            let mut state = states[&id].clone();
+            for (statement_index, statement_accesses) in
+                self.accesses(block).into_iter().enumerate()
+            {
+                for access in &statement_accesses {
+                    self.apply_access(&id, statement_index, access, &mut state);
+                }
+            }
+            for successor in Self::successors(&block.terminator) {
+                let changed = match states.get_mut(&successor) {
+                    Some(successor_state) => successor_state.union(&state),
+                    None => {
+                        states.insert(successor.clone(), state.clone());
+                        true
+                    }
+                };
+                if changed {
+                    worklist.push(successor);
+                }
+            }
+        }
+        states
+    }
+
+    fn apply_access(
+        &self,
+        block: &BasicBlockId,
+        statement_index: usize,
+        access: &Access,
+        state: &mut FlowState,
+    ) {
+        let Access {
+            place,
+            kind,
+            point: _,
+        } = access;
+        match kind {
+            AccessKind::Copy => {}
+            AccessKind::Move | AccessKind::Drop => {
+                state.moved.insert(place.clone());
+            }
+            AccessKind::Write => {
+                // Writing to a place (re-)initializes it and everything within it.
+                state.moved.retain(|m| !is_prefix(place, m));
+                if place.projections.is_empty() {
+                    state.assigned.insert(place.local_id.clone());
+                }
+            }
+            AccessKind::Borrow(ref_kind) => {
+                let region = self
+                    .loan_region(block, statement_index)
+                    .expect("borrow without a `ref` rvalue");
+                state.loans.insert(Loan {
+                    block: block.clone(),
+                    statement_index,
+                    place: place.clone(),
+                    kind: ref_kind.clone(),
+                    region,
+                });
+            }
+        }
+    }
+
+    fn loan_region(&self, block: &BasicBlockId, statement_index: usize) -> Option<Lt> {
+        match self.blocks[block].statements.get(statement_index)? {
+            Statement::Assign(_, Rvalue::Ref(lt, _, _)) => Some(lt.clone()),
+            _ => None,
+        }
+    }
+
+    /// The regions that are live when the locals `live_locals` are live.
+    fn live_regions(&self, live_locals: &Set<LocalId>) -> Set<Lt> {
+        let mut regions = Set::new();
+        for local_id in live_locals {
+            let ty: &Ty = &self.local_decls[local_id].ty;
+            regions_in(&ty.to_parameter(), &mut regions);
+        }
+
+        // Regions not bound by the body outlive the entire body.
+        regions.extend(
+            self.outlives
+                .values()
+                .flatten()
+                .chain(self.outlives.keys())
+                .filter(|lt| !self.is_local_region(lt))
+                .cloned(),
+        );
+
+        let mut stack: Vec<Lt> = regions.iter().cloned().collect();
+        while let Some(lt) = stack.pop() {
+            for a in self.outlives.get(&lt).into_iter().flatten() {
+                if regions.insert(a.clone()) {
+                    stack.push(a.clone());
+                }
+            }
+        }
+        regions
+    }
+
+    fn is_local_region(&self, lt: &Lt) -> bool {
+        match lt.data() {
+            LtData::Static => false,
+            LtData::Variable(v) => self.existentials.contains(v),
+        }
+    }
+
+    fn check_block(
+        &self,
+        block: &BasicBlockDecl,
+        liveness: &BlockLiveness,
+        mut state: FlowState,
+    ) -> Fallible<()> {
+        for (statement_index, statement_accesses) in self.accesses(block).into_iter().enumerate() {
+            for access in &statement_accesses {
+                let live_locals = match access.point {
+                    AccessPoint::Start => &liveness[statement_index],
+                    AccessPoint::End => &liveness[statement_index + 1],
+                };
+                let live_regions = self.live_regions(live_locals);
+                let loans_in_scope: Vec<&Loan> = state
+                    .loans
+                    .iter()
+                    .filter(|loan| {
+                        !self.is_local_region(&loan.region) || live_regions.contains(&loan.region)
+                    })
+                    .collect();
+                self.check_access(access, &state, &loans_in_scope)?;
+                self.apply_access(&block.id, statement_index, access, &mut state);
+            }
+        }
+        Ok(())
+    }
+
+    fn check_access(
+        &self,
+        access: &Access,
+        state: &FlowState,
+        loans_in_scope: &[&Loan],
+    ) -> Fallible<()> {
+        let Access {
+            place,
+            kind,
+            point: _,
+        } = access;
+
+        match kind {
+            AccessKind::Copy | AccessKind::Move | AccessKind::Borrow(_) => {
+                if let Some(m) = state.moved.iter().find(|m| overlaps(m, place)) {
+                    bail!(CheckError::UseOfMoved {
+                        moved: m.clone(),
+                        place: place.clone(),
+                    });
+                }
+            }
+            AccessKind::Write | AccessKind::Drop => {}
+        }
+
+        // Places behind a reference are as mutable as the reference allows,
+        // whether or not the local holding it is mutable.
+        let decl = self.local_decls[&place.local_id];
+        if decl.mutability == RefKind::Shared && !place.projections.contains(&Projection::Deref) {
+            match kind {
+                AccessKind::Borrow(RefKind::Mut) => {
+                    bail!(CheckError::MutableBorrowOfImmutableLocal {
+                        place: place.clone(),
+                        local_id: place.local_id.clone(),
+                    });
+                }
+                AccessKind::Write if !place.projections.is_empty() => {
+                    bail!(CheckError::AssignToImmutableLocal {
+                        place: place.clone(),
+                        local_id: place.local_id.clone(),
+                    });
+                }
+                AccessKind::Write if state.assigned.contains(&place.local_id) => {
+                    bail!(CheckError::AssignTwiceToImmutableLocal {
+                        local_id: place.local_id.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        for loan in loans_in_scope {
+            if !overlaps(&loan.place, place) {
+                continue;
+            }
+            match (kind, &loan.kind) {
+                (AccessKind::Copy, RefKind::Shared)
+                | (AccessKind::Borrow(RefKind::Shared), RefKind::Shared) => {}
+                (AccessKind::Copy, RefKind::Mut) => {
+                    bail!(CheckError::UseWhileMutablyBorrowed {
+                        place: place.clone()
+                    })
+                }
+                (AccessKind::Borrow(RefKind::Shared), RefKind::Mut) => {
+                    bail!(CheckError::ConflictingBorrow {
+                        place: place.clone(),
+                        mutable: false,
+                    })
+                }
+                (AccessKind::Borrow(RefKind::Mut), RefKind::Shared) => {
+                    bail!(CheckError::ConflictingBorrow {
+                        place: place.clone(),
+                        mutable: true,
+                    })
+                }
+                (AccessKind::Borrow(RefKind::Mut), RefKind::Mut) => {
+                    bail!(CheckError::MultipleMutableBorrows {
+                        place: place.clone()
+                    })
+                }
+                (AccessKind::Move, _) => {
+                    bail!(CheckError::MoveWhileBorrowed {
+                        place: place.clone()
+                    })
+                }
+                (AccessKind::Write, _) => {
+                    bail!(CheckError::AssignWhileBorrowed {
+                        place: place.clone()
+                    })
+                }
+                (AccessKind::Drop, _) => {
+                    bail!(CheckError::DropWhileBorrowed {
+                        place: place.clone()
+                    })
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn rvalue_accesses(rvalue: &Rvalue, accesses: &mut Vec<Access>) {
+    match rvalue {
+        Rvalue::Use(operand) | Rvalue::Repeat(operand, _) | Rvalue::Cast(operand, _) => {
+            operand_access(operand, accesses)
+        }
+        Rvalue::Apply(a, _, b) | Rvalue::Checked(a, _, b) => {
+            operand_access(a, accesses);
+            operand_access(b, accesses);
+        }
+        Rvalue::Aggregate(_, operands) => {
+            for operand in operands {
+                operand_access(operand, accesses);
+            }
+        }
+        Rvalue::Ref(_, kind, place) => accesses.push(Access {
+            place: place.clone(),
+            kind: AccessKind::Borrow(kind.clone()),
+            point: AccessPoint::Start,
+        }),
+        Rvalue::AddrOf(_, place) | Rvalue::Len(place) => accesses.push(Access {
+            place: place.clone(),
+            kind: AccessKind::Copy,
+            point: AccessPoint::Start,
+        }),
+    }
+}
+
+fn operand_access(operand: &Operand, accesses: &mut Vec<Access>) {
+    let (place, kind) = match operand {
+        Operand::Move(place) => (place, AccessKind::Move),
+        Operand::Copy(place) => (place, AccessKind::Copy),
+        Operand::Const(_) => return,
+    };
+    accesses.push(Access {
+        place: place.clone(),
+        kind,
+        point: AccessPoint::Start,
+    });
+}
+
+/// True if `prefix` is a prefix of (or equal to) `place`.
+fn is_prefix(prefix: &Place, place: &Place) -> bool {
+    prefix.local_id == place.local_id && place.projections.starts_with(&prefix.projections)
+}
+
+/// Two places overlap if one of them is contained in the other.
+fn overlaps(a: &Place, b: &Place) -> bool {
+    is_prefix(a, b) || is_prefix(b, a)
+}
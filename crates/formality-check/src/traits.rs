@@ -1,35 +1,77 @@
-use anyhow::bail;
+use anyhow::{bail, Context};
 use fn_error_context::context;
-use formality_core::Set;
-use formality_prove::Env;
-use formality_rust::grammar::{
-    AssociatedTy, AssociatedTyBoundData, Fn, Trait, TraitBoundData, TraitItem, WhereClause,
+use formality_core::{visit::CoreVisit, Downcast, Downcasted, Set, Upcast};
+use formality_prove::{Auto, Env};
+use formality_rust::{
+    grammar::{
+        AssociatedTy, AssociatedTyBoundData, Fn, FnBoundData, MaybeAssociatedTyDefault, Trait,
+        TraitBoundData, TraitItem, WhereClause,
+    },
+    prove::ToWcs,
 };
-use formality_types::grammar::Fallible;
+use formality_types::grammar::{
+    AliasName, AliasTy, Fallible, Parameter, Predicate, Relation, RigidName, RigidTy, TraitId,
+    TraitRef, Ty, TyData, Variable, Wcs,
+};
+
+use crate::{fns::implied_outlives_bounds, CheckError};
 
 impl super::Check<'_> {
     #[context("check_trait({:?})", t.id)]
     pub(super) fn check_trait(&self, t: &Trait) -> Fallible<()> {
         let Trait {
+            lang_item: _,
             safety: _,
-            id: _,
+            auto,
+            coinductive: _,
+            id,
             binder,
         } = t;
-        let mut env = Env::default();
+        let (env, trait_vars) = Env::default().universal_substitution(&binder.explicit_binder);
 
         let TraitBoundData {
             where_clauses,
             trait_items,
-        } = env.instantiate_universally(&binder.explicit_binder);
+        } = binder.explicit_binder.instantiate_with(&trait_vars)?;
+
+        if let Auto::Yes = auto {
+            self.check_auto_trait(binder.explicit_binder.len(), &where_clauses, &trait_items)?;
+        }
 
         self.check_trait_items_have_unique_names(&trait_items)?;
 
         self.prove_where_clauses_well_formed(&env, &where_clauses, &where_clauses)?;
 
+        // Within its items, the trait is known to be implemented for `Self`.
+        let trait_ref = id.with(trait_vars[0], &trait_vars[1..]);
+        let trait_assumptions = (&trait_ref, &where_clauses).to_wcs();
         for trait_item in &trait_items {
-            self.check_trait_item(&env, &where_clauses, trait_item)?;
+            self.check_trait_item(&env, &trait_ref, &trait_assumptions, trait_item)?;
         }
 
+        self.check_gat_required_bounds(&env, &trait_ref, &trait_assumptions, &trait_items)?;
+
+        Ok(())
+    }
+
+    /// Auto traits are implemented structurally, so they cannot have generics,
+    /// where-clauses, or items that such impls would need to account for.
+    fn check_auto_trait(
+        &self,
+        num_parameters: usize,
+        where_clauses: &[WhereClause],
+        trait_items: &[TraitItem],
+    ) -> Fallible<()> {
+        // The first parameter is always `Self`.
+        if num_parameters > 1 {
+            bail!(CheckError::AutoTraitWithGenerics);
+        }
+        if !where_clauses.is_empty() {
+            bail!(CheckError::AutoTraitWithWhereClauses);
+        }
+        if !trait_items.is_empty() {
+            bail!(CheckError::AutoTraitWithItems);
+        }
         Ok(())
     }
 
@@ -40,16 +82,19 @@ impl super::Check<'_> {
             match trait_item {
                 TraitItem::Fn(f) => {
                     if !functions.insert(&f.id) {
-                        bail!("the function name `{:?}` is defined multiple times", f.id);
+                        bail!(CheckError::DuplicateAssociatedItem {
+                            kind: "function",
+                            name: format!("{:?}", f.id),
+                        });
                     }
                 }
                 TraitItem::AssociatedTy(associated_ty) => {
                     let AssociatedTy { id, .. } = associated_ty;
                     if !associated_types.insert(id) {
-                        bail!(
-                            "the associated type name `{:?}` is defined multiple times",
-                            id
-                        );
+                        bail!(CheckError::DuplicateAssociatedItem {
+                            kind: "associated type",
+                            name: format!("{:?}", id),
+                        });
                     }
                 }
             }
@@ -60,41 +105,215 @@ impl super::Check<'_> {
     fn check_trait_item(
         &self,
         env: &Env,
-        where_clauses: &[WhereClause],
+        trait_ref: &TraitRef,
+        trait_assumptions: &Wcs,
         trait_item: &TraitItem,
     ) -> Fallible<()> {
         match trait_item {
-            TraitItem::Fn(v) => self.check_fn_in_trait(env, where_clauses, v),
-            TraitItem::AssociatedTy(v) => self.check_associated_ty(env, where_clauses, v),
+            TraitItem::Fn(v) => self.check_fn_in_trait(env, trait_assumptions, v),
+            TraitItem::AssociatedTy(v) => {
+                self.check_associated_ty(env, trait_ref, trait_assumptions, v)
+            }
         }
     }
 
-    fn check_fn_in_trait(&self, env: &Env, where_clauses: &[WhereClause], f: &Fn) -> Fallible<()> {
-        self.check_fn(env, where_clauses, f)
+    fn check_fn_in_trait(&self, env: &Env, trait_assumptions: &Wcs, f: &Fn) -> Fallible<()> {
+        self.check_fn(env, trait_assumptions, f, true)
     }
 
     fn check_associated_ty(
         &self,
         trait_env: &Env,
-        trait_where_clauses: &[WhereClause],
+        trait_ref: &TraitRef,
+        trait_assumptions: &Wcs,
         associated_ty: &AssociatedTy,
     ) -> Fallible<()> {
-        let mut env = trait_env.clone();
-
-        let AssociatedTy { id: _, binder } = associated_ty;
+        let AssociatedTy { id, binder } = associated_ty;
+        let (env, assoc_vars) = trait_env.universal_substitution(binder);
         let AssociatedTyBoundData {
-            ensures: _,
+            ensures,
             where_clauses,
-        } = env.instantiate_universally(binder);
+            default,
+        } = binder.instantiate_with(&assoc_vars)?;
 
         self.prove_where_clauses_well_formed(
             &env,
-            (trait_where_clauses, &where_clauses),
+            (trait_assumptions, &where_clauses),
             &where_clauses,
         )?;
 
-        // FIXME: Do we prove ensures WF? And what do we assume when we do so?
+        // The bounds must be well-formed for the alias `<Self as Trait<..>>::Item<..>`.
+        // We assume the where-clauses of the trait and of the associated type; the bounds
+        // themselves are known to hold for the alias (see the "alias bound" rule), so e.g.
+        // the supertraits of a bound are implied by the bound.
+        let alias = AliasTy::associated_ty(
+            &trait_ref.trait_id,
+            id,
+            assoc_vars.len(),
+            (&trait_ref.parameters, &assoc_vars),
+        );
+        let ensures_wf: Wcs = ensures.iter().flat_map(|e| e.well_formed(&alias)).collect();
+        self.prove_goal(&env, (trait_assumptions, &where_clauses), ensures_wf)?;
+
+        // The default type must be usable by any impl that omits the associated type,
+        // so it is checked just like an impl's value would be.
+        if let MaybeAssociatedTyDefault::DefaultTy(default_ty) = default {
+            self.prove_goal(
+                &env,
+                (trait_assumptions, &where_clauses),
+                default_ty.well_formed(),
+            )?;
+
+            let ensures: Wcs = ensures.iter().map(|e| e.to_wc(&default_ty)).collect();
+            self.prove_goal(&env, (trait_assumptions, &where_clauses), ensures)?;
+        }
 
         Ok(())
     }
+
+    /// A generic associated type must declare the outlives bounds that the fns of the trait
+    /// rely on when using it. For example, given `fn next<'a>(&'a mut self) -> Self::Item<'a>`,
+    /// the signature implies `Self: 'a`, so `type Item<'a>` must have `where Self: 'a`;
+    /// otherwise an impl could give a value for `Item<'a>` that is not well-formed there.
+    ///
+    /// Only bounds between the parameters of the trait and those given to the associated type
+    /// are required, and only for uses of the associated type applied to generic parameters.
+    fn check_gat_required_bounds(
+        &self,
+        env: &Env,
+        trait_ref: &TraitRef,
+        trait_assumptions: &Wcs,
+        trait_items: &[TraitItem],
+    ) -> Fallible<()> {
+        let trait_vars = trait_ref.free_variables();
+        for associated_ty in trait_items.iter().downcasted::<AssociatedTy>() {
+            if associated_ty.binder.is_empty() {
+                continue;
+            }
+
+            for f in trait_items.iter().downcasted::<Fn>() {
+                let mut env = env.clone();
+                let FnBoundData {
+                    input_tys,
+                    output_ty,
+                    where_clauses,
+                    body: _,
+                } = env.instantiate_universally(&f.binder);
+                let candidates: Wcs =
+                    (implied_outlives_bounds(&input_tys), &where_clauses).to_wcs();
+
+                for alias in input_tys
+                    .iter()
+                    .chain(Some(&output_ty))
+                    .flat_map(alias_tys_within)
+                {
+                    let AliasName::AssociatedTyId(name) = &alias.name;
+                    if name.item_id != associated_ty.id || alias.trait_ref() != *trait_ref {
+                        continue;
+                    }
+
+                    let gat_args = &alias.parameters[trait_ref.parameters.len()..];
+                    let gat_vars: Vec<Variable> =
+                        gat_args.iter().filter_map(|p| p.downcast()).collect();
+                    if gat_vars.len() != gat_args.len() {
+                        continue;
+                    }
+
+                    let AssociatedTyBoundData {
+                        ensures: _,
+                        where_clauses: gat_where_clauses,
+                        default: _,
+                    } = associated_ty.binder.instantiate_with(gat_args)?;
+
+                    for bound in &candidates {
+                        let Some(Relation::Outlives(..)) = bound.downcast() else {
+                            continue;
+                        };
+                        let vars = bound.free_variables();
+                        let relates_gat_args = vars.iter().any(|v| gat_vars.contains(v));
+                        let expressible = vars
+                            .iter()
+                            .all(|v| gat_vars.contains(v) || trait_vars.contains(v));
+                        if relates_gat_args
+                            && expressible
+                            && self
+                                .prove_goal(&env, (trait_assumptions, &gat_where_clauses), &bound)
+                                .is_err()
+                        {
+                            bail!(CheckError::MissingRequiredBound {
+                                associated_ty: associated_ty.id.clone(),
+                                fn_id: f.id.clone(),
+                                bound,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the trait of each trait object type `dyn Trait` within `parameters`
+    /// is dyn-compatible (rustc E0038).
+    pub(super) fn check_dyn_compatible(
+        &self,
+        env: &Env,
+        assumptions: impl ToWcs,
+        parameters: impl IntoIterator<Item = impl Upcast<Parameter>>,
+    ) -> Fallible<()> {
+        let assumptions = assumptions.to_wcs();
+        for parameter in parameters {
+            for trait_id in dyn_trait_ids_within(&parameter.upcast()) {
+                self.prove_goal(
+                    env,
+                    &assumptions,
+                    Predicate::DynCompatible(trait_id.clone()),
+                )
+                .context(CheckError::DynIncompatibleTrait { trait_id })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The principal traits of the trait object types that appear within `parameter`, outermost first.
+fn dyn_trait_ids_within(parameter: &Parameter) -> Vec<TraitId> {
+    let Parameter::Ty(ty) = parameter else {
+        return vec![];
+    };
+    let mut trait_ids = vec![];
+    let parameters = match ty.data() {
+        TyData::RigidTy(RigidTy { name, parameters }) => {
+            if let RigidName::DynTrait(dyn_name) = name {
+                trait_ids.push(dyn_name.trait_id.clone());
+            }
+            parameters
+        }
+        TyData::AliasTy(alias) => &alias.parameters,
+        TyData::PredicateTy(_) | TyData::Variable(_) => return trait_ids,
+    };
+    trait_ids.extend(parameters.iter().flat_map(dyn_trait_ids_within));
+    trait_ids
+}
+
+/// The alias types that appear within `ty`, outermost first.
+fn alias_tys_within(ty: &Ty) -> Vec<AliasTy> {
+    let mut aliases = vec![];
+    let parameters = match ty.data() {
+        TyData::RigidTy(RigidTy {
+            name: _,
+            parameters,
+        }) => parameters,
+        TyData::AliasTy(alias) => {
+            aliases.push(alias.clone());
+            &alias.parameters
+        }
+        TyData::PredicateTy(_) | TyData::Variable(_) => return aliases,
+    };
+    for parameter in parameters {
+        if let Parameter::Ty(ty) = parameter {
+            aliases.extend(alias_tys_within(ty));
+        }
+    }
+    aliases
 }
@@ -1,7 +1,12 @@
+use anyhow::{bail, Context};
 use fn_error_context::context;
-use formality_prove::Env;
-use formality_rust::{grammar::WhereClause, prove::ToWcs};
-use formality_types::grammar::{Fallible, Wcs};
+use formality_core::{parse::Location, visit::CoreVisit, Set};
+use formality_prove::{Env, LangItem};
+use formality_rust::{
+    grammar::{WhereClause, WhereClauseData},
+    prove::ToWcs,
+};
+use formality_types::grammar::{Fallible, VarSubstitution, Variable, Wcs};
 
 impl super::Check<'_> {
     #[context("prove_where_clauses_well_formed({where_clauses:?})")]
@@ -11,10 +16,60 @@ impl super::Check<'_> {
         assumptions: impl ToWcs,
         where_clauses: &[WhereClause],
     ) -> Fallible<()> {
-        let wcs: Wcs = where_clauses
+        let sized = self.decls.lang_item_trait(LangItem::Sized);
+        for wc in where_clauses {
+            if let WhereClauseData::MaybeImplemented(_, trait_id) = wc.data() {
+                if sized.as_ref() != Some(trait_id) {
+                    bail!("`?{trait_id:?}` bounds are only permitted for the `Sized` trait");
+                }
+            }
+        }
+
+        let assumptions: Wcs = assumptions.to_wcs();
+        for wc in where_clauses {
+            let result = self.prove_goal(env, &assumptions, wc.well_formed());
+            match self.where_clause_location(wc) {
+                Some(location) => {
+                    result.with_context(|| format!("in the where-clause at {location}"))?
+                }
+                None => result?,
+            }
+        }
+        Ok(())
+    }
+
+    /// The location of `wc` in the input, if the span of the item being checked is known.
+    /// As the variables of `wc` have been instantiated, it is matched against the
+    /// where-clauses in the item up to a renaming of variables.
+    fn where_clause_location(&self, wc: &WhereClause) -> Option<Location> {
+        let item_span = self.current_item_span.get()?;
+        let (span, _) = self
+            .spans
+            .terms_within::<WhereClause>(item_span)
             .into_iter()
-            .flat_map(|wc| wc.well_formed().into_iter())
-            .collect();
-        self.prove_goal(env, assumptions, wcs)
+            .find(|(_, recorded)| equal_up_to_renaming(recorded, wc))?;
+        Some(self.spans.location(span))
     }
 }
+
+/// True if renaming the free variables of `a` gives `b`.
+fn equal_up_to_renaming(a: &WhereClause, b: &WhereClause) -> bool {
+    let (a_vars, b_vars) = (distinct_free_variables(a), distinct_free_variables(b));
+    a_vars.len() == b_vars.len()
+        && a_vars
+            .iter()
+            .zip(&b_vars)
+            .all(|(x, y)| x.kind() == y.kind())
+        && {
+            let renaming: VarSubstitution = a_vars.into_iter().zip(b_vars).collect();
+            renaming.apply(a) == *b
+        }
+}
+
+/// The free variables of `wc`, in order of first occurrence.
+fn distinct_free_variables(wc: &WhereClause) -> Vec<Variable> {
+    let mut vars = wc.free_variables();
+    let mut seen = Set::new();
+    vars.retain(|v| seen.insert(*v));
+    vars
+}
@@ -6,6 +6,46 @@ use std::thread::LocalKey;
 mod stack;
 pub use stack::FixedPointStack;
 
+thread_local! {
+    /// One entry for each fixed-point computation in progress on this thread, across all
+    /// stacks, in the order they began.
+    static IN_PROGRESS: RefCell<Vec<InProgress>> = const { RefCell::new(Vec::new()) };
+}
+
+struct InProgress {
+    /// The depth of the oldest in-progress computation whose (provisional) output this one
+    /// has observed, either directly or through the computations it invoked.
+    observed: usize,
+
+    /// See [`CycleRules::inductive`].
+    inductive: bool,
+}
+
+/// How cycles back to a computation are resolved.
+///
+/// A recursive call to a computation in progress yields its current output. That output
+/// starts out as the default value, so cycles are inductive unless the computation is
+/// coinductive, in which case it starts out as `coinductive_value`. A cycle is only
+/// coinductive if no computation along it, from the one it returns to onward, is
+/// `inductive`; otherwise it starts from the default value like any other.
+pub struct CycleRules<Output> {
+    /// The output that cycles back to this computation start from, if it is coinductive.
+    pub coinductive_value: Option<Output>,
+
+    /// True if cycles through this computation are inductive, even if they return to a
+    /// coinductive computation.
+    pub inductive: bool,
+}
+
+impl<Output> Default for CycleRules<Output> {
+    fn default() -> Self {
+        Self {
+            coinductive_value: None,
+            inductive: false,
+        }
+    }
+}
+
 pub fn fixed_point<Input, Output>(
     tracing_span: impl Fn(&Input) -> tracing::Span,
     storage: &'static LocalKey<RefCell<FixedPointStack<Input, Output>>>,
@@ -13,6 +53,45 @@ pub fn fixed_point<Input, Output>(
     default_value: impl Fn(&Input) -> Output,
     next_value: impl FnMut(Input) -> Output,
 ) -> Output
+where
+    Input: Value,
+    Output: Value,
+{
+    fixed_point_result(
+        tracing_span,
+        storage,
+        args,
+        default_value,
+        |_| CycleRules::default(),
+        next_value,
+    )
+    .output
+}
+
+/// The outcome of [`fixed_point_result`].
+pub struct FixedPointResult<Output> {
+    pub output: Output,
+
+    /// True if `output` does not depend on the provisional output of a computation
+    /// that was still in progress, and hence is the same no matter the context
+    /// in which it is computed. Only final outputs may be cached.
+    pub is_final: bool,
+}
+
+/// Like [`fixed_point`], but also reports whether the output is final.
+///
+/// An output is provisional if computing it observed, through a cycle, the output of
+/// a computation that began earlier and has yet to reach its own fixed point.
+/// Cycles back to the computation itself (or to computations it began) are fine,
+/// since they are resolved by the time it returns.
+pub fn fixed_point_result<Input, Output>(
+    tracing_span: impl Fn(&Input) -> tracing::Span,
+    storage: &'static LocalKey<RefCell<FixedPointStack<Input, Output>>>,
+    args: Input,
+    default_value: impl Fn(&Input) -> Output,
+    cycle_rules: impl Fn(&Input) -> CycleRules<Output>,
+    next_value: impl FnMut(Input) -> Output,
+) -> FixedPointResult<Output>
 where
     Input: Value,
     Output: Value,
@@ -22,13 +101,14 @@ where
             tracing_span,
             storage,
             default_value,
+            cycle_rules,
             next_value,
         }
         .apply(args)
     })
 }
 
-struct FixedPoint<Input, Output, DefaultValue, NextValue, TracingSpan>
+struct FixedPoint<Input, Output, DefaultValue, CycleRulesFn, NextValue, TracingSpan>
 where
     Input: Value,
     Output: Value,
@@ -36,30 +116,71 @@ where
     tracing_span: TracingSpan,
     storage: &'static LocalKey<RefCell<FixedPointStack<Input, Output>>>,
     default_value: DefaultValue,
+    cycle_rules: CycleRulesFn,
     next_value: NextValue,
 }
 
+/// Records that the computation at the top of the in-progress stack has observed
+/// the output of the computation at `depth`.
+fn observe(depth: usize) {
+    IN_PROGRESS.with(|v| {
+        if let Some(top) = v.borrow_mut().last_mut() {
+            top.observed = top.observed.min(depth);
+        }
+    })
+}
+
+/// True if a cycle back to the computation at `depth` passes through an inductive one.
+fn cycle_is_inductive(depth: usize) -> bool {
+    IN_PROGRESS.with(|v| v.borrow()[depth..].iter().any(|p| p.inductive))
+}
+
 pub trait Value: Clone + Eq + Debug + Hash + 'static {}
 impl<T: Clone + Eq + Debug + Hash + 'static> Value for T {}
 
-impl<Input, Output, DefaultValue, NextValue, TracingSpan>
-    FixedPoint<Input, Output, DefaultValue, NextValue, TracingSpan>
+impl<Input, Output, DefaultValue, CycleRulesFn, NextValue, TracingSpan>
+    FixedPoint<Input, Output, DefaultValue, CycleRulesFn, NextValue, TracingSpan>
 where
     Input: Value,
     Output: Value,
     DefaultValue: Fn(&Input) -> Output,
+    CycleRulesFn: Fn(&Input) -> CycleRules<Output>,
     NextValue: FnMut(Input) -> Output,
     TracingSpan: Fn(&Input) -> tracing::Span,
 {
-    fn apply(&mut self, input: Input) -> Output {
-        if let Some(r) = self.with_stack(|stack| stack.search(&input)) {
+    fn apply(&mut self, input: Input) -> FixedPointResult<Output> {
+        if let Some((r, depth, coinductive)) = self.with_stack(|stack| stack.search(&input)) {
+            // Until the computation has produced an output of its own, its output is the
+            // assumption that it holds, which only a coinductive cycle may rely upon.
+            let r = if coinductive && cycle_is_inductive(depth) {
+                (self.default_value)(&input)
+            } else {
+                r
+            };
             tracing::debug!("recursive call to {:?}, yielding {:?}", input, r);
-            return r;
+            observe(depth);
+            return FixedPointResult {
+                output: r,
+                is_final: false,
+            };
         }
 
-        self.with_stack(|stack| {
-            let default_value = (self.default_value)(&input);
-            stack.push(&input, default_value);
+        let CycleRules {
+            coinductive_value,
+            inductive,
+        } = (self.cycle_rules)(&input);
+        let depth = IN_PROGRESS.with(|v| {
+            let mut v = v.borrow_mut();
+            let depth = v.len();
+            v.push(InProgress {
+                observed: depth,
+                inductive,
+            });
+            depth
+        });
+        self.with_stack(|stack| match coinductive_value {
+            Some(value) => stack.push(&input, value, depth, true),
+            None => stack.push(&input, (self.default_value)(&input), depth, false),
         });
 
         loop {
@@ -74,7 +195,19 @@ where
             }
         }
 
-        self.with_stack(|stack| stack.pop(&input))
+        let output = self.with_stack(|stack| stack.pop(&input));
+        let observed = IN_PROGRESS.with(|v| {
+            let mut v = v.borrow_mut();
+            assert_eq!(v.len(), depth + 1);
+            v.pop().unwrap().observed
+        });
+        let is_final = observed >= depth;
+        if !is_final {
+            // Our output was derived from the provisional output of an older computation,
+            // so the same is true of whoever invoked us.
+            observe(observed);
+        }
+        FixedPointResult { output, is_final }
     }
 
     fn with_stack<R>(&self, f: impl FnOnce(&mut FixedPointStack<Input, Output>) -> R) -> R {
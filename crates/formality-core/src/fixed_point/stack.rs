@@ -22,6 +22,14 @@ struct StackEntry<Input, Output> {
     /// Initially false; set to true when the outputs of this rule
     /// are observed while it is being evaluated.
     has_dependents: bool,
+
+    /// Position of this computation among all those in progress on this thread,
+    /// across every stack.
+    depth: usize,
+
+    /// True while `output` is still the coinductive starting value rather than
+    /// one computed by an iteration.
+    coinductive: bool,
 }
 
 impl<Input, Output> FixedPointStack<Input, Output>
@@ -38,18 +46,19 @@ where
 
     /// Search backwards through the stack, looking for the given input.
     ///
-    /// If it is found, return `Some` with the current outputs, and mark it
-    /// as needing fixed point iteration.
+    /// If it is found, return `Some` with the current outputs, the depth of the entry, and
+    /// whether the outputs are still the coinductive starting value, and mark it as needing
+    /// fixed point iteration.
     ///
     /// If not, return `None`.
     ///
     /// The fixed-point mark is returned when the stack is [popped](`Self::pop`) and is used
     /// as part of the fixed point algorithm.
-    pub fn search(&mut self, input: &Input) -> Option<Output> {
+    pub fn search(&mut self, input: &Input) -> Option<(Output, usize, bool)> {
         for entry in &mut self.entries {
             if entry.input == *input {
                 entry.has_dependents = true;
-                return Some(entry.output.clone());
+                return Some((entry.output.clone(), entry.depth, entry.coinductive));
             }
         }
 
@@ -58,13 +67,17 @@ where
 
     /// Push an entry onto the stack, indicating it is currently being evaluated.
     /// There must not already be an entry for `input`.
-    pub fn push(&mut self, input: &Input, output: Output) {
+    /// `depth` is its position among all computations in progress on this thread, and
+    /// `coinductive` is true if `output` is the coinductive starting value.
+    pub fn push(&mut self, input: &Input, output: Output, depth: usize, coinductive: bool) {
         assert!(self.search(input).is_none());
 
         self.entries.push(StackEntry {
             input: input.clone(),
             output,
             has_dependents: false,
+            depth,
+            coinductive,
         });
     }
 
@@ -72,6 +85,7 @@ where
     /// Returns true if another iteration is needed before reaching a fixed point.
     pub fn update_output(&mut self, input: &Input, output: Output) -> bool {
         let top = self.top_frame(input);
+        top.coinductive = false;
         if top.output == output {
             return false;
         }
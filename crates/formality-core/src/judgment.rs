@@ -1,15 +1,51 @@
 use std::cell::RefCell;
 
-use crate::{fixed_point::FixedPointStack, Set};
+use crate::{fixed_point::FixedPointStack, Map, Set};
+
+pub mod export;
+
+mod proof_tree;
+pub use proof_tree::{is_recording_proofs, with_proof_trees, ProofTree};
 
 mod proven_set;
 pub use proven_set::{FailedJudgment, FailedRule, ProvenSet, RuleFailureCause, TryIntoIter};
 
+mod summary;
+pub use summary::FailureSummary;
+
+mod test_cached;
+mod test_export;
 mod test_filtered;
+mod test_proof_tree;
 mod test_reachable;
+mod test_summary;
 
 pub type JudgmentStack<J, O> = RefCell<FixedPointStack<J, Set<O>>>;
 
+pub type JudgmentCache<J, O> = RefCell<Map<J, ProvenSet<O>>>;
+
+thread_local! {
+    /// Functions that clear the non-empty judgment caches of this thread.
+    static CACHE_CLEARERS: RefCell<Vec<fn()>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Registers `clear` to be invoked by the next [`clear_judgment_caches`].
+/// Each judgment registers its cache when inserting into it while it is empty.
+pub fn register_judgment_cache(clear: fn()) {
+    CACHE_CLEARERS.with(|c| c.borrow_mut().push(clear));
+}
+
+/// Discards the results cached by all judgments on this thread (see `cached` in [`judgment_fn`]).
+/// Cached results are keyed on the full inputs, so they stay correct as long as
+/// they are kept, but they keep those inputs alive; call this when the inputs
+/// will not recur, e.g. before checking a new program.
+pub fn clear_judgment_caches() {
+    let clearers = CACHE_CLEARERS.with(|c| std::mem::take(&mut *c.borrow_mut()));
+    for clear in clearers {
+        clear();
+    }
+}
+
 /// `judgment_fn!` allows construction of inference rules using a more logic-like notation.
 ///
 /// The macro input looks like so:
@@ -44,6 +80,39 @@ pub type JudgmentStack<J, O> = RefCell<FixedPointStack<J, Set<O>>>;
 /// You can place a `!` after a condition to mark it as a "match commit point".
 /// Rules that fail before reaching the match commit point will not be included
 /// in the failure result.
+///
+/// ## Cycles
+///
+/// When a judgment recursively requires itself with the same inputs, the recursive
+/// call yields the current approximation of the result, which starts out empty.
+/// Cycles are therefore inductive by default, meaning that they never prove anything.
+/// A `coinductive(<expr> => <value>)` clause, placed after any `trivial` clauses,
+/// makes cycles on inputs for which `<expr>` holds start from `<value>` instead,
+/// so that the cycle succeeds unless some other condition fails. The input names
+/// are bound by reference within `<expr>` and `<value>`.
+///
+/// A cycle is only coinductive if every judgment along it is. An `inductive(<expr>)`
+/// clause, placed after any `coinductive` clauses, marks the inputs for which `<expr>`
+/// holds as inductive: a cycle that passes through such a judgment does not rely on
+/// the coinductive starting value of the judgment it returns to. Judgments without
+/// either clause, like those that only combine the results of others, do not decide
+/// whether a cycle through them is coinductive.
+///
+/// ## Caching
+///
+/// A `cached(<expr>)` clause, placed after any `coinductive` and `inductive` clauses,
+/// remembers the result for inputs where `<expr>` holds, so that later calls with the
+/// same inputs (on the same thread) return it without re-applying the rules. The input
+/// names are bound by reference within `<expr>`. Results that were computed from the
+/// provisional result of a cycle still in progress are not cached, since they may
+/// differ once that cycle reaches its fixed point. The cache is only invalidated by
+/// [`clear_judgment_caches`], which `formality-check` invokes before checking each program.
+///
+/// ## Proof trees
+///
+/// Within [`with_proof_trees`], each result of a judgment records a [`ProofTree`]
+/// naming the rule that produced it and the proof trees of the judgments its
+/// conditions relied upon; see [`ProvenSet::proof`].
 #[macro_export]
 macro_rules! judgment_fn {
     (
@@ -52,6 +121,9 @@ macro_rules! judgment_fn {
             debug($($debug_input_name:ident),*)
             $(assert($assert_expr:expr))*
             $(trivial($trivial_expr:expr => $trivial_result:expr))*
+            $(coinductive($coinductive_expr:expr => $coinductive_result:expr))*
+            $(inductive($inductive_expr:expr))*
+            $(cached($cached_expr:expr))?
             $(($($rule:tt)*))*
         }
     ) => {
@@ -80,17 +152,43 @@ macro_rules! judgment_fn {
                 assert!($assert_expr);
             )*
 
+            #[allow(unused_variables)]
+            let trivial_input = || __JudgmentStruct($(Clone::clone(&$input_name)),*);
             $(
                 // Trivial cases are an (important) optimization that lets
                 // you cut out all the normal rules.
                 if $trivial_expr {
-                    return $crate::ProvenSet::proven(std::iter::once($trivial_result).collect());
+                    let input = $crate::judgment::is_recording_proofs().then(trivial_input);
+                    let output: $crate::Set<$output> = std::iter::once($trivial_result).collect();
+                    let mut proofs = $crate::Map::new();
+                    if let Some(input) = input {
+                        $crate::judgment::ProofTree::record_axioms(&mut proofs, &input, "trivial", &output);
+                    }
+                    return $crate::ProvenSet::proven_with_proofs(output, proofs);
                 }
             )*
 
-            let mut failed_rules = $crate::set![];
             let input = __JudgmentStruct($($input_name),*);
-            let output = $crate::fixed_point::fixed_point::<
+
+            thread_local! {
+                static C: $crate::judgment::JudgmentCache<__JudgmentStruct, $output> = Default::default()
+            }
+            // Cached results have no proof trees, so skip the cache when recording them.
+            #[allow(unused_variables)]
+            let cached = !$crate::judgment::is_recording_proofs() && {
+                let __JudgmentStruct($($input_name),*) = &input;
+                false $(|| $cached_expr)?
+            };
+            if cached {
+                if let Some(result) = C.with(|c| c.borrow().get(&input).cloned()) {
+                    tracing::debug!("cached result for {:?}", input);
+                    return result;
+                }
+            }
+
+            let mut failed_rules = $crate::set![];
+            let mut proofs = $crate::Map::new();
+            let $crate::fixed_point::FixedPointResult { output, is_final } = $crate::fixed_point::fixed_point_result::<
                 __JudgmentStruct,
                 $crate::Set<$output>,
             >(
@@ -117,16 +215,35 @@ macro_rules! judgment_fn {
                 // Default value:
                 |_| Default::default(),
 
+                // Cycle rules:
+                |input| {
+                    #[allow(unused_variables)]
+                    let __JudgmentStruct($($input_name),*) = input;
+                    #[allow(unused_mut)]
+                    let mut rules = $crate::fixed_point::CycleRules::default();
+                    $(
+                        if rules.coinductive_value.is_none() && $coinductive_expr {
+                            rules.coinductive_value = Some(std::iter::once($coinductive_result).collect());
+                        }
+                    )*
+                    $(
+                        rules.inductive |= $inductive_expr;
+                    )*
+                    rules
+                },
+
                 // Next value:
                 |input: __JudgmentStruct| {
                     let mut output = $crate::Set::new();
 
                     failed_rules.clear();
+                    let _premises = $crate::judgment::ProofTree::fresh_premises();
 
                     $crate::push_rules!(
                         $name,
                         &input,
                         output,
+                        proofs,
                         failed_rules,
                         ($($input_name),*) => $output,
                         $(($($rule)*))*
@@ -136,27 +253,44 @@ macro_rules! judgment_fn {
                 },
             );
 
-            if !output.is_empty() {
-                $crate::ProvenSet::proven(output)
+            let result = if !output.is_empty() {
+                // Proofs are kept from the iteration in which each output was first produced,
+                // so that they do not rely on the output itself. If we did not apply the rules
+                // ourselves, the output came from a cycle.
+                proofs.retain(|o, _| output.contains(o));
+                $crate::judgment::ProofTree::record_axioms(&mut proofs, &input, "cycle", &output);
+                $crate::ProvenSet::proven_with_proofs(output, proofs)
             } else {
                 $crate::ProvenSet::failed_rules(&input, failed_rules)
+            };
+
+            if cached && is_final {
+                C.with(|c| {
+                    let mut c = c.borrow_mut();
+                    if c.is_empty() {
+                        $crate::judgment::register_judgment_cache(|| C.with(|c| c.borrow_mut().clear()));
+                    }
+                    c.insert(input, result.clone());
+                });
             }
+
+            result
         }
     }
 }
 
 #[macro_export]
 macro_rules! push_rules {
-    ($judgment_name:ident, $input_value:expr, $output:expr, $failed_rules:expr, $input_names:tt => $output_ty:ty, $($rule:tt)*) => {
-        $($crate::push_rules!(@rule ($judgment_name, $input_value, $output, $failed_rules, $input_names => $output_ty) $rule);)*
+    ($judgment_name:ident, $input_value:expr, $output:expr, $proofs:expr, $failed_rules:expr, $input_names:tt => $output_ty:ty, $($rule:tt)*) => {
+        $($crate::push_rules!(@rule ($judgment_name, $input_value, $output, $proofs, $failed_rules, $input_names => $output_ty) $rule);)*
     };
 
     // `@rule (builder) rule` phase: invoked for each rule, emits `push_rule` call
 
-    (@rule ($judgment_name:ident, $input_value:expr, $output:expr, $failed_rules:expr, $input_names:tt => $output_ty:ty) ($($m:tt)*)) => {
+    (@rule ($judgment_name:ident, $input_value:expr, $output:expr, $proofs:expr, $failed_rules:expr, $input_names:tt => $output_ty:ty) ($($m:tt)*)) => {
         // Start accumulating.
         $crate::push_rules!(@accum
-            args($judgment_name, $input_value, $output, $failed_rules, $input_names => $output_ty)
+            args($judgment_name, $input_value, $output, $proofs, $failed_rules, $input_names => $output_ty)
             accum((1-1); 0;)
             input($($m)*)
         );
@@ -169,7 +303,7 @@ macro_rules! push_rules {
     // at 0. The `current_index` is also expected to start as the expression `0`.
 
     (@accum
-        args($judgment_name:ident, $input_value:expr, $output:expr, $failed_rules:expr, ($($input_names:ident),*) => $output_ty:ty)
+        args($judgment_name:ident, $input_value:expr, $output:expr, $proofs:expr, $failed_rules:expr, ($($input_names:ident),*) => $output_ty:ty)
         accum($match_index:expr; $current_index:expr; $($m:tt)*)
         input(
             ---$(-)* ($n:literal)
@@ -199,7 +333,7 @@ macro_rules! push_rules {
                     inputs($($input_names)*)
                     patterns($($patterns)*,)
                     args(@body
-                        ($judgment_name; $n; $v; $output);
+                        ($judgment_name; $n; $v; $output; $proofs; $input_value);
                         ($failed_rules, $match_index, ($($input_names),*), $n);
                         $($m)*
                     )
@@ -238,9 +372,9 @@ macro_rules! push_rules {
     // Matching phase: peel off the patterns one by one and match them against the values
     // extracted from the input. For anything that is not an identity pattern, invoke `downcast`.
 
-    (@match $conclusion_name:ident inputs() patterns() args(@body ($judgment_name:ident; $n:literal; $v:expr; $output:expr); $inputs:tt; $($m:tt)*)) => {
+    (@match $conclusion_name:ident inputs() patterns() args(@body ($judgment_name:ident; $n:literal; $v:expr; $output:expr; $proofs:expr; $input_value:expr); $inputs:tt; $($m:tt)*)) => {
         tracing::trace_span!("matched rule", rule = $n, judgment = stringify!($judgment_name)).in_scope(|| {
-            $crate::push_rules!(@body ($judgment_name, $n, $v, $output); $inputs; 0; $($m)*);
+            $crate::push_rules!(@body ($judgment_name, $n, $v, $output, $proofs, $input_value); $inputs; 0; $($m)*);
         });
     };
 
@@ -389,9 +523,10 @@ macro_rules! push_rules {
     (@body $args:tt; $inputs:tt; $step_index:expr; ($i:expr => $p:pat) $($m:tt)*) => {
         // Explicitly calling `into_iter` silences some annoying lints
         // in the case where `$i` is an `Option` or a `Result`
-        match $crate::judgment::TryIntoIter::try_into_iter($i, || stringify!($i).to_string()) {
+        match $crate::judgment::TryIntoIter::try_into_proof_iter($i, || stringify!($i).to_string()) {
             Ok(i) => {
-                for $p in std::iter::IntoIterator::into_iter(i) {
+                for ($p, proof) in std::iter::IntoIterator::into_iter(i) {
+                    let _premise = $crate::judgment::ProofTree::push_premise(proof);
                     $crate::push_rules!(@body $args; $inputs; $step_index + 1; $($m)*);
                 }
             }
@@ -408,10 +543,11 @@ macro_rules! push_rules {
         }
     };
 
-    (@body ($judgment_name:ident, $rule_name:literal, $v:expr, $output:expr); $inputs:tt; $step_index:expr;) => {
+    (@body ($judgment_name:ident, $rule_name:literal, $v:expr, $output:expr, $proofs:expr, $input_value:expr); $inputs:tt; $step_index:expr;) => {
         {
             let result = $crate::Upcast::upcast($v);
             tracing::debug!("produced {:?} from rule {:?} in judgment {:?}", result, $rule_name, stringify!($judgment_name));
+            $crate::judgment::ProofTree::record(&mut $proofs, $input_value, $rule_name, &result);
             $output.insert(result)
         }
     };
@@ -422,7 +558,9 @@ macro_rules! push_rules {
         let file = $crate::respan!($step_expr (file!()));
         let line = $crate::respan!($step_expr (line!()));
         let column = $crate::respan!($step_expr (column!()));
-        if $step_index >= $match_index {
+        let step_index: usize = $step_index;
+        let match_index: usize = $match_index;
+        if step_index >= match_index {
             tracing::debug!(
                 "rule {rn} failed at step {s} because {cause} ({file}:{line}:{column})",
                 rn = $rule_name,
@@ -436,8 +574,8 @@ macro_rules! push_rules {
                 $crate::judgment::FailedRule {
                     rule_name_index: Some(($rule_name.to_string(), $step_index)),
                     file: file.to_string(),
-                    line: line,
-                    column: column,
+                    line,
+                    column,
                     cause: $cause,
                 }
             );
@@ -0,0 +1,345 @@
+//! Export of derivations, successful ([`ProofTree`]) or failed ([`FailedJudgment`]),
+//! as JSON and as Graphviz DOT, so that they can be diffed and rendered.
+//!
+//! The JSON schema is stable. A proof tree is exported as
+//!
+//! ```text
+//! { "kind": "proof", "judgment": <string>, "rule": <string>, "output": <string>, "children": [<proof>] }
+//! ```
+//!
+//! and a failed judgment as
+//!
+//! ```text
+//! { "kind": "failed_judgment", "judgment": <string>, "failed_rules": [<failed rule>] }
+//! ```
+//!
+//! where each failed rule is
+//!
+//! ```text
+//! { "rule": <string or null>, "step": <number or null>, "file": <string>, "line": <number>,
+//!   "column": <number>, "cause": <cause> }
+//! ```
+//!
+//! and each cause is an object whose `"kind"` is one of `"if_false"` (with `"expr"` and
+//! `"args"`, a list of `{ "expr", "value" }`), `"if_let_did_not_match"` (with `"pattern"`
+//! and `"value"`), `"empty_collection"` (with `"expr"`), `"inapplicable"` (with `"reason"`),
+//! `"cycle"` (with `"judgment"`), or `"failed_judgment"` (a nested failed judgment).
+//! Judgments and outputs are given as their `Debug` strings.
+//!
+//! A [`ProvenSet`](`super::ProvenSet`) is exported as
+//! `{ "version": 1, "result": "proven", "items": [{ "output": <string>, "proof": <proof or null> }] }`
+//! or `{ "version": 1, "result": "failed", "failure": <failed judgment> }`.
+
+use std::fmt::Write;
+
+use super::{FailedJudgment, FailedRule, ProofTree, RuleFailureCause};
+
+/// The version of the JSON schema, bumped on incompatible changes.
+pub(super) const JSON_VERSION: usize = 1;
+
+/// A JSON value. Object fields are kept in the order given.
+pub(super) enum Json {
+    Null,
+    Number(usize),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    pub(super) fn string(s: impl ToString) -> Json {
+        Json::String(s.to_string())
+    }
+
+    /// Pretty-prints the value, one field or element per line, for the sake of diffs.
+    pub(super) fn pretty(&self) -> String {
+        let mut out = String::new();
+        self.write(&mut out, 0);
+        out
+    }
+
+    fn write(&self, out: &mut String, depth: usize) {
+        match self {
+            Json::Null => out.push_str("null"),
+            Json::Number(n) => write!(out, "{n}").unwrap(),
+            Json::String(s) => write_json_string(out, s),
+            Json::Array(elements) if elements.is_empty() => out.push_str("[]"),
+            Json::Array(elements) => {
+                out.push('[');
+                for (i, element) in elements.iter().enumerate() {
+                    out.push_str(if i == 0 { "\n" } else { ",\n" });
+                    indent(out, depth + 1);
+                    element.write(out, depth + 1);
+                }
+                out.push('\n');
+                indent(out, depth);
+                out.push(']');
+            }
+            Json::Object(fields) if fields.is_empty() => out.push_str("{}"),
+            Json::Object(fields) => {
+                out.push('{');
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    out.push_str(if i == 0 { "\n" } else { ",\n" });
+                    indent(out, depth + 1);
+                    write_json_string(out, name);
+                    out.push_str(": ");
+                    value.write(out, depth + 1);
+                }
+                out.push('\n');
+                indent(out, depth);
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn indent(out: &mut String, depth: usize) {
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+}
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+impl ProofTree {
+    /// Exports this derivation as JSON, see the [module docs](`crate::judgment::export`) for the schema.
+    pub fn to_json(&self) -> String {
+        self.json().pretty()
+    }
+
+    /// Exports this derivation as a Graphviz DOT digraph, with an edge from each judgment
+    /// to the judgments its rule relied upon.
+    pub fn to_dot(&self) -> String {
+        let mut dot = Dot::default();
+        dot.proof_tree(self);
+        dot.finish()
+    }
+
+    pub(super) fn json(&self) -> Json {
+        let ProofTree {
+            judgment,
+            rule,
+            output,
+            children,
+        } = self;
+        Json::Object(vec![
+            ("kind", Json::string("proof")),
+            ("judgment", Json::string(judgment)),
+            ("rule", Json::string(rule)),
+            ("output", Json::string(output)),
+            (
+                "children",
+                Json::Array(children.iter().map(|c| c.json()).collect()),
+            ),
+        ])
+    }
+}
+
+impl FailedJudgment {
+    /// Exports this failed derivation as JSON, see the [module docs](`crate::judgment::export`) for the schema.
+    pub fn to_json(&self) -> String {
+        self.json().pretty()
+    }
+
+    /// Exports this failed derivation as a Graphviz DOT digraph, with an edge from each judgment
+    /// to the rules that failed to prove it, and from each rule to the cause of its failure.
+    pub fn to_dot(&self) -> String {
+        let mut dot = Dot::default();
+        dot.failed_judgment(self);
+        dot.finish()
+    }
+
+    pub(super) fn json(&self) -> Json {
+        let FailedJudgment {
+            judgment,
+            failed_rules,
+        } = self;
+        Json::Object(vec![
+            ("kind", Json::string("failed_judgment")),
+            ("judgment", Json::string(judgment)),
+            (
+                "failed_rules",
+                Json::Array(failed_rules.iter().map(|r| r.json()).collect()),
+            ),
+        ])
+    }
+}
+
+impl FailedRule {
+    fn json(&self) -> Json {
+        let FailedRule {
+            rule_name_index,
+            file,
+            line,
+            column,
+            cause,
+        } = self;
+        let (rule, step) = match rule_name_index {
+            Some((rule, step)) => (Json::string(rule), Json::Number(*step)),
+            None => (Json::Null, Json::Null),
+        };
+        Json::Object(vec![
+            ("rule", rule),
+            ("step", step),
+            ("file", Json::string(file)),
+            ("line", Json::Number(*line as usize)),
+            ("column", Json::Number(*column as usize)),
+            ("cause", cause.json()),
+        ])
+    }
+}
+
+impl RuleFailureCause {
+    fn json(&self) -> Json {
+        match self {
+            RuleFailureCause::IfFalse { expr, args } => Json::Object(vec![
+                ("kind", Json::string("if_false")),
+                ("expr", Json::string(expr)),
+                (
+                    "args",
+                    Json::Array(
+                        args.iter()
+                            .map(|(expr, value)| {
+                                Json::Object(vec![
+                                    ("expr", Json::string(expr)),
+                                    ("value", Json::string(value)),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                ),
+            ]),
+            RuleFailureCause::IfLetDidNotMatch { pattern, value } => Json::Object(vec![
+                ("kind", Json::string("if_let_did_not_match")),
+                ("pattern", Json::string(pattern)),
+                ("value", Json::string(value)),
+            ]),
+            RuleFailureCause::EmptyCollection { expr } => Json::Object(vec![
+                ("kind", Json::string("empty_collection")),
+                ("expr", Json::string(expr)),
+            ]),
+            RuleFailureCause::FailedJudgment(judgment) => judgment.json(),
+            RuleFailureCause::Inapplicable { reason } => Json::Object(vec![
+                ("kind", Json::string("inapplicable")),
+                ("reason", Json::string(reason)),
+            ]),
+            RuleFailureCause::Cycle { judgment } => Json::Object(vec![
+                ("kind", Json::string("cycle")),
+                ("judgment", Json::string(judgment)),
+            ]),
+        }
+    }
+}
+
+/// Accumulates the nodes and edges of a DOT digraph. Nodes are numbered in the
+/// order they are added, so the same derivation always yields the same graph.
+#[derive(Default)]
+pub(super) struct Dot {
+    lines: Vec<String>,
+    nodes: usize,
+}
+
+impl Dot {
+    /// Adds a node with the given attributes, returning its name.
+    pub(super) fn node(&mut self, label: &str, attributes: &str) -> String {
+        let name = format!("n{}", self.nodes);
+        self.nodes += 1;
+        self.lines.push(format!(
+            "  {name} [label={label}{attributes}];",
+            label = dot_string(label)
+        ));
+        name
+    }
+
+    pub(super) fn edge(&mut self, from: &str, to: &str) {
+        self.lines.push(format!("  {from} -> {to};"));
+    }
+
+    pub(super) fn proof_tree(&mut self, tree: &ProofTree) -> String {
+        let ProofTree {
+            judgment,
+            rule,
+            output,
+            children,
+        } = tree;
+        let node = self.node(
+            &format!("{judgment}\nby rule {rule:?}\nproduced {output}"),
+            ", shape=box",
+        );
+        for child in children {
+            let child = self.proof_tree(child);
+            self.edge(&node, &child);
+        }
+        node
+    }
+
+    pub(super) fn failed_judgment(&mut self, failed: &FailedJudgment) -> String {
+        let FailedJudgment {
+            judgment,
+            failed_rules,
+        } = failed;
+        let node = self.node(judgment, ", shape=box, color=red");
+        for failed_rule in failed_rules {
+            let FailedRule {
+                rule_name_index,
+                file,
+                line,
+                column,
+                cause,
+            } = failed_rule;
+            let label = match rule_name_index {
+                Some((rule, step)) => {
+                    format!("rule {rule:?} failed at step #{step}\n({file}:{line}:{column})")
+                }
+                None => format!("failed at ({file}:{line}:{column})"),
+            };
+            let rule_node = self.node(&label, ", shape=ellipse");
+            self.edge(&node, &rule_node);
+            let cause_node = match cause {
+                RuleFailureCause::FailedJudgment(judgment) => self.failed_judgment(judgment),
+                cause => self.node(&cause.to_string(), ", shape=note"),
+            };
+            self.edge(&rule_node, &cause_node);
+        }
+        node
+    }
+
+    pub(super) fn finish(self) -> String {
+        let mut out = String::from("digraph derivation {\n");
+        for line in self.lines {
+            out.push_str(&line);
+            out.push('\n');
+        }
+        out.push('}');
+        out
+    }
+}
+
+/// Quotes `s` as a DOT string, with line breaks left-justified.
+fn dot_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\l"),
+            c => out.push(c),
+        }
+    }
+    out.push_str("\\l\"");
+    out
+}
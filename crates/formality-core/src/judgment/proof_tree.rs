@@ -0,0 +1,176 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    sync::Arc,
+};
+
+use crate::{Map, Set};
+
+use super::proven_set::indent;
+
+/// The derivation of one result of a judgment: the rule that produced it,
+/// along with the derivations of the judgments that rule relied upon.
+///
+/// Proof trees are only recorded within [`with_proof_trees`].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ProofTree {
+    /// The judgment that was proven, e.g. `prove_wc { goal: ..., ... }`.
+    pub judgment: String,
+
+    /// The name of the rule that was applied, as given in its conclusion.
+    /// Results of `trivial` clauses are proven by the rule `"trivial"`, and results
+    /// that were taken from the same judgment being proven further up the tree
+    /// by the rule `"cycle"`.
+    pub rule: String,
+
+    /// The result produced by the rule.
+    pub output: String,
+
+    /// Derivations of the judgments that the conditions of the rule relied upon,
+    /// in the order the conditions appear.
+    pub children: Vec<Arc<ProofTree>>,
+}
+
+thread_local! {
+    static RECORDING: Cell<bool> = const { Cell::new(false) };
+
+    /// Derivations of the conditions matched so far by the rule being applied.
+    static PREMISES: RefCell<Vec<Arc<ProofTree>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Invokes `op`, recording a [`ProofTree`] for each result proven by a judgment
+/// along the way. The proof trees can be retrieved from the resulting
+/// [`ProvenSet`](`super::ProvenSet`)s.
+///
+/// Judgment results are not cached while recording, as cached results have no proof trees.
+pub fn with_proof_trees<R>(op: impl FnOnce() -> R) -> R {
+    struct Restore(bool);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            RECORDING.with(|r| r.set(self.0));
+        }
+    }
+
+    let _restore = Restore(RECORDING.with(|r| r.replace(true)));
+    op()
+}
+
+/// True if proof trees are being recorded, see [`with_proof_trees`].
+pub fn is_recording_proofs() -> bool {
+    RECORDING.with(|r| r.get())
+}
+
+impl ProofTree {
+    /// Iterates over this derivation and all the derivations within it, parents first.
+    pub fn iter(&self) -> impl Iterator<Item = &ProofTree> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let tree = stack.pop()?;
+            stack.extend(tree.children.iter().rev().map(|c| &**c));
+            Some(tree)
+        })
+    }
+
+    /// True if the rule named `rule` was applied anywhere in this derivation.
+    pub fn uses_rule(&self, rule: &str) -> bool {
+        self.iter().any(|tree| tree.rule == rule)
+    }
+
+    /// Records the derivation for `output`, produced by `rule` from the premises
+    /// matched so far. Meant to be used from the judgment macro.
+    #[doc(hidden)]
+    pub fn record<T: Ord + Clone + Debug>(
+        proofs: &mut Map<T, Arc<ProofTree>>,
+        judgment: &impl Debug,
+        rule: &str,
+        output: &T,
+    ) {
+        if !is_recording_proofs() || proofs.contains_key(output) {
+            return;
+        }
+        let children = PREMISES.with(|p| p.borrow().clone());
+        proofs.insert(
+            output.clone(),
+            Arc::new(ProofTree {
+                judgment: format!("{judgment:?}"),
+                rule: rule.to_string(),
+                output: format!("{output:?}"),
+                children,
+            }),
+        );
+    }
+
+    /// Records a derivation without premises for each of `outputs` that lacks one.
+    /// Meant to be used from the judgment macro.
+    #[doc(hidden)]
+    pub fn record_axioms<T: Ord + Clone + Debug>(
+        proofs: &mut Map<T, Arc<ProofTree>>,
+        judgment: &impl Debug,
+        rule: &str,
+        outputs: &Set<T>,
+    ) {
+        if !is_recording_proofs() {
+            return;
+        }
+        let _premises = Self::fresh_premises();
+        for output in outputs {
+            Self::record(proofs, judgment, rule, output);
+        }
+    }
+
+    /// Adds `proof` to the premises of the rule being applied until the returned guard
+    /// is dropped. Meant to be used from the judgment macro.
+    #[doc(hidden)]
+    pub fn push_premise(proof: Option<Arc<ProofTree>>) -> impl Drop {
+        struct Pop(bool);
+        impl Drop for Pop {
+            fn drop(&mut self) {
+                if self.0 {
+                    PREMISES.with(|p| p.borrow_mut().pop());
+                }
+            }
+        }
+
+        let push = proof.is_some() && is_recording_proofs();
+        if let (true, Some(proof)) = (push, proof) {
+            PREMISES.with(|p| p.borrow_mut().push(proof));
+        }
+        Pop(push)
+    }
+
+    /// Starts a new, empty set of premises for the rules of a judgment,
+    /// restoring the premises of the enclosing rule when the returned guard is dropped.
+    /// Meant to be used from the judgment macro.
+    #[doc(hidden)]
+    pub fn fresh_premises() -> impl Drop {
+        struct Restore(Option<Vec<Arc<ProofTree>>>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                if let Some(premises) = self.0.take() {
+                    PREMISES.with(|p| *p.borrow_mut() = premises);
+                }
+            }
+        }
+
+        Restore(is_recording_proofs().then(|| PREMISES.with(|p| p.take())))
+    }
+}
+
+impl std::fmt::Display for ProofTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ProofTree {
+            judgment,
+            rule,
+            output,
+            children,
+        } = self;
+        write!(
+            f,
+            "judgment `{judgment}` produced `{output}` by the rule {rule:?}"
+        )?;
+        for child in children {
+            write!(f, "\n{}", indent(child))?;
+        }
+        Ok(())
+    }
+}
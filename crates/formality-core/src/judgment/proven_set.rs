@@ -1,11 +1,17 @@
-use crate::{set, Set};
+use crate::{set, Map, Set};
 use std::{
     fmt::Debug,
     hash::{Hash, Hasher},
+    sync::Arc,
 };
 
+use super::export::{Dot, Json, JSON_VERSION};
+use super::proof_tree::ProofTree;
+
 /// Represents a set of items that were successfully proven using a judgment.
 /// If the set is empty, then tracks the reason that the judgment failed for diagnostic purposes.
+/// When [recording proof trees](`super::with_proof_trees`), also tracks how each item was proven;
+/// the proof trees are not considered when comparing sets.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[must_use]
 pub struct ProvenSet<T> {
@@ -15,7 +21,7 @@ pub struct ProvenSet<T> {
 #[derive(Clone)]
 enum Data<T> {
     Failure(Box<FailedJudgment>),
-    Success(Set<T>),
+    Success(Set<T>, Map<T, Arc<ProofTree>>),
 }
 
 impl<T> From<Data<T>> for ProvenSet<T> {
@@ -39,8 +45,35 @@ impl<T: Ord + Debug> ProvenSet<T> {
     /// Creates a judgment set with a set of `T` items that were successfully proven.
     /// The set should be non-empty.
     pub fn proven(data: Set<T>) -> Self {
+        Self::proven_with_proofs(data, Map::new())
+    }
+
+    /// Creates a judgment set with a set of `T` items that were successfully proven,
+    /// along with the proof trees for (some of) those items.
+    /// The set should be non-empty.
+    pub fn proven_with_proofs(data: Set<T>, proofs: Map<T, Arc<ProofTree>>) -> Self {
         assert!(!data.is_empty());
-        Data::Success(data).into()
+        Data::Success(data, proofs).into()
+    }
+
+    /// Like [`FromIterator::from_iter`], but keeps the proof trees of the items.
+    pub fn from_proofs(iter: impl IntoIterator<Item = (T, Option<Arc<ProofTree>>)>) -> Self
+    where
+        T: Clone,
+    {
+        let mut set = set![];
+        let mut proofs = Map::new();
+        for (item, proof) in iter {
+            if let Some(proof) = proof {
+                proofs.entry(item.clone()).or_insert(proof);
+            }
+            set.insert(item);
+        }
+        if set.is_empty() {
+            ProvenSet::failed("collect", "empty collection")
+        } else {
+            ProvenSet::proven_with_proofs(set, proofs)
+        }
     }
 
     /// Creates a `JudgmentSet` from a Rust function that failed for the given reason.
@@ -66,7 +99,7 @@ impl<T: Ord + Debug> ProvenSet<T> {
     pub fn is_proven(&self) -> bool {
         match &self.data {
             Data::Failure(_) => false,
-            Data::Success(s) => {
+            Data::Success(s, _) => {
                 assert!(!s.is_empty());
                 true
             }
@@ -76,7 +109,7 @@ impl<T: Ord + Debug> ProvenSet<T> {
     pub fn check_proven(self) -> Result<(), Box<FailedJudgment>> {
         match self.data {
             Data::Failure(e) => Err(e),
-            Data::Success(_) => Ok(()),
+            Data::Success(..) => Ok(()),
         }
     }
 
@@ -84,7 +117,7 @@ impl<T: Ord + Debug> ProvenSet<T> {
     pub fn into_set(self) -> Result<Set<T>, Box<FailedJudgment>> {
         match self.data {
             Data::Failure(e) => Err(e),
-            Data::Success(s) => {
+            Data::Success(s, _) => {
                 assert!(!s.is_empty());
                 Ok(s)
             }
@@ -95,7 +128,15 @@ impl<T: Ord + Debug> ProvenSet<T> {
     pub fn iter<'a>(&'a self) -> Box<dyn Iterator<Item = &'a T> + 'a> {
         match &self.data {
             Data::Failure(_) => Box::new(std::iter::empty()),
-            Data::Success(s) => Box::new(s.iter()),
+            Data::Success(s, _) => Box::new(s.iter()),
+        }
+    }
+
+    /// The proof tree showing how `item` was proven, if one was recorded.
+    pub fn proof(&self, item: &T) -> Option<&ProofTree> {
+        match &self.data {
+            Data::Failure(_) => None,
+            Data::Success(_, proofs) => proofs.get(item).map(|p| &**p),
         }
     }
 
@@ -108,20 +149,30 @@ impl<T: Ord + Debug> ProvenSet<T> {
     pub fn flat_map<I, U>(self, mut op: impl FnMut(T) -> I) -> ProvenSet<U>
     where
         I: TryIntoIter<Item = U>,
-        U: Ord + Debug,
+        U: Ord + Debug + Clone,
     {
         match self.data {
             Data::Failure(e) => ProvenSet {
                 data: Data::Failure(e),
             },
-            Data::Success(set) => {
+            Data::Success(set, mut proofs) => {
                 let mut items = set![];
+                let mut item_proofs = Map::new();
                 let mut failures = set![];
 
                 for item in set {
+                    // Results without a proof tree of their own are proven by the proof of `item`.
+                    let proof = proofs.remove(&item);
                     let collection = op(item);
-                    match collection.try_into_iter(|| "flat_map".to_string()) {
-                        Ok(iterator) => items.extend(iterator),
+                    match collection.try_into_proof_iter(|| "flat_map".to_string()) {
+                        Ok(iterator) => {
+                            for (u, u_proof) in iterator {
+                                if let Some(p) = u_proof.or_else(|| proof.clone()) {
+                                    item_proofs.entry(u.clone()).or_insert(p);
+                                }
+                                items.insert(u);
+                            }
+                        }
                         Err(cause) => {
                             failures.insert(FailedRule::new(cause));
                         }
@@ -129,7 +180,7 @@ impl<T: Ord + Debug> ProvenSet<T> {
                 }
 
                 if !items.is_empty() {
-                    ProvenSet::proven(items)
+                    ProvenSet::proven_with_proofs(items, item_proofs)
                 } else {
                     ProvenSet::failed_rules("flat_map", failures)
                 }
@@ -144,22 +195,87 @@ impl<T: Ord + Debug> ProvenSet<T> {
     #[track_caller]
     pub fn map<U>(self, mut op: impl FnMut(T) -> U) -> ProvenSet<U>
     where
-        U: Ord + Debug,
+        U: Ord + Debug + Clone,
     {
         self.flat_map(|elem| set![op(elem)])
     }
 
+    /// Exports the proven items along with their proof trees, or the failure, as JSON;
+    /// see the [`export`](`crate::judgment::export`) module for the schema.
+    pub fn to_json(&self) -> String {
+        let result = match &self.data {
+            Data::Failure(e) => vec![("result", Json::string("failed")), ("failure", e.json())],
+            Data::Success(set, proofs) => vec![
+                ("result", Json::string("proven")),
+                (
+                    "items",
+                    Json::Array(
+                        set.iter()
+                            .map(|item| {
+                                Json::Object(vec![
+                                    ("output", Json::string(format!("{item:?}"))),
+                                    ("proof", proofs.get(item).map_or(Json::Null, |p| p.json())),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                ),
+            ],
+        };
+        let mut fields = vec![("version", Json::Number(JSON_VERSION))];
+        fields.extend(result);
+        Json::Object(fields).pretty()
+    }
+
+    /// Exports the proof trees of the proven items, or the failure, as a Graphviz DOT digraph.
+    pub fn to_dot(&self) -> String {
+        let mut dot = Dot::default();
+        match &self.data {
+            Data::Failure(e) => {
+                dot.failed_judgment(e);
+            }
+            Data::Success(set, proofs) => {
+                for item in set {
+                    match proofs.get(item) {
+                        Some(proof) => dot.proof_tree(proof),
+                        None => dot.node(&format!("{item:?}"), ", shape=box"),
+                    };
+                }
+            }
+        }
+        dot.finish()
+    }
+
     /// Convenience function for tests: asserts that the proven set is ok and that the debug value is as expected.
     #[track_caller]
     pub fn assert_ok(&self, expect: expect_test::Expect) {
         match &self.data {
             Data::Failure(e) => panic!("expected a successful proof, got {e}"),
-            Data::Success(_) => {
+            Data::Success(..) => {
                 expect.assert_eq(&self.to_string());
             }
         }
     }
 
+    /// Convenience function for tests: asserts that the proven set is ok and that
+    /// the proof trees of its items are as expected.
+    #[track_caller]
+    pub fn assert_proofs(&self, expect: expect_test::Expect) {
+        match &self.data {
+            Data::Failure(e) => panic!("expected a successful proof, got {e}"),
+            Data::Success(set, proofs) => {
+                let trees: Vec<String> = set
+                    .iter()
+                    .map(|item| match proofs.get(item) {
+                        Some(proof) => proof.to_string(),
+                        None => format!("no proof tree recorded for `{item:?}`"),
+                    })
+                    .collect();
+                expect.assert_eq(&crate::test_util::normalize_paths(trees.join("\n")));
+            }
+        }
+    }
+
     /// Convenience function for tests: asserts that the proven set is ok and that the debug value is as expected.
     #[track_caller]
     pub fn assert_err(&self, expect: expect_test::Expect) {
@@ -167,7 +283,7 @@ impl<T: Ord + Debug> ProvenSet<T> {
             Data::Failure(e) => {
                 expect.assert_eq(&crate::test_util::normalize_paths(e));
             }
-            Data::Success(_) => {
+            Data::Success(..) => {
                 panic!("expected an error, got successful proofs: {self}");
             }
         }
@@ -189,7 +305,7 @@ impl<T: PartialEq> PartialEq for Data<T> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Failure(l0), Self::Failure(r0)) => format!("{l0:?}") == format!("{r0:?}"),
-            (Self::Success(l0), Self::Success(r0)) => l0 == r0,
+            (Self::Success(l0, _), Self::Success(r0, _)) => l0 == r0,
             _ => false,
         }
     }
@@ -206,7 +322,7 @@ impl<T: std::fmt::Debug> std::fmt::Debug for Data<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Failure(arg0) => std::fmt::Debug::fmt(arg0, f),
-            Self::Success(arg0) => std::fmt::Debug::fmt(arg0, f),
+            Self::Success(arg0, _) => std::fmt::Debug::fmt(arg0, f),
         }
     }
 }
@@ -219,9 +335,9 @@ impl<T: PartialOrd> PartialOrd for Data<T> {
             (Self::Failure(l0), Self::Failure(r0)) => {
                 PartialOrd::partial_cmp(&format!("{l0:?}"), &format!("{r0:?}"))
             }
-            (Self::Success(l0), Self::Success(r0)) => PartialOrd::partial_cmp(l0, r0),
-            (Self::Failure(_), Self::Success(_)) => Some(std::cmp::Ordering::Less),
-            (Self::Success(_), Self::Failure(_)) => Some(std::cmp::Ordering::Greater),
+            (Self::Success(l0, _), Self::Success(r0, _)) => PartialOrd::partial_cmp(l0, r0),
+            (Self::Failure(_), Self::Success(..)) => Some(std::cmp::Ordering::Less),
+            (Self::Success(..), Self::Failure(_)) => Some(std::cmp::Ordering::Greater),
         }
     }
 }
@@ -232,9 +348,9 @@ impl<T: Ord> Ord for Data<T> {
             (Self::Failure(l0), Self::Failure(r0)) => {
                 Ord::cmp(&format!("{l0:?}"), &format!("{r0:?}"))
             }
-            (Self::Success(l0), Self::Success(r0)) => Ord::cmp(l0, r0),
-            (Self::Failure(_), Self::Success(_)) => std::cmp::Ordering::Less,
-            (Self::Success(_), Self::Failure(_)) => std::cmp::Ordering::Greater,
+            (Self::Success(l0, _), Self::Success(r0, _)) => Ord::cmp(l0, r0),
+            (Self::Failure(_), Self::Success(..)) => std::cmp::Ordering::Less,
+            (Self::Success(..), Self::Failure(_)) => std::cmp::Ordering::Greater,
         }
     }
 }
@@ -243,7 +359,7 @@ impl<T: Hash> Hash for Data<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
             Data::Failure(e) => e.to_string().hash(state),
-            Data::Success(s) => s.hash(state),
+            Data::Success(s, _) => s.hash(state),
         }
     }
 }
@@ -311,6 +427,10 @@ impl FailedJudgment {
                         judgment: judgment.judgment.clone(),
                     };
                     cycles.insert(failed_rule);
+                } else if judgment.failed_rules.is_empty() {
+                    // ...if no rules applied to a judgment not on the stack, that is a failure of its own.
+                    failed_rule.cause = RuleFailureCause::FailedJudgment(judgment);
+                    non_cycles.insert(failed_rule);
                 } else {
                     // ...otherwise, recursively simplify the failed rules.
                     // This will return a boolean indicating if all the failed rules
@@ -501,7 +621,7 @@ impl<T: Debug> std::fmt::Display for ProvenSet<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self.data {
             Data::Failure(err) => std::fmt::Display::fmt(err, f),
-            Data::Success(set) => {
+            Data::Success(set, _) => {
                 writeln!(f, "{{")?;
                 for item in set {
                     writeln!(f, "{},", indent(format!("{item:?}")))?;
@@ -513,7 +633,7 @@ impl<T: Debug> std::fmt::Display for ProvenSet<T> {
     }
 }
 
-fn indent(s: impl std::fmt::Display) -> String {
+pub(super) fn indent(s: impl std::fmt::Display) -> String {
     let s = s.to_string();
     let lines: Vec<String> = s
         .lines()
@@ -531,6 +651,7 @@ fn indent(s: impl std::fmt::Display) -> String {
 /// Otherwise, returns returns an error.
 pub trait TryIntoIter {
     type IntoIter: Iterator<Item = Self::Item>;
+    type ProofIter: Iterator<Item = (Self::Item, Option<Arc<ProofTree>>)>;
     type Item;
 
     /// `value` is the result of the expression `foo`.
@@ -540,10 +661,18 @@ pub trait TryIntoIter {
         self,
         stringify_expr: impl FnOnce() -> String,
     ) -> Result<Self::IntoIter, RuleFailureCause>;
+
+    /// Like [`Self::try_into_iter`], but pairs each element with the proof tree
+    /// showing how it was proven, if there is one.
+    fn try_into_proof_iter(
+        self,
+        stringify_expr: impl FnOnce() -> String,
+    ) -> Result<Self::ProofIter, RuleFailureCause>;
 }
 
-impl<T> TryIntoIter for ProvenSet<T> {
+impl<T: Ord> TryIntoIter for ProvenSet<T> {
     type IntoIter = <Set<T> as IntoIterator>::IntoIter;
+    type ProofIter = std::vec::IntoIter<(T, Option<Arc<ProofTree>>)>;
     type Item = T;
 
     fn try_into_iter(
@@ -552,13 +681,31 @@ impl<T> TryIntoIter for ProvenSet<T> {
     ) -> Result<Self::IntoIter, RuleFailureCause> {
         match self.data {
             Data::Failure(e) => Err(RuleFailureCause::FailedJudgment(e)),
-            Data::Success(s) => Ok(s.into_iter()),
+            Data::Success(s, _) => Ok(s.into_iter()),
+        }
+    }
+
+    fn try_into_proof_iter(
+        self,
+        _stringify_expr: impl FnOnce() -> String,
+    ) -> Result<Self::ProofIter, RuleFailureCause> {
+        match self.data {
+            Data::Failure(e) => Err(RuleFailureCause::FailedJudgment(e)),
+            Data::Success(s, mut proofs) => Ok(s
+                .into_iter()
+                .map(|item| {
+                    let proof = proofs.remove(&item);
+                    (item, proof)
+                })
+                .collect::<Vec<_>>()
+                .into_iter()),
         }
     }
 }
 
-impl<'a, T> TryIntoIter for &'a ProvenSet<T> {
+impl<'a, T: Ord> TryIntoIter for &'a ProvenSet<T> {
     type IntoIter = <&'a Set<T> as IntoIterator>::IntoIter;
+    type ProofIter = std::vec::IntoIter<(&'a T, Option<Arc<ProofTree>>)>;
     type Item = &'a T;
 
     fn try_into_iter(
@@ -567,15 +714,39 @@ impl<'a, T> TryIntoIter for &'a ProvenSet<T> {
     ) -> Result<Self::IntoIter, RuleFailureCause> {
         match &self.data {
             Data::Failure(e) => Err(RuleFailureCause::FailedJudgment(e.clone())),
-            Data::Success(s) => Ok(s.iter()),
+            Data::Success(s, _) => Ok(s.iter()),
+        }
+    }
+
+    fn try_into_proof_iter(
+        self,
+        _stringify_expr: impl FnOnce() -> String,
+    ) -> Result<Self::ProofIter, RuleFailureCause> {
+        match &self.data {
+            Data::Failure(e) => Err(RuleFailureCause::FailedJudgment(e.clone())),
+            Data::Success(s, proofs) => Ok(s
+                .iter()
+                .map(|item| (item, proofs.get(item).cloned()))
+                .collect::<Vec<_>>()
+                .into_iter()),
         }
     }
 }
 
 impl<T: IntoIterator> TryIntoIter for T {
     type IntoIter = std::iter::Peekable<<T as IntoIterator>::IntoIter>;
+    type ProofIter = std::iter::Zip<Self::IntoIter, std::iter::Repeat<Option<Arc<ProofTree>>>>;
     type Item = <T as IntoIterator>::Item;
 
+    fn try_into_proof_iter(
+        self,
+        stringify_expr: impl FnOnce() -> String,
+    ) -> Result<Self::ProofIter, RuleFailureCause> {
+        Ok(self
+            .try_into_iter(stringify_expr)?
+            .zip(std::iter::repeat(None)))
+    }
+
     fn try_into_iter(
         self,
         stringify_expr: impl FnOnce() -> String,
@@ -0,0 +1,178 @@
+use crate::Set;
+
+use super::{proven_set::indent, FailedJudgment, FailedRule, RuleFailureCause};
+
+/// How many of the most specific failures a [`FailureSummary`] shows.
+const MAX_SHOWN: usize = 3;
+
+/// A concise report of why a judgment failed, showing only the most specific
+/// judgments that failed for a reason other than a nested judgment failing
+/// (e.g., there was no impl to apply, or no rules applied to a nested judgment),
+/// along with how they were reached.
+///
+/// Failures rank by depth, except that those within a nested judgment to which
+/// nothing applied at any depth (its rules failed only because no rules applied to
+/// what they required, or because of cycles) all rank at the depth of that judgment,
+/// outermost first. Such a judgment is usually the real mismatch (e.g., `u32 = bool`),
+/// while the failures deeper within it (e.g., that `u32` is not an alias) are incidental.
+///
+/// Cyclic proof attempts are omitted unless nothing more specific failed.
+/// (Rules that fail before their match commit point are never recorded in the first place.)
+/// The full failure is available from [`Self::failure`].
+#[derive(Clone, Debug)]
+pub struct FailureSummary {
+    failure: Box<FailedJudgment>,
+    leaves: Vec<Leaf>,
+}
+
+/// A failed judgment with at least one rule that failed for a reason
+/// other than a nested judgment failing.
+#[derive(Clone, Debug)]
+struct Leaf {
+    failed: FailedJudgment,
+
+    /// The judgments and rules through which `failed` was reached, starting from the root.
+    path: Vec<(String, Option<String>)>,
+
+    /// The depth at which `failed` ranks, see [`FailureSummary`].
+    rank: usize,
+}
+
+impl FailedJudgment {
+    /// Summarizes this failure, see [`FailureSummary`].
+    pub fn into_summary(self: Box<Self>) -> FailureSummary {
+        let mut leaves = vec![];
+        collect_leaves(&self, &mut vec![], None, &mut leaves, false);
+        if leaves.is_empty() {
+            collect_leaves(&self, &mut vec![], None, &mut leaves, true);
+        }
+
+        // Deepest rank first, and then outermost first; the sort is stable,
+        // so ties remain in the order they were found.
+        leaves.sort_by_key(|leaf| (std::cmp::Reverse(leaf.rank), leaf.path.len()));
+
+        let mut seen = Set::new();
+        leaves.retain(|leaf| seen.insert(leaf.failed.judgment.clone()));
+
+        FailureSummary {
+            failure: self,
+            leaves,
+        }
+    }
+}
+
+/// Collects the leaves within `failed`, which was reached through `path`.
+/// If `failed` is within a judgment to which nothing applied, `vacuous_rank` is the rank of that judgment.
+fn collect_leaves(
+    failed: &FailedJudgment,
+    path: &mut Vec<(String, Option<String>)>,
+    vacuous_rank: Option<usize>,
+    leaves: &mut Vec<Leaf>,
+    include_cycles: bool,
+) {
+    let vacuous_rank = vacuous_rank.or_else(|| is_vacuous(failed).then_some(path.len()));
+
+    // Rules that failed because no rules applied to a nested judgment are only
+    // shown when no rule of this judgment failed for a more concrete reason.
+    let has_concrete = failed
+        .failed_rules
+        .iter()
+        .any(|rule| is_concrete(&rule.cause));
+    let leaf_rules: Set<FailedRule> = failed
+        .failed_rules
+        .iter()
+        .filter(|rule| match &rule.cause {
+            RuleFailureCause::FailedJudgment(nested) => {
+                !has_concrete && nested.failed_rules.is_empty()
+            }
+            RuleFailureCause::Cycle { .. } => include_cycles,
+            _ => true,
+        })
+        .cloned()
+        .collect();
+    if !leaf_rules.is_empty() {
+        leaves.push(Leaf {
+            failed: FailedJudgment {
+                judgment: failed.judgment.clone(),
+                failed_rules: leaf_rules,
+            },
+            path: path.clone(),
+            rank: vacuous_rank.unwrap_or(path.len()),
+        });
+    }
+
+    for rule in &failed.failed_rules {
+        if let RuleFailureCause::FailedJudgment(nested) = &rule.cause {
+            if nested.failed_rules.is_empty() {
+                continue;
+            }
+            let rule_name = rule.rule_name_index.as_ref().map(|(name, _)| name.clone());
+            path.push((judgment_name(&failed.judgment).to_string(), rule_name));
+            collect_leaves(nested, path, vacuous_rank, leaves, include_cycles);
+            path.pop();
+        }
+    }
+}
+
+/// True if `cause` is neither a nested judgment failing nor a cycle.
+fn is_concrete(cause: &RuleFailureCause) -> bool {
+    !matches!(
+        cause,
+        RuleFailureCause::FailedJudgment(_) | RuleFailureCause::Cycle { .. }
+    )
+}
+
+/// True if nothing applied to `failed` at any depth: each of its rules failed
+/// because no rules applied to a nested judgment, because of a cycle, or because
+/// nothing applied to a nested judgment either.
+fn is_vacuous(failed: &FailedJudgment) -> bool {
+    failed.failed_rules.iter().all(|rule| match &rule.cause {
+        RuleFailureCause::FailedJudgment(nested) => is_vacuous(nested),
+        RuleFailureCause::Cycle { .. } => true,
+        _ => false,
+    })
+}
+
+/// The name of the judgment in `judgment`, e.g., `prove_wc` in `prove_wc { goal: ... }`.
+fn judgment_name(judgment: &str) -> &str {
+    judgment.split([' ', '{', '(']).next().unwrap_or(judgment)
+}
+
+impl FailureSummary {
+    /// The complete failure that was summarized.
+    pub fn failure(&self) -> &FailedJudgment {
+        &self.failure
+    }
+}
+
+impl std::fmt::Display for FailureSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "judgment `{}` failed", self.failure.judgment)?;
+        if self.leaves.is_empty() {
+            return Ok(());
+        }
+        write!(f, ", most specifically because:")?;
+
+        for Leaf { failed, path, .. } in self.leaves.iter().take(MAX_SHOWN) {
+            write!(f, "\n{}", indent(failed))?;
+            if !path.is_empty() {
+                let path: Vec<String> = path
+                    .iter()
+                    .map(|(judgment, rule)| match rule {
+                        Some(rule) => format!("{judgment} ({rule:?})"),
+                        None => judgment.clone(),
+                    })
+                    .collect();
+                write!(f, "\n{}", indent(format!("via {}", path.join(" > "))))?;
+            }
+        }
+
+        let hidden = self.leaves.len().saturating_sub(MAX_SHOWN);
+        if hidden > 0 {
+            write!(f, "\n  ...and {hidden} less specific failure(s)")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FailureSummary {}
@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use crate::{cast_impl, judgment_fn};
+use formality_macros::test;
+use std::{cell::Cell, sync::Arc};
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Debug, Hash)]
+struct Graph {
+    edges: Vec<(u32, u32)>,
+}
+
+cast_impl!(Graph);
+
+thread_local! {
+    /// Number of times the rules of `reachable` have asked for successors.
+    static VISITS: Cell<usize> = const { Cell::new(0) };
+}
+
+impl Graph {
+    fn successors(&self, n: u32) -> Vec<u32> {
+        VISITS.with(|v| v.set(v.get() + 1));
+        self.edges
+            .iter()
+            .flat_map(|(a, b)| if *a == n { Some(*b) } else { None })
+            .collect()
+    }
+}
+
+fn visits() -> usize {
+    VISITS.with(|v| v.get())
+}
+
+judgment_fn! {
+    fn reachable(
+        graph: Arc<Graph>,
+        from: u32,
+    ) => u32 {
+        debug(from, graph)
+
+        cached(true)
+
+        (
+            (graph.successors(start) => s)
+            --------------------------------------- ("base")
+            (reachable(graph, start) => s)
+        )
+
+        (
+            (graph.successors(a) => b)
+            (reachable(&graph, b) => c)
+            --------------------------------------- ("transitive")
+            (reachable(graph, a) => c)
+        )
+    }
+}
+
+#[test]
+fn cached_result_is_reused() {
+    let graph = Arc::new(Graph {
+        edges: vec![(0, 1), (1, 2)],
+    });
+
+    reachable(&graph, 0).assert_ok(expect_test::expect![[r#"
+        {
+          1,
+          2,
+        }
+    "#]]);
+
+    // Everything reachable from `0` was computed along the way.
+    let before = visits();
+    reachable(&graph, 0).assert_ok(expect_test::expect![[r#"
+        {
+          1,
+          2,
+        }
+    "#]]);
+    reachable(&graph, 1).assert_ok(expect_test::expect![[r#"
+        {
+          2,
+        }
+    "#]]);
+    assert_eq!(visits(), before);
+}
+
+#[test]
+fn provisional_result_is_not_cached() {
+    let graph = Arc::new(Graph {
+        edges: vec![(0, 1), (1, 0)],
+    });
+
+    // Computing `reachable(1)` observes the provisional result of `reachable(0)`,
+    // which is still in progress, so only `reachable(0)` is cached.
+    reachable(&graph, 0).assert_ok(expect_test::expect![[r#"
+        {
+          0,
+          1,
+        }
+    "#]]);
+
+    let before = visits();
+    reachable(&graph, 0).assert_ok(expect_test::expect![[r#"
+        {
+          0,
+          1,
+        }
+    "#]]);
+    assert_eq!(visits(), before);
+
+    reachable(&graph, 1).assert_ok(expect_test::expect![[r#"
+        {
+          0,
+          1,
+        }
+    "#]]);
+    assert!(visits() > before);
+}
+
+#[test]
+fn cleared_result_is_recomputed() {
+    let graph = Arc::new(Graph {
+        edges: vec![(0, 1)],
+    });
+
+    reachable(&graph, 0).assert_ok(expect_test::expect![[r#"
+        {
+          1,
+        }
+    "#]]);
+
+    crate::judgment::clear_judgment_caches();
+    let before = visits();
+    reachable(&graph, 0).assert_ok(expect_test::expect![[r#"
+        {
+          1,
+        }
+    "#]]);
+    assert!(visits() > before);
+}
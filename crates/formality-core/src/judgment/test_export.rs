@@ -0,0 +1,126 @@
+#![cfg(test)]
+
+use crate::{judgment::with_proof_trees, judgment_fn};
+use formality_macros::test;
+
+judgment_fn! {
+    fn even(
+        n: u32,
+    ) => u32 {
+        debug(n)
+
+        (
+            (if n == 0)
+            --------------------------------------- ("zero")
+            (even(n) => n)
+        )
+
+        (
+            (if n >= 2)
+            (even(n - 2) => _m)
+            --------------------------------------- ("plus two")
+            (even(n) => n)
+        )
+    }
+}
+
+#[test]
+fn proof_json() {
+    let json = with_proof_trees(|| even(2)).to_json();
+    expect_test::expect![[r#"
+        {
+          "version": 1,
+          "result": "proven",
+          "items": [
+            {
+              "output": "2",
+              "proof": {
+                "kind": "proof",
+                "judgment": "even { n: 2 }",
+                "rule": "plus two",
+                "output": "2",
+                "children": [
+                  {
+                    "kind": "proof",
+                    "judgment": "even { n: 0 }",
+                    "rule": "zero",
+                    "output": "0",
+                    "children": []
+                  }
+                ]
+              }
+            }
+          ]
+        }"#]]
+    .assert_eq(&json);
+}
+
+#[test]
+fn proof_dot() {
+    let dot = with_proof_trees(|| even(2)).to_dot();
+    expect_test::expect![[r#"
+        digraph derivation {
+          n0 [label="even { n: 2 }\lby rule \"plus two\"\lproduced 2\l", shape=box];
+          n1 [label="even { n: 0 }\lby rule \"zero\"\lproduced 0\l", shape=box];
+          n0 -> n1;
+        }"#]]
+    .assert_eq(&dot);
+}
+
+#[test]
+fn failure_json() {
+    let json = even(1).to_json();
+    expect_test::expect![[r#"
+        {
+          "version": 1,
+          "result": "failed",
+          "failure": {
+            "kind": "failed_judgment",
+            "judgment": "even { n: 1 }",
+            "failed_rules": [
+              {
+                "rule": "plus two",
+                "step": 0,
+                "file": "crates/formality-core/src/judgment/test_export.rs",
+                "line": 19,
+                "column": 17,
+                "cause": {
+                  "kind": "if_false",
+                  "expr": "n >= 2",
+                  "args": []
+                }
+              },
+              {
+                "rule": "zero",
+                "step": 0,
+                "file": "crates/formality-core/src/judgment/test_export.rs",
+                "line": 13,
+                "column": 17,
+                "cause": {
+                  "kind": "if_false",
+                  "expr": "n == 0",
+                  "args": []
+                }
+              }
+            ]
+          }
+        }"#]]
+    .assert_eq(&json);
+}
+
+#[test]
+fn failure_dot() {
+    let dot = even(1).to_dot();
+    expect_test::expect![[r#"
+        digraph derivation {
+          n0 [label="even { n: 1 }\l", shape=box, color=red];
+          n1 [label="rule \"plus two\" failed at step #0\l(crates/formality-core/src/judgment/test_export.rs:19:17)\l", shape=ellipse];
+          n0 -> n1;
+          n2 [label="condition evaluted to false: `n >= 2`\l", shape=note];
+          n1 -> n2;
+          n3 [label="rule \"zero\" failed at step #0\l(crates/formality-core/src/judgment/test_export.rs:13:17)\l", shape=ellipse];
+          n0 -> n3;
+          n4 [label="condition evaluted to false: `n == 0`\l", shape=note];
+          n3 -> n4;
+        }"#]].assert_eq(&dot);
+}
@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use crate::{cast_impl, judgment::with_proof_trees, judgment_fn};
+use formality_macros::test;
+use std::sync::Arc;
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Debug, Hash)]
+struct Graph {
+    edges: Vec<(u32, u32)>,
+}
+
+cast_impl!(Graph);
+
+impl Graph {
+    fn successors(&self, n: u32) -> Vec<u32> {
+        self.edges
+            .iter()
+            .flat_map(|(a, b)| if *a == n { Some(*b) } else { None })
+            .collect()
+    }
+}
+
+judgment_fn! {
+    fn reachable(
+        graph: Arc<Graph>,
+        from: u32,
+    ) => u32 {
+        debug(from, graph)
+
+        trivial(from == 99 => 99)
+
+        cached(true)
+
+        (
+            (graph.successors(start) => s)
+            --------------------------------------- ("edge")
+            (reachable(graph, start) => s)
+        )
+
+        (
+            (reachable(&graph, a) => b)
+            (reachable(&graph, b) => c)
+            --------------------------------------- ("transitive")
+            (reachable(graph, a) => c)
+        )
+    }
+}
+
+#[test]
+fn proof_tree() {
+    let graph = Arc::new(Graph {
+        edges: vec![(0, 1), (1, 2), (2, 99)],
+    });
+
+    let result = with_proof_trees(|| reachable(&graph, 1));
+    result.assert_proofs(expect_test::expect![[r#"
+        judgment `reachable { from: 1, graph: Graph { edges: [(0, 1), (1, 2), (2, 99)] } }` produced `2` by the rule "edge"
+        judgment `reachable { from: 1, graph: Graph { edges: [(0, 1), (1, 2), (2, 99)] } }` produced `99` by the rule "transitive"
+          judgment `reachable { from: 1, graph: Graph { edges: [(0, 1), (1, 2), (2, 99)] } }` produced `2` by the rule "cycle"
+          judgment `reachable { from: 2, graph: Graph { edges: [(0, 1), (1, 2), (2, 99)] } }` produced `99` by the rule "edge""#]]);
+
+    let proof = result.proof(&99).unwrap();
+    assert!(proof.uses_rule("edge"));
+    assert!(!proof.uses_rule("trivial"));
+    assert_eq!(proof.iter().count(), 3);
+}
+
+#[test]
+fn trivial_proof_tree() {
+    let graph = Arc::new(Graph { edges: vec![] });
+
+    with_proof_trees(|| reachable(&graph, 99)).assert_proofs(expect_test::expect![[r#"judgment `reachable { from: 99, graph: Graph { edges: [] } }` produced `99` by the rule "trivial""#]]);
+}
+
+#[test]
+fn no_proof_tree_unless_recording() {
+    let graph = Arc::new(Graph {
+        edges: vec![(0, 1)],
+    });
+
+    let result = reachable(&graph, 0);
+    assert!(result.is_proven());
+    assert!(result.proof(&1).is_none());
+
+    // Results cached while not recording are recomputed with their proof trees.
+    assert!(with_proof_trees(|| reachable(&graph, 0))
+        .proof(&1)
+        .is_some());
+}
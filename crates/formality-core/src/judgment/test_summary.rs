@@ -0,0 +1,189 @@
+#![cfg(test)]
+
+use crate::judgment_fn;
+use formality_macros::test;
+
+judgment_fn! {
+    fn even(
+        n: u32,
+    ) => u32 {
+        debug(n)
+
+        (
+            (if n == 0)
+            --------------------------------------- ("zero")
+            (even(n) => n)
+        )
+
+        (
+            (if n >= 2)
+            (even(n - 2) => _m)
+            --------------------------------------- ("plus two")
+            (even(n) => n)
+        )
+
+        (
+            (even(n) => m)
+            --------------------------------------- ("again")
+            (even(n) => m)
+        )
+    }
+}
+
+/// Failures are reported deepest first, omitting the cyclic uses of "again".
+#[test]
+fn deepest_failure() {
+    let summary = even(5).into_set().unwrap_err().into_summary();
+    expect_test::expect![[r#"
+        judgment `even { n: 5 }` failed, most specifically because:
+          judgment `even { n: 1 }` failed at the following rule(s):
+            the rule "plus two" failed at step #0 (crates/formality-core/src/judgment/test_summary.rs:19:17) because
+              condition evaluted to false: `n >= 2`
+            the rule "zero" failed at step #0 (crates/formality-core/src/judgment/test_summary.rs:13:17) because
+              condition evaluted to false: `n == 0`
+          via even ("plus two") > even ("plus two")
+          judgment `even { n: 3 }` failed at the following rule(s):
+            the rule "zero" failed at step #0 (crates/formality-core/src/judgment/test_summary.rs:13:17) because
+              condition evaluted to false: `n == 0`
+          via even ("plus two")
+          judgment `even { n: 5 }` failed at the following rule(s):
+            the rule "zero" failed at step #0 (crates/formality-core/src/judgment/test_summary.rs:13:17) because
+              condition evaluted to false: `n == 0`"#]]
+    .assert_eq(&summary.to_string());
+}
+
+judgment_fn! {
+    fn cyclic(
+        n: u32,
+    ) => u32 {
+        debug(n)
+
+        (
+            (cyclic(n) => m)
+            --------------------------------------- ("again")
+            (cyclic(n) => m)
+        )
+    }
+}
+
+/// When there is nothing but cycles, the cycles are reported.
+#[test]
+fn only_cycles() {
+    let summary = cyclic(0).into_set().unwrap_err().into_summary();
+    expect_test::expect![[r#"
+        judgment `cyclic { n: 0 }` failed, most specifically because:
+          judgment `cyclic { n: 0 }` failed at the following rule(s):
+            the rule "again" failed at step #0 (crates/formality-core/src/judgment/test_summary.rs:62:14) because
+              cyclic proof attempt: `cyclic { n: 0 }`"#]]
+    .assert_eq(&summary.to_string());
+}
+
+judgment_fn! {
+    fn alias(
+        n: u32,
+    ) => u32 {
+        debug(n)
+
+        (
+            (if n >= 100)!
+            --------------------------------------- ("alias")
+            (alias(n) => n - 100)
+        )
+    }
+}
+
+judgment_fn! {
+    fn normalize(
+        n: u32,
+    ) => u32 {
+        debug(n)
+
+        (
+            (alias(n) => m)
+            --------------------------------------- ("alias")
+            (normalize(n) => m)
+        )
+    }
+}
+
+judgment_fn! {
+    fn same(
+        a: u32,
+        b: u32,
+    ) => u32 {
+        debug(a, b)
+
+        (
+            (if a == b)
+            --------------------------------------- ("equal")
+            (same(a, b) => a)
+        )
+    }
+}
+
+judgment_fn! {
+    fn eq(
+        a: u32,
+        b: u32,
+    ) => u32 {
+        debug(a, b)
+
+        (
+            (normalize(a) => c)
+            (eq(c, b) => d)
+            --------------------------------------- ("normalize")
+            (eq(a, b) => d)
+        )
+
+        (
+            (eq(b, a) => c)
+            --------------------------------------- ("symmetric")
+            (eq(a, b) => c)
+        )
+    }
+}
+
+judgment_fn! {
+    fn sub(
+        a: u32,
+        b: u32,
+    ) => u32 {
+        debug(a, b)
+
+        (
+            (same(a, b) => c)
+            --------------------------------------- ("same")
+            (sub(a, b) => c)
+        )
+
+        (
+            (eq(a, b) => c)
+            --------------------------------------- ("eq")
+            (sub(a, b) => c)
+        )
+    }
+}
+
+/// Nothing applied to `eq(1, 2)`, so the failures within it rank with the rule that required it,
+/// not by their own depth: `normalize { n: 2 }` is the deepest failure, but not the informative one.
+#[test]
+fn deepest_failure_within_vacuous_judgment() {
+    let summary = sub(1, 2).into_set().unwrap_err().into_summary();
+    expect_test::expect![[r#"
+        judgment `sub { a: 1, b: 2 }` failed, most specifically because:
+          judgment `same { a: 1, b: 2 }` failed at the following rule(s):
+            the rule "equal" failed at step #0 (crates/formality-core/src/judgment/test_summary.rs:117:17) because
+              condition evaluted to false: `a == b`
+                a = 1
+                b = 2
+          via sub ("same")
+          judgment `normalize { n: 1 }` failed at the following rule(s):
+            the rule "alias" failed at step #0 (crates/formality-core/src/judgment/test_summary.rs:102:14) because
+              judgment had no applicable rules: `alias { n: 1 }`
+          via sub ("eq") > eq ("normalize")
+          judgment `normalize { n: 2 }` failed at the following rule(s):
+            the rule "alias" failed at step #0 (crates/formality-core/src/judgment/test_summary.rs:102:14) because
+              judgment had no applicable rules: `alias { n: 2 }`
+          via sub ("eq") > eq ("symmetric") > eq ("normalize")"#]]
+    .assert_eq(&summary.to_string());
+}
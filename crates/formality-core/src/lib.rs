@@ -184,15 +184,36 @@ macro_rules! declare_language {
             {
                 $crate::parse::core_term_with::<FormalityLang, T, B>(bindings, text)
             }
+
+            /// Parses `text` as a term with no bindings in scope, also recording
+            /// the spans of the terms of the types in `spanned`.
+            #[track_caller]
+            pub fn try_term_with_spans<T>(text: &str, spanned: &[std::any::TypeId]) -> $crate::Fallible<(T, $crate::parse::Spans)>
+            where
+                T: Parse,
+            {
+                $crate::parse::core_term_with_spans::<FormalityLang, T, _>(None::<(String, grammar::Variable)>, text, spanned)
+            }
         }
     }
 }
 
 /// Declares a newtyped struct that is an arbitrary (string) identifier,
 /// like the name of a module or something.
+///
+/// With `id!(Name, reject_variables)`, the name of an in-scope variable is not
+/// parsed as an identifier, leaving it to be parsed as the variable instead.
 #[macro_export]
 macro_rules! id {
     ($n:ident) => {
+        $crate::id!(@define $n, false);
+    };
+
+    ($n:ident, reject_variables) => {
+        $crate::id!(@define $n, true);
+    };
+
+    (@define $n:ident, $reject_variables:expr) => {
         #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct $n {
             data: std::sync::Arc<String>,
@@ -249,6 +270,9 @@ macro_rules! id {
                     text: &'t str,
                 ) -> parse::ParseResult<'t, Self> {
                     $crate::parse::Parser::single_variant(scope, text, stringify!($n), |p| {
+                        if $reject_variables {
+                            p.reject_variable()?;
+                        }
                         let string = p.identifier()?;
                         Ok($n::new(&string))
                     })
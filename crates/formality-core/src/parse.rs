@@ -27,6 +27,9 @@ pub trait CoreParse<L: Language>: Sized + Debug + Clone + Eq + 'static + Upcast<
 mod parser;
 pub use parser::{skip_whitespace, ActiveVariant, Parser, Precedence};
 
+mod span;
+pub use span::{Location, Span, Spans};
+
 /// Parses `text` as a term with the given bindings in scope.
 ///
 /// References to the given string will be replaced with the given parameter
@@ -58,6 +61,23 @@ where
     Ok(value)
 }
 
+/// Like [`core_term_with`], but also records the spans of the terms
+/// of the types in `spanned`, see [`Spans`].
+#[track_caller]
+pub fn core_term_with_spans<L, T, B>(
+    bindings: impl IntoIterator<Item = B>,
+    text: &str,
+    spanned: &[std::any::TypeId],
+) -> Fallible<(T, Spans)>
+where
+    T: CoreParse<L>,
+    L: Language,
+    B: Upcast<(String, CoreVariable<L>)>,
+{
+    let (value, spans) = span::with_spans(text, spanned, || core_term_with(bindings, text));
+    Ok((value?, spans))
+}
+
 /// Record from a successful parse.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SuccessfulParse<'t, T> {
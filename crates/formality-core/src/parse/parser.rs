@@ -344,6 +344,7 @@ where
                 .all(|(s_j, j)| i == j || Self::is_preferable(s_i, s_j))
             {
                 let s_i = self.successes.into_iter().nth(i).unwrap();
+                super::span::record(self.start_text, s_i.text, &s_i.value);
                 // It's better to print this result alongside the main parsing section.
                 drop(guard);
                 tracing::trace!("best parse = `{:?}`", s_i);
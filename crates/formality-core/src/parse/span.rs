@@ -0,0 +1,152 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+};
+
+/// A range of bytes in the text that was parsed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A line and column in the text that was parsed, both starting from 1.
+/// Columns count characters, not bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// The spans of the terms parsed by [`core_term_with_spans`](`super::core_term_with_spans`),
+/// for the types that were requested.
+///
+/// Terms do not carry their spans. Instead, the terms of a type are matched against
+/// the recorded ones in the order in which they appear in the input,
+/// so that equal terms at different positions each get their own span.
+#[derive(Default)]
+pub struct Spans {
+    text: String,
+    terms: Vec<(Span, Box<dyn Any>)>,
+}
+
+impl Spans {
+    /// The recorded terms of type `T` within `span`, in the order in which they appear
+    /// in the input. Terms nested in another recorded term of type `T` are skipped.
+    pub fn terms_within<T: 'static>(&self, span: Span) -> Vec<(Span, &T)> {
+        let mut terms: Vec<(Span, &T)> = self
+            .terms
+            .iter()
+            .filter(|(s, _)| span.start <= s.start && s.end <= span.end)
+            .filter_map(|(s, t)| Some((*s, t.downcast_ref::<T>()?)))
+            .collect();
+        // Outermost first, so that nested terms come after the term containing them.
+        terms.sort_by_key(|(s, _)| (s.start, std::cmp::Reverse(s.end)));
+        let mut outermost: Vec<(Span, &T)> = vec![];
+        for (s, t) in terms {
+            match outermost.last() {
+                Some((last, _)) if s.start < last.end => {}
+                _ => outermost.push((s, t)),
+            }
+        }
+        outermost
+    }
+
+    /// The spans of `terms`, which appear in the input in the given order.
+    /// Each term is matched with the first recorded term equal to it that follows
+    /// the match of the previous term.
+    pub fn spans_of<'a, T: PartialEq + 'static>(
+        &self,
+        terms: impl IntoIterator<Item = &'a T>,
+    ) -> Vec<Option<Span>> {
+        let recorded = self.terms_within::<T>(Span {
+            start: 0,
+            end: self.text.len(),
+        });
+        let mut next = 0;
+        terms
+            .into_iter()
+            .map(|term| {
+                let index = next + recorded[next..].iter().position(|(_, t)| *t == term)?;
+                next = index + 1;
+                Some(recorded[index].0)
+            })
+            .collect()
+    }
+
+    /// The location of the start of `span`.
+    pub fn location(&self, span: Span) -> Location {
+        let before = &self.text[..span.start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+struct Recorder {
+    /// Address of the start of the text being parsed.
+    start: usize,
+    types: Vec<TypeId>,
+    spans: Spans,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Invokes `op` to parse `text`, recording the spans of the terms whose types are in `types`.
+pub(super) fn with_spans<R>(text: &str, types: &[TypeId], op: impl FnOnce() -> R) -> (R, Spans) {
+    struct Restore(Option<Recorder>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            RECORDER.with(|r| *r.borrow_mut() = self.0.take());
+        }
+    }
+
+    let recorder = Recorder {
+        start: text.as_ptr() as usize,
+        types: types.to_vec(),
+        spans: Spans {
+            text: text.to_string(),
+            terms: vec![],
+        },
+    };
+    let _restore = Restore(RECORDER.with(|r| r.replace(Some(recorder))));
+    let result = op();
+    let spans = RECORDER.with(|r| r.borrow_mut().take());
+    (result, spans.map(|r| r.spans).unwrap_or_default())
+}
+
+/// Records that `value` was parsed from the text between `start` and `end`,
+/// both of which are suffixes of the text given to [`with_spans`].
+/// Does nothing unless spans of terms of type `T` are being recorded.
+pub(super) fn record<T: Clone + 'static>(start: &str, end: &str, value: &T) {
+    RECORDER.with(|r| {
+        let mut r = r.borrow_mut();
+        let Some(recorder) = &mut *r else {
+            return;
+        };
+        if !recorder.types.contains(&TypeId::of::<T>()) {
+            return;
+        }
+        let offset = |s: &str| (s.as_ptr() as usize).checked_sub(recorder.start);
+        let (Some(start), Some(end)) = (offset(start), offset(end)) else {
+            return;
+        };
+        if end > recorder.spans.text.len() {
+            return;
+        }
+        recorder
+            .spans
+            .terms
+            .push((Span { start, end }, Box::new(value.clone())));
+    });
+}
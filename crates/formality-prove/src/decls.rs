@@ -1,8 +1,8 @@
-use formality_core::{set, Set, Upcast};
+use formality_core::{set, Downcast, Set, Upcast};
 use formality_macros::term;
 use formality_types::grammar::{
-    AdtId, AliasName, AliasTy, Binder, Parameter, Predicate, Relation, TraitId, TraitRef, Ty, Wc,
-    Wcs,
+    AdtId, AliasName, AliasTy, Binder, Coinductive, FnId, Parameter, Predicate, Relation,
+    RigidName, RigidTy, TraitId, TraitRef, Ty, Variance, Wc, WcData, Wcs,
 };
 
 #[term]
@@ -15,7 +15,10 @@ pub struct Decls {
     pub neg_impl_decls: Vec<NegImplDecl>,
     pub alias_eq_decls: Vec<AliasEqDecl>,
     pub alias_bound_decls: Vec<AliasBoundDecl>,
+    pub alias_wf_decls: Vec<AliasWfDecl>,
     pub adt_decls: Vec<AdtDecl>,
+    pub fn_decls: Vec<FnDecl>,
+    pub trait_fn_decls: Vec<TraitFnDecl>,
     pub local_trait_ids: Set<TraitId>,
     pub local_adt_ids: Set<AdtId>,
 }
@@ -58,6 +61,31 @@ impl Decls {
         v.pop().unwrap()
     }
 
+    /// A goal is coinductive if each of its where-clauses is either a well-formedness
+    /// relation or names a coinductive trait. Cycles proving coinductive goals succeed.
+    pub fn is_coinductive(&self, goal: impl Upcast<Wcs>) -> Coinductive {
+        let goal: Wcs = goal.upcast();
+        goal.into_iter()
+            .map(|wc| self.wc_is_coinductive(&wc))
+            .fold(Coinductive::Yes, |a, b| a & b)
+    }
+
+    fn wc_is_coinductive(&self, wc: &Wc) -> Coinductive {
+        match wc.data() {
+            WcData::Predicate(Predicate::IsImplemented(trait_ref)) => self
+                .trait_decls
+                .iter()
+                .find(|t| t.id == trait_ref.trait_id)
+                .map(|t| t.is_coinductive())
+                .unwrap_or_default(),
+            WcData::Predicate(_) => Coinductive::No,
+            WcData::Relation(Relation::WellFormed(_)) => Coinductive::Yes,
+            WcData::Relation(_) => Coinductive::No,
+            WcData::ForAll(binder) => self.wc_is_coinductive(binder.peek()),
+            WcData::Implies(_, wc) => self.wc_is_coinductive(wc),
+        }
+    }
+
     pub fn alias_eq_decls<'s>(
         &'s self,
         name: &'s AliasName,
@@ -67,8 +95,28 @@ impl Decls {
             .filter(move |a| a.alias_name() == *name)
     }
 
-    pub fn alias_bound_decls(&self) -> &[AliasBoundDecl] {
-        &self.alias_bound_decls
+    pub fn alias_bound_decls<'s>(
+        &'s self,
+        name: &'s AliasName,
+    ) -> impl Iterator<Item = &'s AliasBoundDecl> {
+        self.alias_bound_decls
+            .iter()
+            .filter(move |a| a.alias_name() == *name)
+    }
+
+    /// The where-clauses that must hold for `alias` to be well-formed,
+    /// in addition to its trait-ref being implemented; see [`AliasWfDecl`].
+    pub fn alias_wf_where_clauses(&self, alias: &AliasTy) -> Wcs {
+        self.alias_wf_decls
+            .iter()
+            .filter(|a| a.alias_name() == alias.name)
+            .flat_map(|a| {
+                a.binder
+                    .instantiate_with(&alias.parameters)
+                    .unwrap()
+                    .where_clause
+            })
+            .collect()
     }
 
     pub fn adt_decl(&self, adt_id: &AdtId) -> &AdtDecl {
@@ -78,6 +126,166 @@ impl Decls {
         v.pop().unwrap()
     }
 
+    pub fn fn_decl(&self, fn_id: &FnId) -> &FnDecl {
+        let mut v: Vec<_> = self.fn_decls.iter().filter(|f| f.id == *fn_id).collect();
+        assert!(!v.is_empty(), "no function named `{fn_id:?}`");
+        assert!(v.len() <= 1, "multiple functions named `{fn_id:?}`");
+        v.pop().unwrap()
+    }
+
+    /// The methods declared in the trait `trait_id`.
+    pub fn trait_fn_decls(&self, trait_id: &TraitId) -> Vec<TraitFnDecl> {
+        self.trait_fn_decls
+            .iter()
+            .filter(|f| f.trait_id == *trait_id)
+            .cloned()
+            .collect()
+    }
+
+    /// The associated types declared in the trait `trait_id`, as their [`AliasWfDecl`]s.
+    pub fn associated_ty_wf_decls(&self, trait_id: &TraitId) -> Vec<AliasWfDecl> {
+        self.alias_wf_decls
+            .iter()
+            .filter(|a| {
+                let AliasName::AssociatedTyId(name) = a.alias_name();
+                name.trait_id == *trait_id
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// The variance of each parameter of the ADT `adt_id`.
+    pub fn adt_variances(&self, adt_id: &AdtId) -> Vec<Variance> {
+        let decl = self.adt_decl(adt_id);
+        if decl.variances.is_empty() {
+            vec![Variance::Invariant; decl.binder.len()]
+        } else {
+            decl.variances.clone()
+        }
+    }
+
+    /// The variance of each of the `arity` parameters of a rigid type named `name`.
+    pub fn variances(&self, name: &RigidName, arity: usize) -> Vec<Variance> {
+        match name {
+            RigidName::AdtId(adt_id) => self.adt_variances(adt_id),
+            _ => name.builtin_variances(arity).unwrap(),
+        }
+    }
+
+    pub fn lang_item(&self, trait_id: &TraitId) -> LangItem {
+        self.trait_decl(trait_id).lang_item
+    }
+
+    /// The trait declared as the lang item `lang_item`, if any.
+    pub fn lang_item_trait(&self, lang_item: LangItem) -> Option<TraitId> {
+        self.trait_decls
+            .iter()
+            .find(|t| t.lang_item == lang_item)
+            .map(|t| t.id.clone())
+    }
+
+    pub fn is_auto_trait(&self, trait_id: &TraitId) -> bool {
+        self.trait_decl(trait_id).auto == Auto::Yes
+    }
+
+    /// True if there is an explicit positive or negative impl of `trait_id` for
+    /// some rigid type named `name`. Such impls replace the structural impl of an auto trait.
+    pub fn has_explicit_impl_for(&self, trait_id: &TraitId, name: &RigidName) -> bool {
+        let names_self_ty = |trait_ref: &TraitRef| match trait_ref.parameters[0].downcast() {
+            Some(RigidTy {
+                name: n,
+                parameters: _,
+            }) => n == *name,
+            None => false,
+        };
+        self.impl_decls(trait_id)
+            .any(|i| names_self_ty(&i.binder.peek().trait_ref))
+            || self
+                .neg_impl_decls(trait_id)
+                .any(|i| names_self_ty(&i.binder.peek().trait_ref))
+    }
+
+    /// The types that must implement an auto trait for the rigid type `name<parameters>` to do so:
+    /// the field types for an ADT, the upvar types for a closure, and the type parameters
+    /// for other builtin types. Trait objects have no components: they implement only
+    /// the auto traits they name.
+    pub fn auto_trait_components(
+        &self,
+        name: &RigidName,
+        parameters: &[Parameter],
+    ) -> Option<Vec<Ty>> {
+        match name {
+            RigidName::AdtId(adt_id) => {
+                let decl = self.adt_decl(adt_id);
+                Some(decl.binder.instantiate_with(parameters).unwrap().field_tys)
+            }
+            RigidName::Closure(_, _, arity) => Some(
+                parameters
+                    .iter()
+                    .skip(arity + 1)
+                    .filter_map(|p| p.downcast::<Ty>())
+                    .collect(),
+            ),
+            RigidName::FnPtr(_) | RigidName::FnDef(_) | RigidName::ScalarId(_) => Some(vec![]),
+            RigidName::Ref(_) | RigidName::Tuple(_) => Some(
+                parameters
+                    .iter()
+                    .filter_map(|p| p.downcast::<Ty>())
+                    .collect(),
+            ),
+            RigidName::DynTrait(_) => None,
+        }
+    }
+
+    /// `trait_ref` followed by the supertraits it implies, transitively: e.g., given
+    /// `trait Ord where Self: PartialOrd`, `T: Ord` implies `T: PartialOrd`.
+    pub fn supertrait_refs(&self, trait_ref: &TraitRef) -> Vec<TraitRef> {
+        let mut trait_refs = vec![trait_ref.clone()];
+        let mut index = 0;
+        while let Some(trait_ref) = trait_refs.get(index).cloned() {
+            let TraitDeclBoundData { where_clause } = self
+                .trait_decl(&trait_ref.trait_id)
+                .binder
+                .instantiate_with(&trait_ref.parameters)
+                .unwrap();
+            for wc in where_clause {
+                if let Some(Predicate::IsImplemented(supertrait_ref)) = wc.downcast() {
+                    if supertrait_ref.parameters[0] == trait_ref.parameters[0]
+                        && !trait_refs.contains(&supertrait_ref)
+                    {
+                        trait_refs.push(supertrait_ref);
+                    }
+                }
+            }
+            index += 1;
+        }
+        trait_refs
+    }
+
+    /// The associated types without parameters of the traits implied by `trait_ref`,
+    /// as the aliases they name for its self type: e.g., `<T as Iterator>::Item` for `T: Iterator`.
+    /// These are the associated types whose values a trait object type must give.
+    pub fn associated_ty_aliases(&self, trait_ref: &TraitRef) -> Vec<AliasTy> {
+        self.supertrait_refs(trait_ref)
+            .into_iter()
+            .flat_map(|trait_ref| {
+                self.alias_wf_decls
+                    .iter()
+                    .filter_map(move |a| match a.alias_name() {
+                        AliasName::AssociatedTyId(name)
+                            if name.trait_id == trait_ref.trait_id && name.item_arity == 0 =>
+                        {
+                            Some(AliasTy {
+                                name: name.upcast(),
+                                parameters: trait_ref.parameters.clone(),
+                            })
+                        }
+                        _ => None,
+                    })
+            })
+            .collect()
+    }
+
     /// Return the set of "trait invariants" for all traits.
     /// See [`TraitDecl::trait_invariants`].
     pub fn trait_invariants(&self) -> Set<TraitInvariant> {
@@ -95,7 +303,10 @@ impl Decls {
             neg_impl_decls: vec![],
             alias_eq_decls: vec![],
             alias_bound_decls: vec![],
+            alias_wf_decls: vec![],
             adt_decls: vec![],
+            fn_decls: vec![],
+            trait_fn_decls: vec![],
             local_trait_ids: set![],
             local_adt_ids: set![],
         }
@@ -149,23 +360,77 @@ pub enum Safety {
     Unsafe,
 }
 
+/// Mark a trait as an `auto` trait, which rigid types implement if their components do,
+/// unless there is an explicit (positive or negative) impl for the type.
+#[term]
+#[derive(Copy, Default)]
+pub enum Auto {
+    #[default]
+    #[grammar(manual)]
+    No,
+    #[grammar(auto)]
+    Yes,
+}
+
+/// Mark a trait as one of the built-in traits that the prover knows about,
+/// like `#[lang(copy)] trait Copy {}`. Rigid types implement these traits
+/// without needing impls; see `prove::builtin_impls`.
+#[term]
+#[derive(Copy, Default)]
+pub enum LangItem {
+    #[default]
+    #[grammar(#[lang(none)])]
+    None,
+    #[grammar(#[lang(sized)])]
+    Sized,
+    #[grammar(#[lang(copy)])]
+    Copy,
+    #[grammar(#[lang(clone)])]
+    Clone,
+    #[grammar(#[lang(drop)])]
+    Drop,
+    #[grammar(#[lang(fn_once)])]
+    FnOnce,
+    #[grammar(#[lang(fn_mut)])]
+    FnMut,
+    #[grammar(#[lang(fn)])]
+    Fn,
+}
+
 /// A "trait declaration" declares a trait that exists, its generics, and its where-clauses.
 /// It doesn't capture the trait items, which will be transformed into other sorts of rules.
 ///
 /// In Rust syntax, it covers the `trait Foo: Bar` part of the declaration, but not what appears in the `{...}`.
-#[term($?safety trait $id $binder)]
+#[term($?lang_item $?safety $?auto $?coinductive trait $id $binder)]
 pub struct TraitDecl {
     /// The name of the trait
     pub id: TraitId,
 
+    /// The built-in trait this declaration stands for, if any
+    pub lang_item: LangItem,
+
     /// Whether the trait is `unsafe` or not
     pub safety: Safety,
 
+    /// Whether the trait is an `auto` trait
+    pub auto: Auto,
+
+    /// Whether cycles proving the trait are accepted
+    pub coinductive: Coinductive,
+
     /// The binder here captures the generics of the trait; it always begins with a `Self` type.
     pub binder: Binder<TraitDeclBoundData>,
 }
 
 impl TraitDecl {
+    /// Auto traits are always coinductive, so that they hold for recursive types.
+    pub fn is_coinductive(&self) -> Coinductive {
+        match self.auto {
+            Auto::Yes => Coinductive::Yes,
+            Auto::No => self.coinductive,
+        }
+    }
+
     /// Return the set of "trait invariants", i.e., things we know to be true
     /// because of the trait where-clauses. For example, given `trait Ord<ty Self> where {PartialOrd(Self)}`,
     /// this would return the set `{trait_invariant(<ty Self> Ord(Self) => PartialOrd(Self)}`
@@ -285,22 +550,88 @@ pub struct AliasBoundDeclBoundData {
     pub where_clause: Wcs,
 }
 
+/// Alias well-formedness declarations give the where-clauses declared on an associated type,
+/// which must hold for an alias type to be well-formed.
+/// For example given a trait `trait Foo { type Bar<U>: [] where U: Baz; }`
+/// the alias `<T as Foo>::Bar<U>` is only well-formed if `T: Foo` and `U: Baz`.
+#[term(alias $binder)]
+pub struct AliasWfDecl {
+    /// The binder covers the parameters of the alias, in order:
+    /// those of the trait followed by those of the associated type.
+    pub binder: Binder<AliasWfDeclBoundData>,
+}
+
+impl AliasWfDecl {
+    pub fn alias_name(&self) -> AliasName {
+        self.binder.peek().alias.name.clone()
+    }
+}
+
+#[term(wf $alias $:where $where_clause)]
+pub struct AliasWfDeclBoundData {
+    pub alias: AliasTy,
+    pub where_clause: Wcs,
+}
+
 /// An "ADT declaration" declares an ADT name, its generics, and its where-clauses.
 /// It doesn't capture the ADT fields, yet.
 ///
 /// In Rust syntax, it covers the `struct Foo<X> where X: Bar` part of the declaration, but not what appears in the `{...}`.
-#[term(adt $id $binder)]
+#[term(adt $id $binder $:variances $[variances])]
 pub struct AdtDecl {
     /// The name of the ADT.
     pub id: AdtId,
 
     /// The binder here captures the generics of the ADT.
     pub binder: Binder<AdtDeclBoundData>,
+
+    /// The variance of each generic parameter, inferred from the ADT's fields.
+    /// If empty, all parameters are treated as invariant.
+    pub variances: Vec<Variance>,
 }
 
 /// The "bound data" for a [`AdtDecl`][].
-#[term($:where $where_clause)]
+#[term($:where $where_clause $:fields $[field_tys])]
 pub struct AdtDeclBoundData {
     /// The where-clauses declared on the ADT,
     pub where_clause: Wcs,
+
+    /// The types of the fields of all variants of the ADT.
+    pub field_tys: Vec<Ty>,
+}
+
+/// A "fn declaration" declares the signature of a function, so that the prover
+/// knows how the type of the function (`FnDef`) can be called.
+#[term(fn $id $binder)]
+pub struct FnDecl {
+    /// The name of the function.
+    pub id: FnId,
+
+    /// The binder here captures the generics of the function.
+    pub binder: Binder<FnDeclBoundData>,
+}
+
+/// The "bound data" for a [`FnDecl`][].
+#[term(($,input_tys) -> $output_ty $:where $where_clause)]
+pub struct FnDeclBoundData {
+    pub input_tys: Vec<Ty>,
+    pub output_ty: Ty,
+
+    /// The where-clauses that must hold to call the function.
+    pub where_clause: Wcs,
+}
+
+/// A "trait fn declaration" declares the signature of a method of a trait,
+/// which decides whether the method can be called on a trait object type `dyn Trait`.
+#[term(fn $trait_id :: $id $binder)]
+pub struct TraitFnDecl {
+    /// The trait that declares the method.
+    pub trait_id: TraitId,
+
+    /// The name of the method.
+    pub id: FnId,
+
+    /// The outer binder captures the generics of the trait, beginning with the `Self` type;
+    /// the inner one captures the generics of the method.
+    pub binder: Binder<Binder<FnDeclBoundData>>,
 }
@@ -1,3 +1,4 @@
+mod builtin_impls;
 mod combinators;
 mod constraints;
 mod env;
@@ -5,8 +6,11 @@ mod is_local;
 mod minimize;
 mod negation;
 mod prove_after;
+mod prove_dyn_compatible;
 mod prove_eq;
 mod prove_normalize;
+mod prove_outlives;
+mod prove_sub;
 mod prove_via;
 mod prove_wc;
 mod prove_wc_list;
@@ -22,6 +26,7 @@ use tracing::Level;
 use crate::decls::Decls;
 
 pub use self::env::{Bias, Env};
+use self::prove_after::prove_after;
 use self::prove_wc_list::prove_wc_list;
 pub use negation::{is_definitely_not_proveable, may_not_be_provable, negation_via_failure};
 
@@ -63,11 +68,23 @@ pub fn prove(
             f.write_str(&self.0)
         }
     }
-    let result_set =
-        match prove_wc_list(decls, &env, assumptions, goal).try_into_iter(|| "".to_string()) {
-            Ok(s) => ProvenSet::from_iter(s),
-            Err(e) => ProvenSet::failed_rules(label, set![FailedRule::new(e)]),
-        };
+    let result_set = match prove_wc_list(&decls, &env, &assumptions, goal)
+        .try_into_proof_iter(|| "".to_string())
+    {
+        Ok(s) => ProvenSet::from_proofs(s),
+        Err(e) => ProvenSet::failed_rules(label, set![FailedRule::new(e)]),
+    };
+
+    // Outlives relations that no longer involve existential lifetimes can be
+    // checked against the assumptions.
+    let result_set = result_set.flat_map(|c| {
+        let (c, outlives) = c.take_universal_outlives();
+        if outlives.is_empty() {
+            ProvenSet::singleton(c)
+        } else {
+            prove_after(&decls, c, &assumptions, outlives)
+        }
+    });
 
     tracing::debug!(?result_set);
 
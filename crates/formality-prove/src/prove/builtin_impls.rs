@@ -0,0 +1,255 @@
+use formality_core::{Downcast, Upcast, Upcasted};
+use formality_types::grammar::{
+    AliasName, AliasTy, AssociatedItemId, AssociatedTyName, Binder, ClosureKind, DynName,
+    Parameter, Predicate, RefKind, RigidName, RigidTy, TraitId, TraitRef, Ty, Wcs,
+};
+
+use crate::decls::{AliasEqDecl, AliasEqDeclBoundData, Decls, FnDeclBoundData, LangItem};
+
+/// If the rigid self type of `trait_ref` has a built-in impl of a lang-item trait,
+/// returns the where-clauses of that impl. For example, `(A, B): Copy` holds if
+/// `A: Copy` and `B: Copy`.
+///
+/// There is no built-in impl of `Drop`. Fn pointers, fn definitions and closures
+/// implement the `Fn` traits (as permitted by the closure's kind) for their argument types.
+///
+/// A trait object implements its principal trait, if that trait is dyn-compatible, and the
+/// auto traits it names; it implements the supertraits of its principal trait through the
+/// trait invariants.
+pub(super) fn builtin_impl(decls: &Decls, trait_ref: &TraitRef) -> Option<Wcs> {
+    let TraitRef {
+        trait_id,
+        parameters,
+    } = trait_ref;
+    let self_ty: RigidTy = parameters[0].downcast()?;
+    let RigidTy {
+        name,
+        parameters: self_parameters,
+    } = self_ty.clone();
+
+    if let RigidName::DynTrait(dyn_name) = &name {
+        if dyn_name.auto_traits.contains(trait_id) {
+            return Some(Wcs::t());
+        }
+        let principal = dyn_name.principal_trait_ref(&self_ty);
+        if principal.trait_id != *trait_id {
+            return None;
+        }
+        return Some(
+            (
+                Wcs::all_eq(&parameters[1..], &principal.parameters[1..]),
+                Predicate::DynCompatible(trait_id.clone()),
+            )
+                .upcast(),
+        );
+    }
+
+    match (decls.lang_item(trait_id), &name) {
+        (LangItem::Sized, RigidName::AdtId(adt_id)) => {
+            let decl = decls.adt_decl(adt_id);
+            let field_tys = decl
+                .binder
+                .instantiate_with(&self_parameters)
+                .unwrap()
+                .field_tys;
+            Some(all_implement(trait_id, field_tys))
+        }
+        (LangItem::Sized, RigidName::Tuple(_)) => {
+            Some(all_implement(trait_id, tys(&self_parameters)))
+        }
+        (
+            LangItem::Sized,
+            RigidName::ScalarId(_)
+            | RigidName::Ref(_)
+            | RigidName::FnPtr(_)
+            | RigidName::FnDef(_)
+            | RigidName::Closure(..),
+        ) => Some(Wcs::t()),
+
+        (LangItem::Copy | LangItem::Clone, RigidName::Tuple(_)) => {
+            Some(all_implement(trait_id, tys(&self_parameters)))
+        }
+        (LangItem::Copy | LangItem::Clone, RigidName::Closure(_, _, arity)) => {
+            Some(all_implement(trait_id, tys(&self_parameters[arity + 1..])))
+        }
+        (
+            LangItem::Copy | LangItem::Clone,
+            RigidName::ScalarId(_)
+            | RigidName::Ref(RefKind::Shared)
+            | RigidName::FnPtr(_)
+            | RigidName::FnDef(_),
+        ) => Some(Wcs::t()),
+
+        // `fn(A, B) -> R: Fn<(A, B)>`, and likewise for fn definitions and closures
+        (lang_item @ (LangItem::FnOnce | LangItem::FnMut | LangItem::Fn), _)
+            if parameters.len() == 2 =>
+        {
+            let signature = Signature::of(decls, &name, &self_parameters)?;
+            if !signature.implements(lang_item) {
+                return None;
+            }
+            Some(
+                (
+                    Wcs::all_eq(&parameters[1..], vec![signature.args()]),
+                    signature.where_clause,
+                )
+                    .upcast(),
+            )
+        }
+
+        _ => None,
+    }
+}
+
+/// If `alias` is `<F as FnOnce<Args>>::Output` for a rigid callable type `F`,
+/// returns the declaration equating it with the return type of `F`, as the impl
+/// of `FnOnce` would if it were written out. If `alias` is an associated type of
+/// a trait object type, returns the declaration equating it with the value given
+/// in the type, e.g., `<dyn Iterator<Item = u32> + 'a as Iterator>::Item = u32`.
+pub(super) fn builtin_alias_eq_decl(decls: &Decls, alias: &AliasTy) -> Option<AliasEqDecl> {
+    if let Some(RigidTy {
+        name: RigidName::DynTrait(dyn_name),
+        parameters: _,
+    }) = alias.parameters.first().and_then(|p| p.downcast())
+    {
+        return dyn_alias_eq_decl(decls, &dyn_name, alias);
+    }
+
+    let AliasTy { name, parameters } = alias;
+    let AliasName::AssociatedTyId(AssociatedTyName {
+        trait_id,
+        item_id,
+        item_arity: 0,
+    }) = name
+    else {
+        return None;
+    };
+    if decls.lang_item_trait(LangItem::FnOnce).as_ref() != Some(trait_id)
+        || *item_id != AssociatedItemId::new("Output")
+        || parameters.len() != 2
+    {
+        return None;
+    }
+
+    let RigidTy {
+        name: self_name,
+        parameters: self_parameters,
+    } = parameters[0].downcast()?;
+    let signature = Signature::of(decls, &self_name, &self_parameters)?;
+    Some(AliasEqDecl {
+        binder: Binder::dummy(AliasEqDeclBoundData {
+            alias: AliasTy {
+                name: name.clone(),
+                parameters: vec![parameters[0].clone(), signature.args()],
+            },
+            ty: signature.output_ty.downcast()?,
+            where_clause: signature.where_clause,
+        }),
+    })
+}
+
+/// The declaration equating `alias` with the value given for it in the trait object type
+/// named `dyn_name` that is its self type, if `alias` names one of the associated types
+/// of the principal trait or its supertraits.
+fn dyn_alias_eq_decl(decls: &Decls, dyn_name: &DynName, alias: &AliasTy) -> Option<AliasEqDecl> {
+    let self_ty: RigidTy = alias.parameters[0].downcast()?;
+    let (_, values, _) = dyn_name.split_parameters(&self_ty.parameters);
+    let declared_alias = decls
+        .associated_ty_aliases(&dyn_name.principal_trait_ref(&self_ty))
+        .into_iter()
+        .find(|a| a.name == alias.name)?;
+    let AliasName::AssociatedTyId(AssociatedTyName { item_id, .. }) = &alias.name;
+    let index = dyn_name.projections.iter().position(|p| p == item_id)?;
+    Some(AliasEqDecl {
+        binder: Binder::dummy(AliasEqDeclBoundData {
+            alias: declared_alias,
+            ty: values[index].downcast()?,
+            where_clause: Wcs::t(),
+        }),
+    })
+}
+
+/// The signature of a callable rigid type.
+struct Signature {
+    /// The most permissive `Fn` trait the type implements.
+    kind: ClosureKind,
+    input_tys: Vec<Parameter>,
+    output_ty: Parameter,
+    /// The where-clauses that must hold to call it.
+    where_clause: Wcs,
+}
+
+impl Signature {
+    fn of(decls: &Decls, name: &RigidName, parameters: &[Parameter]) -> Option<Signature> {
+        match name {
+            // The parameters of `FnPtr(n)` are the `n` argument types followed by the return type.
+            RigidName::FnPtr(arity) if parameters.len() == arity + 1 => Some(Signature {
+                kind: ClosureKind::Fn,
+                input_tys: parameters[..*arity].to_vec(),
+                output_ty: parameters[*arity].clone(),
+                where_clause: Wcs::t(),
+            }),
+
+            RigidName::FnDef(fn_id) => {
+                let FnDeclBoundData {
+                    input_tys,
+                    output_ty,
+                    where_clause,
+                } = decls
+                    .fn_decl(fn_id)
+                    .binder
+                    .instantiate_with(parameters)
+                    .ok()?;
+                Some(Signature {
+                    kind: ClosureKind::Fn,
+                    input_tys: input_tys.upcasted().collect(),
+                    output_ty: output_ty.upcast(),
+                    where_clause,
+                })
+            }
+
+            // The argument types and return type are followed by the upvar types.
+            RigidName::Closure(_, kind, arity) if parameters.len() > *arity => Some(Signature {
+                kind: *kind,
+                input_tys: parameters[..*arity].to_vec(),
+                output_ty: parameters[*arity].clone(),
+                where_clause: Wcs::t(),
+            }),
+
+            _ => None,
+        }
+    }
+
+    /// Whether the type implements the `Fn` trait `lang_item`:
+    /// everything callable through `Fn` is callable through `FnMut`, and so on.
+    fn implements(&self, lang_item: LangItem) -> bool {
+        let trait_kind = match lang_item {
+            LangItem::Fn => ClosureKind::Fn,
+            LangItem::FnMut => ClosureKind::FnMut,
+            LangItem::FnOnce => ClosureKind::FnOnce,
+            _ => return false,
+        };
+        self.kind <= trait_kind
+    }
+
+    /// The tuple of argument types, which is the parameter of the `Fn` traits.
+    fn args(&self) -> Parameter {
+        RigidTy {
+            name: RigidName::Tuple(self.input_tys.len()),
+            parameters: self.input_tys.clone(),
+        }
+        .upcast()
+    }
+}
+
+fn tys(parameters: &[Parameter]) -> Vec<Ty> {
+    parameters.iter().filter_map(|p| p.downcast()).collect()
+}
+
+/// Goals requiring each of `tys` to implement `trait_id`.
+fn all_implement(trait_id: &TraitId, tys: Vec<Ty>) -> Wcs {
+    tys.into_iter()
+        .map(|ty| trait_id.with(ty, Vec::<Parameter>::new()))
+        .upcasted()
+        .collect()
+}
@@ -1,15 +1,28 @@
 use super::env::Env;
-use formality_core::{cast_impl, visit::CoreVisit, Downcast, Upcast, UpcastFrom};
+use formality_core::{
+    cast_impl, visit::CoreVisit, Downcast, Fallible, ProvenSet, Set, Upcast, UpcastFrom, Upcasted,
+};
 use formality_types::{
-    grammar::{ExistentialVar, Parameter, Substitution, Variable},
+    grammar::{ExistentialVar, Lt, Parameter, Relation, Substitution, Variable, Wcs},
     rust::Visit,
 };
 
+#[cfg(test)]
+mod test;
+
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub struct Constraints {
     pub env: Env,
     pub known_true: bool,
     pub substitution: Substitution,
+
+    /// Outlives relations `a: b` that must hold but which have been deferred
+    /// rather than proven, because they involve existential lifetimes.
+    /// The substitution has already been applied to them.
+    ///
+    /// When existential lifetimes go out of scope, they are eliminated (see [`Constraints::pop_subst`]),
+    /// leaving relations between universal variables that are proven against the assumptions.
+    pub outlives: Set<(Parameter, Parameter)>,
 }
 
 cast_impl!(Constraints);
@@ -31,7 +44,7 @@ impl Constraints {
     }
 
     pub fn unconditionally_true(&self) -> bool {
-        self.known_true && self.substitution.is_empty()
+        self.known_true && self.substitution.is_empty() && self.outlives.is_empty()
     }
 
     pub fn from(
@@ -46,6 +59,7 @@ impl Constraints {
             env,
             substitution,
             known_true: true,
+            outlives: Set::new(),
         };
         c2.assert_valid();
         c2
@@ -59,6 +73,40 @@ impl Constraints {
         &self.substitution
     }
 
+    /// Extend `self` with the (deferred) requirement that `a: b`.
+    pub fn with_outlives(self, a: impl Upcast<Parameter>, b: impl Upcast<Parameter>) -> Self {
+        let mut outlives = self.outlives;
+        insert_outlives(&mut outlives, a.upcast(), b.upcast());
+        let c = Constraints { outlives, ..self };
+        c.assert_valid();
+        c
+    }
+
+    /// Removes the outlives relations that no longer involve any existential variables,
+    /// returning them as goals to be proven.
+    pub fn take_universal_outlives(mut self) -> (Self, Wcs) {
+        let (universal, existential) = self
+            .outlives
+            .into_iter()
+            .partition(|relation| !has_existentials(relation));
+        self.outlives = existential;
+        (self, outlives_goals(universal))
+    }
+
+    /// The relations between universal variables (and `'static`) that must hold
+    /// for there to be some choice of existential lifetimes satisfying `self.outlives`.
+    pub fn universal_outlives(&self) -> Fallible<Wcs> {
+        let existentials: Vec<Variable> = self
+            .env
+            .variables()
+            .iter()
+            .filter(|v| v.is_a::<ExistentialVar>())
+            .copied()
+            .collect();
+        let outlives = eliminate_outlives(self.outlives.clone(), &existentials)?;
+        Ok(outlives_goals(outlives))
+    }
+
     pub fn ambiguous(self) -> Constraints {
         Self {
             known_true: false,
@@ -94,29 +142,41 @@ impl Constraints {
             .iter()
             .all(|v| !occurs_in(v, &c2.substitution)));
 
-        // Apply c2's substitution to our substitution (since it may have bound
-        // existential variables that we reference)
+        // Apply c2's substitution to our substitution and outlives relations
+        // (since it may have bound existential variables that we reference)
         let c1_substitution = c2.substitution.apply(&self.substitution);
+        let c1_outlives = c2.substitution.apply(&self.outlives);
 
         Constraints {
             env: c2.env,
             known_true: self.known_true && c2.known_true,
             substitution: c1_substitution.into_iter().chain(c2.substitution).collect(),
+            outlives: c1_outlives.into_iter().chain(c2.outlives).collect(),
         }
     }
 
-    pub fn pop_subst<V>(mut self, v: &[V]) -> Self
+    /// Removes the variables `v` (and any created after them) from the environment,
+    /// eliminating them from the outlives relations. Fails if the relations cannot
+    /// be satisfied without naming the removed variables.
+    pub fn pop_subst<V>(mut self, v: &[V]) -> ProvenSet<Self>
     where
         V: Upcast<Variable> + Copy,
     {
         if v.is_empty() {
-            return self;
+            return ProvenSet::singleton(self);
         }
 
         let vars = self.env.pop_vars(v);
-        self.substitution -= vars;
+        self.substitution -= &vars;
 
-        self
+        match eliminate_outlives(std::mem::take(&mut self.outlives), &vars) {
+            Ok(outlives) => {
+                self.outlives = outlives;
+                self.assert_valid();
+                ProvenSet::singleton(self)
+            }
+            Err(e) => ProvenSet::failed("pop_subst", e),
+        }
     }
 
     pub fn is_valid_extension_of(&self, env0: &Env) -> bool {
@@ -130,6 +190,7 @@ impl CoreVisit<crate::FormalityLang> for Constraints {
             env,
             known_true: _,
             substitution,
+            outlives,
         } = self;
 
         // Debatable if `env.free_variables()` should be considered
@@ -138,6 +199,7 @@ impl CoreVisit<crate::FormalityLang> for Constraints {
         env.free_variables()
             .into_iter()
             .chain(substitution.free_variables())
+            .chain(outlives.free_variables())
             .collect()
     }
 
@@ -146,8 +208,9 @@ impl CoreVisit<crate::FormalityLang> for Constraints {
             env,
             known_true: _,
             substitution,
+            outlives,
         } = self;
-        env.size() + substitution.size()
+        env.size() + substitution.size() + outlives.size()
     }
 
     fn assert_valid(&self) {
@@ -155,8 +218,14 @@ impl CoreVisit<crate::FormalityLang> for Constraints {
             env,
             known_true: _,
             substitution,
+            outlives,
         } = self;
 
+        assert!(env.encloses(outlives));
+        outlives
+            .iter()
+            .for_each(|relation| assert!(domain_free(substitution, relation)));
+
         let domain = substitution.domain();
         let range = substitution.range();
 
@@ -185,6 +254,83 @@ impl CoreVisit<crate::FormalityLang> for Constraints {
     }
 }
 
+/// Removes `vars` from the outlives relations in `outlives`:
+///
+/// * an existential lifetime `?v` is eliminated by relating everything that must
+///   outlive it to everything it must outlive (`a: ?v` and `?v: b` imply `a: b`);
+/// * a universal variable `!u` may be any lifetime at all, so `a: !u` requires `a: 'static`,
+///   and `!u: b` cannot hold for any other `b`.
+///
+/// Variables are eliminated innermost universe first, as an existential can only be
+/// chosen to name variables from its own universe or outer ones. Eliminating `?v` before
+/// a placeholder `!u` from an inner universe would discharge `!u: ?v` as though `?v`
+/// could be chosen as `!u`, rather than requiring it for all values of `!u`.
+fn eliminate_outlives(
+    mut outlives: Set<(Parameter, Parameter)>,
+    vars: &[Variable],
+) -> Fallible<Set<(Parameter, Parameter)>> {
+    for &v in vars.iter().rev() {
+        if v.is_a::<ExistentialVar>() {
+            let v: Parameter = v.upcast();
+            let (about_v, rest): (Set<_>, Set<_>) =
+                outlives.into_iter().partition(|(a, b)| *a == v || *b == v);
+            outlives = rest;
+            for (a, _) in about_v.iter().filter(|(_, b)| *b == v) {
+                for (_, b) in about_v.iter().filter(|(a, _)| *a == v) {
+                    insert_outlives(&mut outlives, a.clone(), b.clone());
+                }
+            }
+        } else {
+            let (about_v, rest): (Set<_>, Set<_>) = outlives
+                .into_iter()
+                .partition(|relation| occurs_in(v, relation));
+            outlives = rest;
+            let v: Parameter = v.upcast();
+            for (a, b) in about_v {
+                if b == v && a != v {
+                    insert_outlives(&mut outlives, a, Lt::static_());
+                } else {
+                    anyhow::bail!("`{a:?}: {b:?}` does not hold for all values of `{v:?}`");
+                }
+            }
+        }
+    }
+
+    Ok(outlives)
+}
+
+/// Adds `a: b` to `outlives`, unless it is trivially true.
+fn insert_outlives(
+    outlives: &mut Set<(Parameter, Parameter)>,
+    a: impl Upcast<Parameter>,
+    b: impl Upcast<Parameter>,
+) {
+    let a: Parameter = a.upcast();
+    let b: Parameter = b.upcast();
+    if a != b && a != Lt::static_().upcast() {
+        outlives.insert((a, b));
+    }
+}
+
+fn outlives_goals(outlives: Set<(Parameter, Parameter)>) -> Wcs {
+    outlives
+        .into_iter()
+        .map(|(a, b)| Relation::outlives(a, b))
+        .upcasted()
+        .collect()
+}
+
+fn has_existentials(t: &impl Visit) -> bool {
+    t.free_variables()
+        .iter()
+        .any(|v| v.is_a::<ExistentialVar>())
+}
+
+/// True if none of the variables bound by `substitution` appear in `t`.
+fn domain_free(substitution: &Substitution, t: &impl Visit) -> bool {
+    substitution.domain().iter().all(|&v| !occurs_in(v, t))
+}
+
 pub fn occurs_in(v: impl Upcast<Variable>, t: &impl Visit) -> bool {
     let v: Variable = v.upcast();
     t.free_variables().contains(&v)
@@ -0,0 +1,53 @@
+use expect_test::expect;
+use formality_core::Upcast;
+use formality_macros::test;
+use formality_types::grammar::{ParameterKind, Variable};
+
+use crate::prove::{Constraints, Env};
+
+/// `for<'a> exists<'b> 'a: 'b` holds, choosing `'b` to be `'a`.
+#[test]
+fn for_a_exists_b_a_outlives_b() {
+    let mut env = Env::default();
+    let a = env.fresh_universal(ParameterKind::Lt);
+    let b = env.fresh_existential(ParameterKind::Lt);
+    let c = Constraints::none(env).with_outlives(a, b);
+
+    let vars: [Variable; 2] = [a.upcast(), b.upcast()];
+    c.pop_subst(&vars).assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+/// `exists<'b> for<'a> 'a: 'b` does not hold, even when both binders are popped at once:
+/// no lifetime is outlived by every lifetime, and `'b` cannot be chosen to be `'a`.
+#[test]
+fn exists_b_for_a_a_outlives_b() {
+    let mut env = Env::default();
+    let b = env.fresh_existential(ParameterKind::Lt);
+    let a = env.fresh_universal(ParameterKind::Lt);
+    let c = Constraints::none(env).with_outlives(a, b);
+
+    let vars: [Variable; 2] = [b.upcast(), a.upcast()];
+    c.pop_subst(&vars).assert_err(expect![[r#"
+        judgment `pop_subst` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            `!lt_2: ?lt_1` does not hold for all values of `!lt_2`"#]]);
+}
+
+/// `exists<'b> for<'a> 'b: 'a` requires `'b` to be `'static`.
+#[test]
+fn exists_b_for_a_b_outlives_a() {
+    let mut env = Env::default();
+    let b = env.fresh_existential(ParameterKind::Lt);
+    let a = env.fresh_universal(ParameterKind::Lt);
+    let c = Constraints::none(env).with_outlives(b, a);
+
+    c.pop_subst(&[a]).assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [?lt_1], bias: Soundness }, known_true: true, substitution: {}, outlives: {(?lt_1, static)} },
+        }
+    "#]]);
+}
@@ -308,6 +308,13 @@ judgment_fn! {
             (is_local_parameter(decls, env, _assumptions, RigidTy { name: RigidName::AdtId(a), parameters: _ }) => Constraints::none(env))
         )
 
+        // Trait objects are local if their principal trait was declared in this crate.
+        (
+            (if decls.is_local_trait_id(&d.trait_id))
+            --- ("local trait object")
+            (is_local_parameter(decls, env, _assumptions, RigidTy { name: RigidName::DynTrait(d), parameters: _ }) => Constraints::none(env))
+        )
+
         // existential variables might or might not be local, depending on how they are instantiated.
         (
             --- ("existential variable")
@@ -333,6 +340,8 @@ fn is_fundamental(_decls: &Decls, name: &RigidName) -> bool {
         RigidName::ScalarId(_)
         | RigidName::Tuple(_)
         | RigidName::FnPtr(_)
-        | RigidName::FnDef(_) => false,
+        | RigidName::FnDef(_)
+        | RigidName::Closure(..)
+        | RigidName::DynTrait(_) => false,
     }
 }
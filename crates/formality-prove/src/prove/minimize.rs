@@ -117,6 +117,7 @@ impl Minimization {
             env: _,
             known_true,
             substitution,
+            outlives,
         } = constraints;
         let substitution: Substitution = substitution
             .iter()
@@ -124,10 +125,12 @@ impl Minimization {
                 (env2out_subst.map_var(x).unwrap(), env2out_subst.apply(&p))
             })
             .collect();
+        let outlives = env2out_subst.apply(&outlives);
         Constraints {
             env: env_out,
             known_true,
             substitution,
+            outlives,
         }
     }
 }
@@ -38,6 +38,7 @@ fn minimize_a() {
         substitution: vec![(ty1, ty2.to::<Ty>()), (ty0, ScalarId::U32.to::<Ty>())]
             .into_iter()
             .collect(),
+        outlives: Default::default(),
     };
     let c = m.reconstitute(c_min);
 
@@ -57,6 +58,7 @@ fn minimize_a() {
                 ?ty_1 => u32,
                 ?ty_3 => ?ty_4,
             },
+            outlives: {},
         }
     "#]]
     .assert_debug_eq(&c);
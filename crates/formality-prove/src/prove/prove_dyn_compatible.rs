@@ -0,0 +1,171 @@
+use formality_core::{judgment_fn, visit::CoreVisit, Downcast, Upcast};
+use formality_types::grammar::{
+    AliasName, AliasTy, AssociatedTyName, BoundVar, Parameter, ParameterKind, Predicate, RigidName,
+    RigidTy, TraitId, TraitRef, Ty, TyData, Variable, Wcs,
+};
+
+use crate::{
+    decls::{
+        AliasWfDecl, AliasWfDeclBoundData, Decls, FnDeclBoundData, LangItem, TraitDeclBoundData,
+        TraitFnDecl,
+    },
+    prove::combinators::for_all,
+};
+
+use super::{constraints::Constraints, env::Env};
+
+judgment_fn! {
+    /// A trait is dyn-compatible, i.e., it can be the principal trait of a trait object
+    /// type `dyn Trait`, if it does not require `Self: Sized`, its supertraits do not take
+    /// `Self` as a parameter and are dyn-compatible, and its methods and associated types
+    /// can be used from a trait object.
+    pub fn prove_dyn_compatible(
+        _decls: Decls,
+        env: Env,
+        assumptions: Wcs,
+        trait_id: TraitId,
+    ) => Constraints {
+        debug(trait_id, assumptions, env)
+
+        (
+            (let (vars, TraitDeclBoundData { where_clause }) = decls.trait_decl(&trait_id).binder.open())
+            (if !requires_sized_self(&decls, &vars[0], &where_clause))
+            (let supertrait_refs = supertrait_refs(&vars[0], &where_clause))
+            (if !supertrait_refs.iter().any(|t| mentions(&t.parameters[1..], &vars[0])))
+            (for_all(&decls, &env, &assumptions, &supertrait_refs, &|decls, env, assumptions, t: TraitRef| prove_dyn_compatible(decls, env, assumptions, t.trait_id)) => c1)
+            (for_all(&decls, c1.env(), &assumptions, &decls.trait_fn_decls(&trait_id), &prove_dyn_compatible_fn) => c2)
+            (for_all(&decls, c2.env(), &assumptions, &decls.associated_ty_wf_decls(&trait_id), &prove_dyn_compatible_associated_ty) => c3)
+            ----------------------------- ("dyn compatible")
+            (prove_dyn_compatible(decls, env, assumptions, trait_id) => c1.seq(&c2).seq(&c3))
+        )
+    }
+}
+
+judgment_fn! {
+    /// A method can be called on a trait object if it has no type or const parameters,
+    /// takes `self`, `&self` or `&mut self` as its first argument, and does not otherwise
+    /// mention `Self`, except as the self type of an associated type, whose value the trait
+    /// object type gives. Methods that require `Self: Sized` cannot be called on a trait
+    /// object at all, and so do not count.
+    fn prove_dyn_compatible_fn(
+        _decls: Decls,
+        env: Env,
+        _assumptions: Wcs,
+        trait_fn: TraitFnDecl,
+    ) => Constraints {
+        debug(trait_fn, _assumptions, env)
+
+        (
+            (let (vars, fn_binder) = trait_fn.binder.open())
+            (let (_, FnDeclBoundData { input_tys: _, output_ty: _, where_clause }) = fn_binder.open())
+            (if requires_sized_self(&_decls, &vars[0], &where_clause))
+            ----------------------------- ("requires Sized")
+            (prove_dyn_compatible_fn(_decls, env, _assumptions, trait_fn) => Constraints::none(env))
+        )
+
+        (
+            (let (vars, fn_binder) = trait_fn.binder.open())
+            // Lifetime parameters are fine, as they are erased.
+            (if fn_binder.kinds().iter().all(|kind| *kind == ParameterKind::Lt))
+            (let (_, FnDeclBoundData { input_tys, output_ty, where_clause: _ }) = fn_binder.open())
+            (if let Some((receiver, others)) = input_tys.split_first())
+            (if is_receiver(receiver, &vars[0]))
+            (if !others.iter().chain(Some(&output_ty)).any(|ty| mentions_outside_projections(ty, &vars[0])))
+            ----------------------------- ("dispatchable")
+            (prove_dyn_compatible_fn(_decls, env, _assumptions, trait_fn) => Constraints::none(env))
+        )
+    }
+}
+
+judgment_fn! {
+    /// An associated type can be used from a trait object if it has no parameters,
+    /// as the trait object type must give its value. Associated types that require
+    /// `Self: Sized` cannot be used from a trait object at all, and so do not count.
+    fn prove_dyn_compatible_associated_ty(
+        _decls: Decls,
+        env: Env,
+        _assumptions: Wcs,
+        associated_ty: AliasWfDecl,
+    ) => Constraints {
+        debug(associated_ty, _assumptions, env)
+
+        (
+            (let AliasName::AssociatedTyId(AssociatedTyName { item_arity, .. }) = associated_ty.alias_name())
+            (if item_arity == 0)
+            ----------------------------- ("no parameters")
+            (prove_dyn_compatible_associated_ty(_decls, env, _assumptions, associated_ty) => Constraints::none(env))
+        )
+
+        (
+            (let (vars, AliasWfDeclBoundData { alias: _, where_clause }) = associated_ty.binder.open())
+            (if requires_sized_self(&_decls, &vars[0], &where_clause))
+            ----------------------------- ("requires Sized")
+            (prove_dyn_compatible_associated_ty(_decls, env, _assumptions, associated_ty) => Constraints::none(env))
+        )
+    }
+}
+
+/// True if `where_clause` contains `Self: Sized`, where `Self` is `self_var`.
+fn requires_sized_self(decls: &Decls, self_var: &BoundVar, where_clause: &Wcs) -> bool {
+    let Some(sized_id) = decls.lang_item_trait(LangItem::Sized) else {
+        return false;
+    };
+    supertrait_refs(self_var, where_clause)
+        .iter()
+        .any(|trait_ref| trait_ref.trait_id == sized_id)
+}
+
+/// The trait-refs in `where_clause` whose self type is `self_var`.
+fn supertrait_refs(self_var: &BoundVar, where_clause: &Wcs) -> Vec<TraitRef> {
+    where_clause
+        .into_iter()
+        .filter_map(|wc| match wc.downcast() {
+            Some(Predicate::IsImplemented(trait_ref))
+                if is_var(&trait_ref.parameters[0], self_var) =>
+            {
+                Some(trait_ref)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_var(parameter: &Parameter, var: &BoundVar) -> bool {
+    *parameter == var.upcast()
+}
+
+/// True if `ty` is `Self`, `&Self` or `&mut Self`.
+fn is_receiver(ty: &Ty, self_var: &BoundVar) -> bool {
+    match ty.data() {
+        TyData::RigidTy(RigidTy {
+            name: RigidName::Ref(_),
+            parameters,
+        }) => matches!(&parameters[..], [_, referent] if is_var(referent, self_var)),
+        _ => is_var(&ty.upcast(), self_var),
+    }
+}
+
+fn mentions(parameters: &[Parameter], var: &BoundVar) -> bool {
+    parameters
+        .iter()
+        .any(|p| p.free_variables().contains(&Variable::BoundVar(*var)))
+}
+
+/// Like [`mentions`], but ignores `var` where it is the self type of an associated type,
+/// e.g. `<Self as Iterator>::Item`.
+fn mentions_outside_projections(ty: &Ty, var: &BoundVar) -> bool {
+    let parameters = match ty.data() {
+        TyData::AliasTy(AliasTy { parameters, .. }) => match parameters.split_first() {
+            Some((self_ty, others)) if is_var(self_ty, var) => others,
+            _ => parameters,
+        },
+        TyData::RigidTy(RigidTy { parameters, .. }) => parameters,
+        TyData::PredicateTy(_) | TyData::Variable(_) => {
+            return mentions(&[ty.upcast()], var);
+        }
+    };
+    parameters.iter().any(|p| match p {
+        Parameter::Ty(ty) => mentions_outside_projections(ty, var),
+        Parameter::Lt(_) | Parameter::Const(_) => mentions(&[p.clone()], var),
+    })
+}
@@ -4,10 +4,10 @@ use formality_types::grammar::{
 };
 
 use crate::{
-    decls::{AliasEqDeclBoundData, Decls},
+    decls::{AliasEqDecl, AliasEqDeclBoundData, Decls},
     prove::{
-        combinators::zip, env::Env, prove, prove_after::prove_after,
-        prove_eq::prove_existential_var_eq,
+        builtin_impls::builtin_alias_eq_decl, combinators::zip, env::Env, prove,
+        prove_after::prove_after, prove_eq::prove_existential_var_eq,
     },
 };
 
@@ -30,7 +30,7 @@ judgment_fn! {
         )
 
         (
-            (decls.alias_eq_decls(&a.name) => decl)
+            (alias_eq_decls(&decls, &a) => decl)
             (let (env, subst) = env.existential_substitution(&decl.binder))
             (let decl = decl.binder.instantiate_with(&subst).unwrap())
             (let AliasEqDeclBoundData { alias: AliasTy { name, parameters }, ty, where_clause } = decl)
@@ -38,14 +38,24 @@ judgment_fn! {
             (prove(&decls, env, &assumptions, Wcs::all_eq(&a.parameters, &parameters)) => c)
             (prove_after(&decls, c, &assumptions, &where_clause) => c)
             (let ty = c.substitution().apply(&ty))
-            (let c = c.pop_subst(&subst))
+            (c.pop_subst(&subst) => c)
             (assert c.env().encloses(&ty))
             ----------------------------- ("normalize-via-impl")
-            (prove_normalize(decls, env, assumptions, TyData::AliasTy(a)) => (c, ty))
+            (prove_normalize(decls, env, assumptions, TyData::AliasTy(a)) => (c, ty.clone()))
         )
     }
 }
 
+/// The declarations that may normalize `alias`: those derived from impls,
+/// plus the built-in one for the return type of a callable type, if any.
+fn alias_eq_decls(decls: &Decls, alias: &AliasTy) -> Vec<AliasEqDecl> {
+    decls
+        .alias_eq_decls(&alias.name)
+        .cloned()
+        .chain(builtin_alias_eq_decl(decls, alias))
+        .collect()
+}
+
 judgment_fn! {
     fn prove_normalize_via(
         _decls: Decls,
@@ -108,10 +118,10 @@ judgment_fn! {
             (let (env, subst) = env.existential_substitution(&binder))
             (let via1 = binder.instantiate_with(&subst).unwrap())
             (prove_normalize_via(decls, env, assumptions, via1, goal) => (c, p))
-            (let c = c.pop_subst(&subst))
+            (c.pop_subst(&subst) => c)
             (assert c.env().encloses(&p))
             ----------------------------- ("forall")
-            (prove_normalize_via(decls, env, assumptions, WcData::ForAll(binder), goal) => (c, p))
+            (prove_normalize_via(decls, env, assumptions, WcData::ForAll(binder), goal) => (c, p.clone()))
         )
 
         (
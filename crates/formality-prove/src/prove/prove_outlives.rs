@@ -0,0 +1,122 @@
+use formality_core::{judgment_fn, Downcast, Upcast, Upcasted};
+use formality_types::grammar::{
+    AliasTy, Const, LtData, Parameter, ParameterKind, Relation, RigidTy, Variable, Wcs,
+};
+
+use crate::{
+    decls::Decls,
+    prove::{prove, prove_after::prove_after, prove_normalize::prove_normalize},
+};
+
+use super::{constraints::Constraints, env::Env};
+
+/// Goal(s) to prove `a` outlives `b`
+pub fn outlives(a: impl Upcast<Parameter>, b: impl Upcast<Parameter>) -> Relation {
+    Relation::outlives(a, b)
+}
+
+judgment_fn! {
+    pub fn prove_outlives(
+        _decls: Decls,
+        env: Env,
+        assumptions: Wcs,
+        a: Parameter,
+        b: Parameter,
+    ) => Constraints {
+        debug(a, b, assumptions, env)
+
+        trivial(a == b => Constraints::none(env))
+
+        (
+            --- ("static")
+            (prove_outlives(_decls, env, _assumptions, LtData::Static, _b) => Constraints::none(env))
+        )
+
+        (
+            --- ("consts")
+            (prove_outlives(_decls, env, _assumptions, _a: Const, _b) => Constraints::none(env))
+        )
+
+        (
+            // A rigid type outlives `b` if all of its parameters do: e.g., `&'a T: 'b`
+            // if `'a: 'b` and `T: 'b`.
+            (prove(decls, env, assumptions, all_outlive(&parameters, &b)) => c)
+            --- ("rigid")
+            (prove_outlives(decls, env, assumptions, RigidTy { name: _, parameters }, b) => c)
+        )
+
+        (
+            // An alias outlives `b` if all of its parameters do, whatever it normalizes to.
+            (prove(decls, env, assumptions, all_outlive(&parameters, &b)) => c)
+            --- ("alias components")
+            (prove_outlives(decls, env, assumptions, AliasTy { name: _, parameters }, b) => c)
+        )
+
+        (
+            (prove_normalize(&decls, env, &assumptions, &a) => (c, y))
+            (prove_after(&decls, c, &assumptions, outlives(y, &b)) => c)
+            --- ("alias normalize")
+            (prove_outlives(decls, env, assumptions, a: AliasTy, b) => c)
+        )
+
+        (
+            // If `a: c` is assumed, then `a: b` holds if `c: b`. Assuming `x: c` also
+            // assumes it of each component of `x`: e.g., `Vec<T>: 'c` gives `T: 'c`.
+            (&assumptions => assumption)
+            (if let Some(Relation::Outlives(x, c)) = assumption.downcast())
+            (if outlives_components(&x).contains(&a))
+            (prove_outlives(&decls, &env, &assumptions, c, &b) => c)
+            --- ("transitive")
+            (prove_outlives(decls, env, assumptions, a, b) => c)
+        )
+
+        (
+            // We cannot yet decide relations between existential lifetimes, so
+            // defer them to the caller.
+            (if is_existential(&a, ParameterKind::Lt) || (is_universal(&a) && is_existential(&b, ParameterKind::Lt)))
+            --- ("existential lifetimes")
+            (prove_outlives(_decls, env, _assumptions, a, b) => Constraints::none(env).with_outlives(a, b))
+        )
+
+        (
+            // We don't know what type an existential type variable will be, so we
+            // can't tell what it outlives.
+            (if is_existential(&a, ParameterKind::Ty))
+            --- ("existential types")
+            (prove_outlives(_decls, env, _assumptions, a, _b) => Constraints::none(env).ambiguous())
+        )
+    }
+}
+
+/// Goals requiring each of `parameters` to outlive `b`.
+fn all_outlive(parameters: &[Parameter], b: &Parameter) -> Wcs {
+    parameters
+        .iter()
+        .map(|p| outlives(p, b))
+        .upcasted()
+        .collect()
+}
+
+/// `p` along with the parameters of the rigid types within it, which outlive whatever `p` does.
+fn outlives_components(p: &Parameter) -> Vec<Parameter> {
+    let mut components = vec![p.clone()];
+    if let Some(RigidTy {
+        name: _,
+        parameters,
+    }) = p.downcast()
+    {
+        components.extend(parameters.iter().flat_map(outlives_components));
+    }
+    components
+}
+
+fn is_existential(p: &Parameter, kind: ParameterKind) -> bool {
+    match p.downcast() {
+        Some(Variable::ExistentialVar(v)) => v.kind == kind,
+        _ => false,
+    }
+}
+
+fn is_universal(p: &Parameter) -> bool {
+    matches!(p.downcast(), Some(Variable::UniversalVar(_)))
+}
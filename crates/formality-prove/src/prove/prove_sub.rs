@@ -0,0 +1,99 @@
+use formality_core::{judgment_fn, Downcast, Upcast, Upcasted};
+use formality_types::grammar::{
+    Lt, Parameter, ParameterKind, Relation, RigidTy, TyData, Variance, Wcs,
+};
+
+use crate::{
+    decls::Decls,
+    prove::{
+        prove, prove_after::prove_after, prove_eq::prove_eq, prove_normalize::prove_normalize,
+        prove_outlives::prove_outlives,
+    },
+};
+
+use super::{constraints::Constraints, env::Env};
+
+/// Goal(s) to prove `a` is a subtype of `b`
+pub fn sub(a: impl Upcast<Parameter>, b: impl Upcast<Parameter>) -> Relation {
+    Relation::sub(a, b)
+}
+
+judgment_fn! {
+    pub fn prove_sub(
+        _decls: Decls,
+        env: Env,
+        assumptions: Wcs,
+        a: Parameter,
+        b: Parameter,
+    ) => Constraints {
+        debug(a, b, assumptions, env)
+
+        assert(a.kind() == b.kind())
+
+        trivial(a == b => Constraints::none(env))
+
+        (
+            // `'a <: 'b` if `'a: 'b`, i.e., a reference with lifetime `'a` may be
+            // used where one with lifetime `'b` is expected.
+            (prove_outlives(decls, env, assumptions, a, b) => c)
+            ----------------------------- ("lifetimes")
+            (prove_sub(decls, env, assumptions, a: Lt, b: Lt) => c)
+        )
+
+        (
+            (let RigidTy { name: a_name, parameters: a_parameters } = a)
+            (let RigidTy { name: b_name, parameters: b_parameters } = b)
+            (if a_name == b_name)!
+            (if a_parameters.len() == b_parameters.len())
+            (let variances = decls.variances(&a_name, a_parameters.len()))
+            (prove(decls, env, assumptions, relate_all(variances, a_parameters, b_parameters)) => c)
+            ----------------------------- ("rigid")
+            (prove_sub(decls, env, assumptions, TyData::RigidTy(a), TyData::RigidTy(b)) => c)
+        )
+
+        (
+            // Anything other than two rigid types or two lifetimes (variables, aliases,
+            // constants) is only related to itself.
+            //
+            // FIXME: an existential variable equated to a type here loses the freedom
+            // to pick different lifetimes than the other side.
+            (if a.kind() != ParameterKind::Lt)
+            (if !(is_rigid(&a) && is_rigid(&b)))
+            (prove_eq(decls, env, assumptions, a, b) => c)
+            ----------------------------- ("equate")
+            (prove_sub(decls, env, assumptions, a, b) => c)
+        )
+
+        (
+            (prove_normalize(&decls, env, &assumptions, &x) => (c, y))
+            (prove_after(&decls, c, &assumptions, sub(y, &z)) => c)
+            ----------------------------- ("normalize-l")
+            (prove_sub(decls, env, assumptions, x, z) => c)
+        )
+
+        (
+            (prove_normalize(&decls, env, &assumptions, &z) => (c, y))
+            (prove_after(&decls, c, &assumptions, sub(&x, y)) => c)
+            ----------------------------- ("normalize-r")
+            (prove_sub(decls, env, assumptions, x, z) => c)
+        )
+    }
+}
+
+/// Goals relating each of `a` to the corresponding parameter of `b` according to `variances`.
+fn relate_all(variances: Vec<Variance>, a: Vec<Parameter>, b: Vec<Parameter>) -> Wcs {
+    variances
+        .into_iter()
+        .zip(a.into_iter().zip(b))
+        .map(|(variance, (a, b))| match variance {
+            Variance::Covariant => Relation::sub(a, b),
+            Variance::Contravariant => Relation::sub(b, a),
+            Variance::Invariant => Relation::equals(a, b),
+        })
+        .upcasted()
+        .collect()
+}
+
+fn is_rigid(p: &Parameter) -> bool {
+    p.downcast::<RigidTy>().is_some()
+}
@@ -38,8 +38,9 @@ judgment_fn! {
             (let (env, subst) = env.existential_substitution(&binder))
             (let via1 = binder.instantiate_with(&subst).unwrap())
             (prove_via(decls, env, assumptions, via1, goal) => c)
+            (c.pop_subst(&subst) => c)
             ----------------------------- ("forall")
-            (prove_via(decls, env, assumptions, WcData::ForAll(binder), goal) => c.pop_subst(&subst))
+            (prove_via(decls, env, assumptions, WcData::ForAll(binder), goal) => c)
         )
 
         (
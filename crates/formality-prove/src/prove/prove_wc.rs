@@ -1,14 +1,20 @@
-use formality_core::judgment_fn;
-use formality_types::grammar::{Predicate, Relation, Wc, WcData, Wcs};
+use formality_core::{judgment_fn, Downcast, Upcast, Upcasted};
+use formality_types::grammar::{
+    AliasTy, Coinductive, Parameter, Predicate, Relation, RigidTy, TraitRef, Ty, Wc, WcData, Wcs,
+};
 
 use crate::{
     decls::Decls,
     prove::{
+        builtin_impls::builtin_impl,
         env::{Bias, Env},
         is_local::{is_local_trait_ref, may_be_remote},
         prove,
         prove_after::prove_after,
+        prove_dyn_compatible::prove_dyn_compatible,
         prove_eq::prove_eq,
+        prove_outlives::prove_outlives,
+        prove_sub::prove_sub,
         prove_via::prove_via,
         prove_wf::prove_wf,
     },
@@ -25,12 +31,19 @@ judgment_fn! {
     ) => Constraints {
         debug(goal, assumptions, env)
 
+        coinductive(_decls.is_coinductive(goal) == Coinductive::Yes => Constraints::none(env))
+
+        inductive(_decls.is_coinductive(goal) == Coinductive::No)
+
+        cached(true)
+
         (
             (let (env, subst) = env.universal_substitution(&binder))
             (let p1 = binder.instantiate_with(&subst).unwrap())
             (prove_wc(decls, env, &assumptions, p1) => c)
+            (c.pop_subst(&subst) => c)
             --- ("forall")
-            (prove_wc(decls, env, assumptions, WcData::ForAll(binder)) => c.pop_subst(&subst))
+            (prove_wc(decls, env, assumptions, WcData::ForAll(binder)) => c)
         )
 
         (
@@ -57,12 +70,31 @@ judgment_fn! {
             (let (env, subst) = env.existential_substitution(&i.binder))
             (let i = i.binder.instantiate_with(&subst).unwrap())
             (let t = decls.trait_decl(&i.trait_ref.trait_id).binder.instantiate_with(&i.trait_ref.parameters).unwrap())
-            (let co_assumptions = (&assumptions, &trait_ref))
-            (prove(&decls, env, co_assumptions, Wcs::all_eq(&trait_ref.parameters, &i.trait_ref.parameters)) => c)
-            (prove_after(&decls, c, co_assumptions, &i.where_clause) => c)
+            (prove(&decls, env, &assumptions, Wcs::all_eq(&trait_ref.parameters, &i.trait_ref.parameters)) => c)
+            (prove_after(&decls, c, &assumptions, &i.where_clause) => c)
             (prove_after(&decls, c, &assumptions, &t.where_clause) => c)
+            (c.pop_subst(&subst) => c)
             ----------------------------- ("positive impl")
-            (prove_wc(decls, env, assumptions, Predicate::IsImplemented(trait_ref)) => c.pop_subst(&subst))
+            (prove_wc(decls, env, assumptions, Predicate::IsImplemented(trait_ref)) => c)
+        )
+
+        (
+            (if let Some(where_clause) = builtin_impl(&decls, &trait_ref))!
+            (prove(&decls, env, &assumptions, where_clause) => c)
+            ----------------------------- ("built-in impl")
+            (prove_wc(decls, env, assumptions, Predicate::IsImplemented(trait_ref)) => c)
+        )
+
+        (
+            // A rigid type implements an auto trait if its components do, unless
+            // there is an explicit impl (positive or negative) for it.
+            (if decls.is_auto_trait(&trait_ref.trait_id))!
+            (if let Some(RigidTy { name, parameters }) = trait_ref.parameters[0].downcast())
+            (if !decls.has_explicit_impl_for(&trait_ref.trait_id, &name))
+            (if let Some(components) = decls.auto_trait_components(&name, &parameters))
+            (prove(&decls, env, &assumptions, auto_trait_goals(&trait_ref, components)) => c)
+            ----------------------------- ("auto trait")
+            (prove_wc(decls, env, assumptions, Predicate::IsImplemented(trait_ref)) => c)
         )
 
         (
@@ -78,8 +110,9 @@ judgment_fn! {
             (let i = i.binder.instantiate_with(&subst).unwrap())
             (prove(&decls, env, &assumptions, Wcs::all_eq(&trait_ref.parameters, &i.trait_ref.parameters)) => c)
             (prove_after(&decls, c, &assumptions, &i.where_clause) => c)
+            (c.pop_subst(&subst) => c)
             ----------------------------- ("negative impl")
-            (prove_wc(decls, env, assumptions, Predicate::NotImplemented(trait_ref)) => c.pop_subst(&subst))
+            (prove_wc(decls, env, assumptions, Predicate::NotImplemented(trait_ref)) => c)
         )
 
         (
@@ -94,8 +127,34 @@ judgment_fn! {
             (let ti = ti.binder.instantiate_with(&subst).unwrap())
             (prove_via(&decls, env, &assumptions, &ti.where_clause, &trait_ref) => c)
             (prove_after(&decls, c, &assumptions, &ti.trait_ref) => c)
+            (c.pop_subst(&subst) => c)
             ----------------------------- ("trait implied bound")
-            (prove_wc(decls, env, assumptions, Predicate::IsImplemented(trait_ref)) => c.pop_subst(&subst))
+            (prove_wc(decls, env, assumptions, Predicate::IsImplemented(trait_ref)) => c)
+        )
+
+        (
+            // The bounds declared on an associated type hold for the alias even when
+            // it cannot be normalized, e.g., `<T as Iterator>::Item` for a universal `T`.
+            (if let Some(AliasTy { name, parameters: _ }) = trait_ref.parameters[0].downcast())!
+            (decls.alias_bound_decls(&name) => abd)
+            (let (env, subst) = env.existential_substitution(&abd.binder))
+            (let abd = abd.binder.instantiate_with(&subst).unwrap())
+            (let ensures = abd.ensures.instantiate_with(&[&abd.alias]).unwrap())
+            (prove_via(&decls, env, &assumptions, ensures, &trait_ref) => c)
+            (prove_after(&decls, c, &assumptions, &abd.where_clause) => c)
+            (c.pop_subst(&subst) => c)
+            ----------------------------- ("alias bound")
+            (prove_wc(decls, env, assumptions, Predicate::IsImplemented(trait_ref)) => c)
+        )
+
+        (
+            // An alias assumed to be well-formed implies whatever its well-formedness requires,
+            // e.g., `@wf(<T as Iterator>::Item)` implies `T: Iterator`.
+            (&assumptions => a)
+            (alias_wf_implied_bounds(&decls, &a) => b)!
+            (prove_via(&decls, &env, &assumptions, b, &trait_ref) => c)
+            ----------------------------- ("alias wf implied bound")
+            (prove_wc(decls, env, assumptions, Predicate::IsImplemented(trait_ref)) => c)
         )
 
         (
@@ -104,6 +163,18 @@ judgment_fn! {
             (prove_wc(decls, env, assumptions, Relation::Equals(a, b)) => c)
         )
 
+        (
+            (prove_sub(decls, env, assumptions, a, b) => c)
+            ----------------------------- ("subtype")
+            (prove_wc(decls, env, assumptions, Relation::Sub(a, b)) => c)
+        )
+
+        (
+            (prove_outlives(decls, env, assumptions, a, b) => c)
+            ----------------------------- ("outlives")
+            (prove_wc(decls, env, assumptions, Relation::Outlives(a, b)) => c)
+        )
+
         (
             (let t = decls.trait_decl(&trait_ref.trait_id))
             (let t = t.binder.instantiate_with(&trait_ref.parameters).unwrap())
@@ -131,5 +202,34 @@ judgment_fn! {
             ----------------------------- ("const has ty")
             (prove_wc(decls, env, assumptions, Predicate::ConstHasType(ct, ty)) => c)
         )
+
+        (
+            (prove_dyn_compatible(decls, env, assumptions, trait_id) => c)
+            ----------------------------- ("dyn compatible")
+            (prove_wc(decls, env, assumptions, Predicate::DynCompatible(trait_id)) => c)
+        )
+    }
+}
+
+/// Goals requiring each of `components` to implement the auto trait of `trait_ref`.
+fn auto_trait_goals(trait_ref: &TraitRef, components: Vec<Ty>) -> Wcs {
+    components
+        .into_iter()
+        .map(|ty| trait_ref.trait_id.with(ty, Vec::<Parameter>::new()))
+        .upcasted()
+        .collect()
+}
+
+/// The where-clauses implied by `assumption` if it assumes that an alias is well-formed:
+/// the trait-ref of the alias along with the where-clauses of the associated type.
+fn alias_wf_implied_bounds(decls: &Decls, assumption: &Wc) -> Wcs {
+    match assumption.data() {
+        WcData::Relation(Relation::WellFormed(parameter)) => {
+            match parameter.downcast::<AliasTy>() {
+                Some(alias) => (alias.trait_ref(), decls.alias_wf_where_clauses(&alias)).upcast(),
+                None => Wcs::t(),
+            }
+        }
+        _ => Wcs::t(),
     }
 }
@@ -1,5 +1,5 @@
 use formality_core::{judgment_fn, Cons};
-use formality_types::grammar::Wcs;
+use formality_types::grammar::{Coinductive, Wcs};
 
 use crate::{
     decls::Decls,
@@ -19,6 +19,10 @@ judgment_fn! {
 
         assert(env.encloses((&assumptions, &goal)))
 
+        coinductive(_decls.is_coinductive(goal) == Coinductive::Yes => Constraints::none(env))
+
+        cached(true)
+
         (
             --- ("none")
             (prove_wc_list(_decls, env, _assumptions, ()) => Constraints::none(env))
@@ -1,11 +1,12 @@
-use formality_core::{judgment_fn, ProvenSet};
+use formality_core::{judgment_fn, Upcast};
 use formality_types::grammar::{
-    AliasName, AliasTy, ConstData, Parameter, Parameters, RigidName, RigidTy, UniversalVar, Wcs,
+    AliasName, AliasTy, AssociatedTyName, ConstData, DynName, Lt, Parameter, Predicate, RigidName,
+    RigidTy, UniversalVar, Wcs,
 };
 
 use crate::{
     decls::Decls,
-    prove::{combinators::for_all, prove_after::prove_after},
+    prove::{combinators::for_all, prove_after::prove_after, prove_outlives::outlives},
 };
 
 use super::{constraints::Constraints, env::Env};
@@ -30,6 +31,21 @@ judgment_fn! {
             (prove_wf(_decls, env, _assumptions, UniversalVar { .. }) => Constraints::none(env))
         )
 
+        (
+            // All lifetimes are WF.
+            --- ("lifetimes")
+            (prove_wf(_decls, env, _assumptions, _lt: Lt) => Constraints::none(env))
+        )
+
+        (
+            // `&'a T` also requires `T: 'a`.
+            (for_all(&decls, &env, &assumptions, &parameters, &prove_wf) => c)
+            (if let [lt, ty] = &parameters[..])
+            (prove_after(&decls, c, &assumptions, outlives(ty, lt)) => c)
+            --- ("references")
+            (prove_wf(decls, env, assumptions, RigidTy { name: RigidName::Ref(_), parameters }) => c)
+        )
+
         (
             (for_all(&decls, &env, &assumptions, &parameters, &prove_wf) => c)
             --- ("tuples")
@@ -42,6 +58,25 @@ judgment_fn! {
             (prove_wf(decls, env, assumptions, RigidTy { name: RigidName::ScalarId(_), parameters }) => c)
         )
 
+        (
+            (for_all(&decls, &env, &assumptions, &parameters, &prove_wf) => c)
+            --- ("fn pointers")
+            (prove_wf(decls, env, assumptions, RigidTy { name: RigidName::FnPtr(_), parameters }) => c)
+        )
+
+        (
+            // The where-clauses of the function are proven where it is referenced or called.
+            (for_all(&decls, &env, &assumptions, &parameters, &prove_wf) => c)
+            --- ("fn definitions")
+            (prove_wf(decls, env, assumptions, RigidTy { name: RigidName::FnDef(_), parameters }) => c)
+        )
+
+        (
+            (for_all(&decls, &env, &assumptions, &parameters, &prove_wf) => c)
+            --- ("closures")
+            (prove_wf(decls, env, assumptions, RigidTy { name: RigidName::Closure(..), parameters }) => c)
+        )
+
         (
             (for_all(&decls, &env, &assumptions, &parameters, &prove_wf) => c)
             (let t = decls.adt_decl(&adt_id))
@@ -51,6 +86,19 @@ judgment_fn! {
             (prove_wf(decls, env, assumptions, RigidTy { name: RigidName::AdtId(adt_id), parameters }) => c)
         )
 
+        (
+            // The principal trait must be dyn-compatible, the auto traits must be auto traits,
+            // and the value of each associated type of the principal trait and its supertraits
+            // must be given and meet the bounds declared on the associated type.
+            (for_all(&decls, &env, &assumptions, &parameters, &prove_wf) => c)
+            (if dyn_name.auto_traits.iter().all(|t| decls.is_auto_trait(t)))
+            (if names_associated_tys(&decls, &dyn_name, &parameters))
+            (prove_after(&decls, c, &assumptions, Predicate::DynCompatible(dyn_name.trait_id.clone())) => c)
+            (prove_after(&decls, c, &assumptions, projection_bounds(&decls, &dyn_name, &parameters)) => c)
+            --- ("trait objects")
+            (prove_wf(decls, env, assumptions, RigidTy { name: RigidName::DynTrait(dyn_name), parameters }) => c)
+        )
+
         (
             (prove_wf(&decls, &env, &assumptions, ty) => c)
             --- ("rigid constants")
@@ -58,20 +106,57 @@ judgment_fn! {
         )
 
         (
-            (prove_alias_wf(&decls, &env, &assumptions, name, parameters) => c)
+            (for_all(&decls, &env, &assumptions, &alias.parameters, &prove_wf) => c)
+            (prove_after(&decls, c, &assumptions, alias.trait_ref()) => c)
+            (prove_after(&decls, c, &assumptions, decls.alias_wf_where_clauses(&alias)) => c)
             --- ("aliases")
-            (prove_wf(decls, env, assumptions, AliasTy { name, parameters }) => c)
+            (prove_wf(decls, env, assumptions, alias: AliasTy) => c)
         )
     }
 }
 
-pub fn prove_alias_wf(
-    decls: &Decls,
-    env: &Env,
-    assumptions: &Wcs,
-    _name: AliasName,
-    parameters: Parameters,
-) -> ProvenSet<Constraints> {
-    // FIXME: verify self type implements trait
-    for_all(decls, env, assumptions, &parameters, &prove_wf)
+/// True if the projections of the trait object type `dyn_name<parameters>` are exactly
+/// the associated types of its principal trait and supertraits.
+fn names_associated_tys(decls: &Decls, dyn_name: &DynName, parameters: &[Parameter]) -> bool {
+    let ty = RigidTy {
+        name: dyn_name.clone().upcast(),
+        parameters: parameters.to_vec(),
+    };
+    let mut item_ids: Vec<_> = decls
+        .associated_ty_aliases(&dyn_name.principal_trait_ref(&ty))
+        .into_iter()
+        .map(|alias| {
+            let AliasName::AssociatedTyId(AssociatedTyName { item_id, .. }) = alias.name;
+            item_id
+        })
+        .collect();
+    let mut projections = dyn_name.projections.clone();
+    item_ids.sort();
+    projections.sort();
+    item_ids == projections
+}
+
+/// The bounds declared on the associated types named by the projections of the trait object
+/// type `dyn_name<parameters>`, applied to the values given for them, together with the
+/// where-clauses under which those bounds are declared. For example, `dyn Iterator<Item = X>`
+/// requires `X: Copy` if `Iterator` declares `type Item: Copy`.
+fn projection_bounds(decls: &Decls, dyn_name: &DynName, parameters: &[Parameter]) -> Wcs {
+    let ty = RigidTy {
+        name: dyn_name.clone().upcast(),
+        parameters: parameters.to_vec(),
+    };
+    let (_, values, _) = dyn_name.split_parameters(parameters);
+    let mut wcs = vec![];
+    for alias in decls.associated_ty_aliases(&dyn_name.principal_trait_ref(&ty)) {
+        let AliasName::AssociatedTyId(AssociatedTyName { item_id, .. }) = &alias.name;
+        let Some(index) = dyn_name.projections.iter().position(|p| p == item_id) else {
+            continue;
+        };
+        for abd in decls.alias_bound_decls(&alias.name) {
+            let abd = abd.binder.instantiate_with(&alias.parameters).unwrap();
+            wcs.extend(abd.where_clause);
+            wcs.push(abd.ensures.instantiate_with(&[&values[index]]).unwrap());
+        }
+    }
+    wcs.into_iter().collect()
 }
@@ -1,4 +1,8 @@
 mod adt_wf;
+mod alias_bounds;
+mod alias_wf;
+mod builtin_impls;
+mod dyn_trait;
 mod eq_assumptions;
 mod eq_partial_eq;
 mod exists_constraints;
@@ -7,4 +11,5 @@ mod is_local;
 mod magic_copy;
 mod occurs_check;
 mod simple_impl;
+mod subtyping;
 mod universes;
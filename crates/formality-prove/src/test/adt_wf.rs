@@ -35,6 +35,7 @@ fn well_formed_adt() {
                 },
                 known_true: true,
                 substitution: {},
+                outlives: {},
             },
         }
     "#]]
@@ -51,7 +52,7 @@ fn not_well_formed_adt() {
         assumptions,
         Relation::WellFormed(goal),
     ).assert_err(expect![[r#"
-        judgment `prove { goal: {@ wf(X<u64>)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl Foo(u32)], [], [], [], [adt X <ty> where {Foo(^ty0_0)}], {}, {}) }` failed at the following rule(s):
+        judgment `prove { goal: {@ wf(X<u64>)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl Foo(u32)], [], [], [], [], [adt X <ty> where {Foo(^ty0_0)}], [], [], {}, {}) }` failed at the following rule(s):
           failed at (src/file.rs:LL:CC) because
             judgment `prove_wc_list { goal: {@ wf(X<u64>)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
               the rule "some" failed at step #0 (src/file.rs:LL:CC) because
@@ -59,13 +60,27 @@ fn not_well_formed_adt() {
                   the rule "parameter well formed" failed at step #0 (src/file.rs:LL:CC) because
                     judgment `prove_wf { goal: X<u64>, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
                       the rule "ADT" failed at step #3 (src/file.rs:LL:CC) because
-                        judgment `prove_after { constraints: Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {} }, goal: {Foo(u64)}, assumptions: {} }` failed at the following rule(s):
+                        judgment `prove_after { constraints: Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {Foo(u64)}, assumptions: {} }` failed at the following rule(s):
                           the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
-                            judgment `prove { goal: {Foo(u64)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl Foo(u32)], [], [], [], [adt X <ty> where {Foo(^ty0_0)}], {}, {}) }` failed at the following rule(s):
+                            judgment `prove { goal: {Foo(u64)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl Foo(u32)], [], [], [], [], [adt X <ty> where {Foo(^ty0_0)}], [], [], {}, {}) }` failed at the following rule(s):
                               failed at (src/file.rs:LL:CC) because
                                 judgment `prove_wc_list { goal: {Foo(u64)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
                                   the rule "some" failed at step #0 (src/file.rs:LL:CC) because
                                     judgment `prove_wc { goal: Foo(u64), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "positive impl" failed at step #4 (src/file.rs:LL:CC) because
+                                        judgment `prove { goal: {u64 = u32}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl Foo(u32)], [], [], [], [], [adt X <ty> where {Foo(^ty0_0)}], [], [], {}, {}) }` failed at the following rule(s):
+                                          failed at (src/file.rs:LL:CC) because
+                                            judgment `prove_wc_list { goal: {u64 = u32}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                                judgment `prove_wc { goal: u64 = u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                  the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
+                                                    judgment `prove_eq { a: u64, b: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                      the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                        judgment had no applicable rules: `prove_normalize { p: u64, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                                                      the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                                        judgment `prove_eq { a: u32, b: u64, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                          the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                            judgment had no applicable rules: `prove_normalize { p: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
                                       the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
                                         expression evaluated to an empty collection: `decls.trait_invariants()`"#]]);
 }
@@ -0,0 +1,112 @@
+use expect_test::expect;
+use formality_macros::test;
+use formality_types::rust::term;
+
+use crate::decls::Decls;
+
+use crate::test_util::test_prove;
+
+/// `trait Iterator { type Item: Copy where Self: Sized; }`
+fn decls() -> Decls {
+    Decls {
+        trait_decls: vec![
+            term("trait Copy<ty Self> where {}"),
+            term("trait Sized<ty Self> where {}"),
+            term("trait Iterator<ty Self> where {}"),
+        ],
+        alias_bound_decls: vec![term(
+            "alias <ty T> <T as Iterator>::Item : <ty I> Copy(I) where {Sized(T)}",
+        )],
+        ..Decls::empty()
+    }
+}
+
+/// The bound holds for the alias even though it cannot be normalized.
+#[test]
+fn rigid_alias_has_bound() {
+    test_prove(
+        decls(),
+        term("{} => {for<ty T> if {Sized(T)} Copy(<T as Iterator>::Item)}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+/// The where-clauses of the alias bound must hold.
+#[test]
+fn alias_bound_where_clause_not_satisfied() {
+    test_prove(
+        decls(),
+        term("{} => {for<ty T> Copy(<T as Iterator>::Item)}"),
+    )
+    .assert_err(expect![[r#"
+        judgment `prove { goal: {for <ty> Copy(<^ty0_0 as Iterator>::Item)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Sized <ty> , trait Iterator <ty> ], [], [], [], [alias <ty> <^ty0_0 as Iterator>::Item : <ty> Copy(^ty0_0) where {Sized(^ty0_0)}], [], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {for <ty> Copy(<^ty0_0 as Iterator>::Item)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: for <ty> Copy(<^ty0_0 as Iterator>::Item), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
+                    judgment `prove_wc { goal: Copy(<!ty_1 as Iterator>::Item), assumptions: {}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
+                      the rule "alias bound" failed at step #6 (src/file.rs:LL:CC) because
+                        judgment `prove_after { constraints: Constraints { env: Env { variables: [!ty_1, ?ty_2], bias: Soundness }, known_true: true, substitution: {?ty_2 => !ty_1}, outlives: {} }, goal: {Sized(?ty_2)}, assumptions: {} }` failed at the following rule(s):
+                          the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                            judgment `prove { goal: {Sized(!ty_0)}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Sized <ty> , trait Iterator <ty> ], [], [], [], [alias <ty> <^ty0_0 as Iterator>::Item : <ty> Copy(^ty0_0) where {Sized(^ty0_0)}], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                              failed at (src/file.rs:LL:CC) because
+                                judgment `prove_wc_list { goal: {Sized(!ty_0)}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                    judgment `prove_wc { goal: Sized(!ty_0), assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                                        expression evaluated to an empty collection: `decls.trait_invariants()`
+                      the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                        expression evaluated to an empty collection: `decls.trait_invariants()`"#]]);
+}
+
+/// Only the declared bounds hold.
+#[test]
+fn alias_bound_other_trait() {
+    test_prove(
+        decls(),
+        term("{} => {for<ty T> if {Sized(T)} Sized(<T as Iterator>::Item)}"),
+    )
+    .assert_err(expect![[r#"
+        judgment `prove { goal: {for <ty> if {Sized(^ty0_0)} Sized(<^ty0_0 as Iterator>::Item)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Sized <ty> , trait Iterator <ty> ], [], [], [], [alias <ty> <^ty0_0 as Iterator>::Item : <ty> Copy(^ty0_0) where {Sized(^ty0_0)}], [], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {for <ty> if {Sized(^ty0_0)} Sized(<^ty0_0 as Iterator>::Item)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: for <ty> if {Sized(^ty0_0)} Sized(<^ty0_0 as Iterator>::Item), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
+                    judgment `prove_wc { goal: if {Sized(!ty_1)} Sized(<!ty_1 as Iterator>::Item), assumptions: {}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
+                      the rule "implies" failed at step #0 (src/file.rs:LL:CC) because
+                        judgment `prove_wc { goal: Sized(<!ty_1 as Iterator>::Item), assumptions: {Sized(!ty_1)}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
+                          the rule "alias bound" failed at step #5 (src/file.rs:LL:CC) because
+                            judgment had no applicable rules: `prove_via { goal: Sized(<!ty_1 as Iterator>::Item), via: Copy(<?ty_2 as Iterator>::Item), assumptions: {Sized(!ty_1)}, env: Env { variables: [!ty_1, ?ty_2], bias: Soundness } }`
+                          the rule "assumption - predicate" failed at step #1 (src/file.rs:LL:CC) because
+                            judgment `prove_via { goal: Sized(<!ty_1 as Iterator>::Item), via: Sized(!ty_1), assumptions: {Sized(!ty_1)}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
+                              the rule "predicate-congruence-axiom" failed at step #3 (src/file.rs:LL:CC) because
+                                judgment `prove { goal: {!ty_0 = <!ty_0 as Iterator>::Item}, assumptions: {Sized(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Sized <ty> , trait Iterator <ty> ], [], [], [], [alias <ty> <^ty0_0 as Iterator>::Item : <ty> Copy(^ty0_0) where {Sized(^ty0_0)}], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                  failed at (src/file.rs:LL:CC) because
+                                    judgment `prove_wc_list { goal: {!ty_0 = <!ty_0 as Iterator>::Item}, assumptions: {Sized(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                        judgment `prove_wc { goal: !ty_0 = <!ty_0 as Iterator>::Item, assumptions: {Sized(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                          the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                                            judgment had no applicable rules: `prove_via { goal: !ty_0 = <!ty_0 as Iterator>::Item, via: Sized(!ty_0), assumptions: {Sized(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }`
+                                          the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
+                                            judgment `prove_eq { a: !ty_0, b: <!ty_0 as Iterator>::Item, assumptions: {Sized(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                              the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                judgment `prove_normalize { p: !ty_0, assumptions: {Sized(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                  the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                                    judgment had no applicable rules: `prove_normalize_via { goal: !ty_0, via: Sized(!ty_0), assumptions: {Sized(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }`
+                                              the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                                judgment `prove_eq { a: <!ty_0 as Iterator>::Item, b: !ty_0, assumptions: {Sized(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                  the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                    judgment `prove_normalize { p: <!ty_0 as Iterator>::Item, assumptions: {Sized(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                      the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                                        judgment had no applicable rules: `prove_normalize_via { goal: <!ty_0 as Iterator>::Item, via: Sized(!ty_0), assumptions: {Sized(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }`
+                                                      the rule "normalize-via-impl" failed at step #0 (src/file.rs:LL:CC) because
+                                                        expression evaluated to an empty collection: `alias_eq_decls(&decls, &a)`
+                          the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                            expression evaluated to an empty collection: `decls.trait_invariants()`"#]]);
+}
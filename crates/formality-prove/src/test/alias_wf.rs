@@ -0,0 +1,134 @@
+use expect_test::expect;
+use formality_macros::test;
+use formality_types::rust::term;
+
+use crate::decls::Decls;
+
+use crate::test_util::test_prove;
+
+/// `trait Family { type Member<U> where U: Copy; }` with `impl Family for u32`.
+fn decls() -> Decls {
+    Decls {
+        trait_decls: vec![
+            term("trait Copy<ty Self> where {}"),
+            term("trait Family<ty Self> where {}"),
+        ],
+        impl_decls: vec![term("impl Family(u32) where {}")],
+        alias_wf_decls: vec![term(
+            "alias <ty T, ty U> wf <T as Family>::Member<U> where {Copy(U)}",
+        )],
+        ..Decls::empty()
+    }
+}
+
+/// An alias is only well-formed if its trait-ref is implemented.
+#[test]
+fn alias_wf_requires_trait_ref() {
+    test_prove(
+        decls(),
+        term("{} => {for<ty U> if {Copy(U)} @wf(<u64 as Family>::Member<U>)}"),
+    )
+    .assert_err(expect![[r#"
+        judgment `prove { goal: {for <ty> if {Copy(^ty0_0)} @ wf(<u64 as Family>::Member<^ty0_0>)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Family <ty> ], [impl Family(u32)], [], [], [], [alias <ty, ty> wf <^ty0_0 as Family>::Member<^ty0_1> where {Copy(^ty0_1)}], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {for <ty> if {Copy(^ty0_0)} @ wf(<u64 as Family>::Member<^ty0_0>)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: for <ty> if {Copy(^ty0_0)} @ wf(<u64 as Family>::Member<^ty0_0>), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
+                    judgment `prove_wc { goal: if {Copy(!ty_1)} @ wf(<u64 as Family>::Member<!ty_1>), assumptions: {}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
+                      the rule "implies" failed at step #0 (src/file.rs:LL:CC) because
+                        judgment `prove_wc { goal: @ wf(<u64 as Family>::Member<!ty_1>), assumptions: {Copy(!ty_1)}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
+                          the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                            judgment had no applicable rules: `prove_via { goal: @ wf(<u64 as Family>::Member<!ty_1>), via: Copy(!ty_1), assumptions: {Copy(!ty_1)}, env: Env { variables: [!ty_1], bias: Soundness } }`
+                          the rule "parameter well formed" failed at step #0 (src/file.rs:LL:CC) because
+                            judgment `prove_wf { goal: <u64 as Family>::Member<!ty_1>, assumptions: {Copy(!ty_1)}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
+                              the rule "aliases" failed at step #1 (src/file.rs:LL:CC) because
+                                judgment `prove_after { constraints: Constraints { env: Env { variables: [!ty_1], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {Family(u64)}, assumptions: {Copy(!ty_1)} }` failed at the following rule(s):
+                                  the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                                    judgment `prove { goal: {Family(u64)}, assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Family <ty> ], [impl Family(u32)], [], [], [], [alias <ty, ty> wf <^ty0_0 as Family>::Member<^ty0_1> where {Copy(^ty0_1)}], [], [], [], {}, {}) }` failed at the following rule(s):
+                                      failed at (src/file.rs:LL:CC) because
+                                        judgment `prove_wc_list { goal: {Family(u64)}, assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                          the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                            judgment `prove_wc { goal: Family(u64), assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                              the rule "assumption - predicate" failed at step #1 (src/file.rs:LL:CC) because
+                                                judgment had no applicable rules: `prove_via { goal: Family(u64), via: Copy(!ty_0), assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }`
+                                              the rule "positive impl" failed at step #4 (src/file.rs:LL:CC) because
+                                                judgment `prove { goal: {u64 = u32}, assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Family <ty> ], [impl Family(u32)], [], [], [], [alias <ty, ty> wf <^ty0_0 as Family>::Member<^ty0_1> where {Copy(^ty0_1)}], [], [], [], {}, {}) }` failed at the following rule(s):
+                                                  failed at (src/file.rs:LL:CC) because
+                                                    judgment `prove_wc_list { goal: {u64 = u32}, assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                                        judgment `prove_wc { goal: u64 = u32, assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                          the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                                                            judgment had no applicable rules: `prove_via { goal: u64 = u32, via: Copy(!ty_0), assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }`
+                                                          the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
+                                                            judgment `prove_eq { a: u64, b: u32, assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                              the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                                judgment `prove_normalize { p: u64, assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                                  the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                                                    judgment had no applicable rules: `prove_normalize_via { goal: u64, via: Copy(!ty_0), assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }`
+                                                              the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                                                judgment `prove_eq { a: u32, b: u64, assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                                  the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                                    judgment `prove_normalize { p: u32, assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                                      the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                                                        judgment had no applicable rules: `prove_normalize_via { goal: u32, via: Copy(!ty_0), assumptions: {Copy(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }`
+                                              the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                                                expression evaluated to an empty collection: `decls.trait_invariants()`"#]]);
+}
+
+/// The where-clauses of the associated type must hold.
+#[test]
+fn alias_wf_requires_where_clauses() {
+    test_prove(
+        decls(),
+        term("{} => {for<ty U> @wf(<u32 as Family>::Member<U>)}"),
+    )
+    .assert_err(expect![[r#"
+        judgment `prove { goal: {for <ty> @ wf(<u32 as Family>::Member<^ty0_0>)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Family <ty> ], [impl Family(u32)], [], [], [], [alias <ty, ty> wf <^ty0_0 as Family>::Member<^ty0_1> where {Copy(^ty0_1)}], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {for <ty> @ wf(<u32 as Family>::Member<^ty0_0>)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: for <ty> @ wf(<u32 as Family>::Member<^ty0_0>), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
+                    judgment `prove_wc { goal: @ wf(<u32 as Family>::Member<!ty_1>), assumptions: {}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
+                      the rule "parameter well formed" failed at step #0 (src/file.rs:LL:CC) because
+                        judgment `prove_wf { goal: <u32 as Family>::Member<!ty_1>, assumptions: {}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
+                          the rule "aliases" failed at step #2 (src/file.rs:LL:CC) because
+                            judgment `prove_after { constraints: Constraints { env: Env { variables: [!ty_1], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {Copy(!ty_1)}, assumptions: {} }` failed at the following rule(s):
+                              the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                                judgment `prove { goal: {Copy(!ty_0)}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Family <ty> ], [impl Family(u32)], [], [], [], [alias <ty, ty> wf <^ty0_0 as Family>::Member<^ty0_1> where {Copy(^ty0_1)}], [], [], [], {}, {}) }` failed at the following rule(s):
+                                  failed at (src/file.rs:LL:CC) because
+                                    judgment `prove_wc_list { goal: {Copy(!ty_0)}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                        judgment `prove_wc { goal: Copy(!ty_0), assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                                          the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                                            expression evaluated to an empty collection: `decls.trait_invariants()`"#]]);
+}
+
+#[test]
+fn alias_wf() {
+    test_prove(
+        decls(),
+        term("{} => {for<ty U> if {Copy(U)} @wf(<u32 as Family>::Member<U>)}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+/// Assuming that an alias is well-formed implies that its trait-ref
+/// and the where-clauses of the associated type hold.
+#[test]
+fn alias_wf_implied_bounds() {
+    test_prove(
+        decls(),
+        term("{} => {for<ty T, ty U> if {@wf(<T as Family>::Member<U>)} Family(T), for<ty T, ty U> if {@wf(<T as Family>::Member<U>)} Copy(U)}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
@@ -0,0 +1,177 @@
+use expect_test::expect;
+use formality_core::Upcast;
+use formality_macros::test;
+use formality_types::grammar::{FnId, Parameter, RigidName, TraitId, Ty, Wc};
+use formality_types::rust::term;
+
+use crate::{decls::Decls, prove::prove};
+
+/// Decls with the `Fn` family of lang items and a function `foo<T>(T) -> u32 where T: Debug`.
+fn decls() -> Decls {
+    Decls {
+        trait_decls: vec![
+            term("#[lang(fn_once)] trait FnOnce<ty Self, ty Args> where {}"),
+            term("#[lang(fn_mut)] trait FnMut<ty Self, ty Args> where {}"),
+            term("#[lang(fn)] trait Fn<ty Self, ty Args> where {}"),
+            term("trait Debug<ty Self> where {}"),
+        ],
+        impl_decls: vec![term("impl Debug(u32) where {}")],
+        fn_decls: vec![term("fn foo<ty T> (T) -> u32 where {Debug(T)}")],
+        ..Decls::empty()
+    }
+}
+
+/// The goal `foo::<arg>: trait_id<(arg,)>`; fn definitions have no surface syntax.
+fn fn_def_goal(trait_id: &str, arg: &str) -> Wc {
+    let arg: Parameter = term::<Ty>(arg).upcast();
+    let fn_def = Ty::rigid(RigidName::FnDef(FnId::new("foo")), vec![arg.clone()]);
+    let args: Parameter = Ty::rigid(RigidName::Tuple(1), vec![arg]).upcast();
+    TraitId::new(trait_id).with(fn_def, vec![args]).upcast()
+}
+
+#[test]
+fn fn_ptr_fn_once() {
+    let goal: Wc = term("FnOnce(fn(u32) -> bool, (u32))");
+    prove(decls(), (), (), goal).assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+#[test]
+fn fn_ptr_fn_once_wrong_args() {
+    let goal: Wc = term("FnOnce(fn(u32) -> bool, (bool))");
+    prove(decls(), (), (), goal).assert_err(expect![[r#"
+        judgment `prove { goal: {FnOnce(fn(u32) -> bool, (bool))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(fn_once)] trait FnOnce <ty, ty> , #[lang(fn_mut)] trait FnMut <ty, ty> , #[lang(fn)] trait Fn <ty, ty> , trait Debug <ty> ], [impl Debug(u32)], [], [], [], [], [], [fn foo <ty> (^ty0_0) -> u32 where {Debug(^ty0_0)}], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {FnOnce(fn(u32) -> bool, (bool))}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: FnOnce(fn(u32) -> bool, (bool)), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "built-in impl" failed at step #1 (src/file.rs:LL:CC) because
+                    judgment `prove { goal: {(bool) = (u32)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(fn_once)] trait FnOnce <ty, ty> , #[lang(fn_mut)] trait FnMut <ty, ty> , #[lang(fn)] trait Fn <ty, ty> , trait Debug <ty> ], [impl Debug(u32)], [], [], [], [], [], [fn foo <ty> (^ty0_0) -> u32 where {Debug(^ty0_0)}], [], {}, {}) }` failed at the following rule(s):
+                      failed at (src/file.rs:LL:CC) because
+                        judgment `prove_wc_list { goal: {(bool) = (u32)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                          the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                            judgment `prove_wc { goal: (bool) = (u32), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                              the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment `prove_eq { a: (bool), b: (u32), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                    judgment had no applicable rules: `prove_normalize { p: (bool), assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                                  the rule "rigid" failed at step #3 (src/file.rs:LL:CC) because
+                                    judgment `prove { goal: {bool = u32}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(fn_once)] trait FnOnce <ty, ty> , #[lang(fn_mut)] trait FnMut <ty, ty> , #[lang(fn)] trait Fn <ty, ty> , trait Debug <ty> ], [impl Debug(u32)], [], [], [], [], [], [fn foo <ty> (^ty0_0) -> u32 where {Debug(^ty0_0)}], [], {}, {}) }` failed at the following rule(s):
+                                      failed at (src/file.rs:LL:CC) because
+                                        judgment `prove_wc_list { goal: {bool = u32}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                          the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                            judgment `prove_wc { goal: bool = u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                              the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
+                                                judgment `prove_eq { a: bool, b: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                  the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                    judgment had no applicable rules: `prove_normalize { p: bool, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                                                  the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                                    judgment `prove_eq { a: u32, b: bool, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                      the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                        judgment had no applicable rules: `prove_normalize { p: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                                  the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                    judgment `prove_eq { a: (u32), b: (bool), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                        judgment had no applicable rules: `prove_normalize { p: (u32), assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                                      the rule "rigid" failed at step #3 (src/file.rs:LL:CC) because
+                                        judgment `prove { goal: {u32 = bool}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(fn_once)] trait FnOnce <ty, ty> , #[lang(fn_mut)] trait FnMut <ty, ty> , #[lang(fn)] trait Fn <ty, ty> , trait Debug <ty> ], [impl Debug(u32)], [], [], [], [], [], [fn foo <ty> (^ty0_0) -> u32 where {Debug(^ty0_0)}], [], {}, {}) }` failed at the following rule(s):
+                                          failed at (src/file.rs:LL:CC) because
+                                            judgment `prove_wc_list { goal: {u32 = bool}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                                judgment `prove_wc { goal: u32 = bool, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                  the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
+                                                    judgment `prove_eq { a: u32, b: bool, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                      the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                        judgment had no applicable rules: `prove_normalize { p: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                                                      the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                                        judgment `prove_eq { a: bool, b: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                          the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                            judgment had no applicable rules: `prove_normalize { p: bool, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                  the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                    expression evaluated to an empty collection: `decls.trait_invariants()`"#]]);
+}
+
+#[test]
+fn fn_ptr_output() {
+    let goal: Wc = term("<fn(u32) -> bool as FnOnce<(u32)>>::Output = bool");
+    prove(decls(), (), (), goal).assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+#[test]
+fn fn_def_fn() {
+    prove(decls(), (), (), fn_def_goal("Fn", "u32")).assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+#[test]
+fn fn_def_where_clause_not_satisfied() {
+    prove(decls(), (), (), fn_def_goal("Fn", "bool")).assert_err(expect![[r#"
+        judgment `prove { goal: {Fn(fn_def(foo)<bool>, (bool))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(fn_once)] trait FnOnce <ty, ty> , #[lang(fn_mut)] trait FnMut <ty, ty> , #[lang(fn)] trait Fn <ty, ty> , trait Debug <ty> ], [impl Debug(u32)], [], [], [], [], [], [fn foo <ty> (^ty0_0) -> u32 where {Debug(^ty0_0)}], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {Fn(fn_def(foo)<bool>, (bool))}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: Fn(fn_def(foo)<bool>, (bool)), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "built-in impl" failed at step #1 (src/file.rs:LL:CC) because
+                    judgment `prove { goal: {(bool) = (bool), Debug(bool)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(fn_once)] trait FnOnce <ty, ty> , #[lang(fn_mut)] trait FnMut <ty, ty> , #[lang(fn)] trait Fn <ty, ty> , trait Debug <ty> ], [impl Debug(u32)], [], [], [], [], [], [fn foo <ty> (^ty0_0) -> u32 where {Debug(^ty0_0)}], [], {}, {}) }` failed at the following rule(s):
+                      failed at (src/file.rs:LL:CC) because
+                        judgment `prove_wc_list { goal: {(bool) = (bool), Debug(bool)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                          the rule "some" failed at step #1 (src/file.rs:LL:CC) because
+                            judgment `prove_after { constraints: Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {Debug(bool)}, assumptions: {} }` failed at the following rule(s):
+                              the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                                judgment `prove { goal: {Debug(bool)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(fn_once)] trait FnOnce <ty, ty> , #[lang(fn_mut)] trait FnMut <ty, ty> , #[lang(fn)] trait Fn <ty, ty> , trait Debug <ty> ], [impl Debug(u32)], [], [], [], [], [], [fn foo <ty> (^ty0_0) -> u32 where {Debug(^ty0_0)}], [], {}, {}) }` failed at the following rule(s):
+                                  failed at (src/file.rs:LL:CC) because
+                                    judgment `prove_wc_list { goal: {Debug(bool)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                        judgment `prove_wc { goal: Debug(bool), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                          the rule "positive impl" failed at step #4 (src/file.rs:LL:CC) because
+                                            judgment `prove { goal: {bool = u32}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(fn_once)] trait FnOnce <ty, ty> , #[lang(fn_mut)] trait FnMut <ty, ty> , #[lang(fn)] trait Fn <ty, ty> , trait Debug <ty> ], [impl Debug(u32)], [], [], [], [], [], [fn foo <ty> (^ty0_0) -> u32 where {Debug(^ty0_0)}], [], {}, {}) }` failed at the following rule(s):
+                                              failed at (src/file.rs:LL:CC) because
+                                                judgment `prove_wc_list { goal: {bool = u32}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                  the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                                    judgment `prove_wc { goal: bool = u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                      the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
+                                                        judgment `prove_eq { a: bool, b: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                          the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                            judgment had no applicable rules: `prove_normalize { p: bool, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                                                          the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                                            judgment `prove_eq { a: u32, b: bool, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                              the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                                judgment had no applicable rules: `prove_normalize { p: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                                          the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                                            expression evaluated to an empty collection: `decls.trait_invariants()`
+                  the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                    expression evaluated to an empty collection: `decls.trait_invariants()`"#]]);
+}
+
+#[test]
+fn fn_mut_closure_fn_mut() {
+    let goal: Wc = term("FnMut(closure C: fn_mut(u32) -> bool [u8], (u32))");
+    prove(decls(), (), (), goal).assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+#[test]
+fn fn_mut_closure_not_fn() {
+    let goal: Wc = term("Fn(closure C: fn_mut(u32) -> bool [u8], (u32))");
+    prove(decls(), (), (), goal).assert_err(expect![[r#"
+        judgment `prove { goal: {Fn(closure C: fn_mut(u32) -> bool [u8], (u32))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(fn_once)] trait FnOnce <ty, ty> , #[lang(fn_mut)] trait FnMut <ty, ty> , #[lang(fn)] trait Fn <ty, ty> , trait Debug <ty> ], [impl Debug(u32)], [], [], [], [], [], [fn foo <ty> (^ty0_0) -> u32 where {Debug(^ty0_0)}], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {Fn(closure C: fn_mut(u32) -> bool [u8], (u32))}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: Fn(closure C: fn_mut(u32) -> bool [u8], (u32)), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                    expression evaluated to an empty collection: `decls.trait_invariants()`"#]]);
+}
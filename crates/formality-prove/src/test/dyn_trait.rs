@@ -0,0 +1,318 @@
+use expect_test::expect;
+use formality_macros::test;
+use formality_types::rust::term;
+
+use crate::decls::Decls;
+
+use crate::test_util::test_prove;
+
+/// `trait Iterator: Super { type Item; }` along with an auto trait `Send`
+/// and a trait `NotDyn` that is not dyn-compatible, as it requires `Self: Sized`.
+fn decls() -> Decls {
+    Decls {
+        trait_decls: vec![
+            term("trait Super<ty Self> where {}"),
+            term("trait Iterator<ty Self> where {Super(Self)}"),
+            term("auto trait Send<ty Self> where {}"),
+            term("trait Other<ty Self> where {}"),
+            term("trait NotDyn<ty Self> where {Sized(Self)}"),
+            term("#[lang(sized)] trait Sized<ty Self> where {}"),
+            term("trait Convert<ty Self, ty T> where {}"),
+        ],
+        alias_wf_decls: vec![term("alias <ty T> wf <T as Iterator>::Item where {}")],
+        ..Decls::empty()
+    }
+}
+
+/// A trait object implements its principal trait, with the parameters given in the type.
+#[test]
+fn dyn_implements_principal_trait() {
+    test_prove(
+        decls(),
+        term("{} => {Convert(dyn Convert<u32> + static, u32)}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+#[test]
+fn dyn_does_not_implement_other_parameters() {
+    test_prove(
+        decls(),
+        term("{} => {Convert(dyn Convert<u32> + static, u64)}"),
+    )
+    .assert_err(expect![[r#"
+        judgment `prove { goal: {Convert(dyn Convert<u32> + static, u64)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Super <ty> , trait Iterator <ty> where {Super(^ty0_0)}, auto trait Send <ty> , trait Other <ty> , trait NotDyn <ty> where {Sized(^ty0_0)}, #[lang(sized)] trait Sized <ty> , trait Convert <ty, ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {Convert(dyn Convert<u32> + static, u64)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: Convert(dyn Convert<u32> + static, u64), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "built-in impl" failed at step #1 (src/file.rs:LL:CC) because
+                    judgment `prove { goal: {u64 = u32, @ DynCompatible(Convert)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Super <ty> , trait Iterator <ty> where {Super(^ty0_0)}, auto trait Send <ty> , trait Other <ty> , trait NotDyn <ty> where {Sized(^ty0_0)}, #[lang(sized)] trait Sized <ty> , trait Convert <ty, ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+                      failed at (src/file.rs:LL:CC) because
+                        judgment `prove_wc_list { goal: {u64 = u32, @ DynCompatible(Convert)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                          the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                            judgment `prove_wc { goal: u64 = u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                              the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment `prove_eq { a: u64, b: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                    judgment had no applicable rules: `prove_normalize { p: u64, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                                  the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                    judgment `prove_eq { a: u32, b: u64, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                        judgment had no applicable rules: `prove_normalize { p: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                  the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                    judgment had no applicable rules: `prove_via { goal: Convert(dyn Convert<u32> + static, u64), via: Sized(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`
+                  the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                    judgment had no applicable rules: `prove_via { goal: Convert(dyn Convert<u32> + static, u64), via: Super(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`"#]]);
+}
+
+/// Supertraits of the principal trait are implemented via the trait invariants.
+#[test]
+fn dyn_implements_supertraits() {
+    test_prove(
+        decls(),
+        term("{} => {Super(dyn Iterator<Item = u32> + static)}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+#[test]
+fn dyn_does_not_implement_unrelated_traits() {
+    test_prove(
+        decls(),
+        term("{} => {Other(dyn Iterator<Item = u32> + static)}"),
+    )
+    .assert_err(expect![[r#"
+        judgment `prove { goal: {Other(dyn Iterator<Item = u32> + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Super <ty> , trait Iterator <ty> where {Super(^ty0_0)}, auto trait Send <ty> , trait Other <ty> , trait NotDyn <ty> where {Sized(^ty0_0)}, #[lang(sized)] trait Sized <ty> , trait Convert <ty, ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {Other(dyn Iterator<Item = u32> + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: Other(dyn Iterator<Item = u32> + static), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                    judgment had no applicable rules: `prove_via { goal: Other(dyn Iterator<Item = u32> + static), via: Sized(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`
+                  the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                    judgment had no applicable rules: `prove_via { goal: Other(dyn Iterator<Item = u32> + static), via: Super(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`"#]]);
+}
+
+/// A trait object does not implement a principal trait that is not dyn-compatible.
+#[test]
+fn dyn_does_not_implement_dyn_incompatible_trait() {
+    test_prove(decls(), term("{} => {NotDyn(dyn NotDyn + static)}")).assert_err(expect![[r#"
+        judgment `prove { goal: {NotDyn(dyn NotDyn + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Super <ty> , trait Iterator <ty> where {Super(^ty0_0)}, auto trait Send <ty> , trait Other <ty> , trait NotDyn <ty> where {Sized(^ty0_0)}, #[lang(sized)] trait Sized <ty> , trait Convert <ty, ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {NotDyn(dyn NotDyn + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: NotDyn(dyn NotDyn + static), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "built-in impl" failed at step #1 (src/file.rs:LL:CC) because
+                    judgment `prove { goal: {@ DynCompatible(NotDyn)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Super <ty> , trait Iterator <ty> where {Super(^ty0_0)}, auto trait Send <ty> , trait Other <ty> , trait NotDyn <ty> where {Sized(^ty0_0)}, #[lang(sized)] trait Sized <ty> , trait Convert <ty, ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+                      failed at (src/file.rs:LL:CC) because
+                        judgment `prove_wc_list { goal: {@ DynCompatible(NotDyn)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                          the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                            judgment `prove_wc { goal: @ DynCompatible(NotDyn), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                              the rule "dyn compatible" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment `prove_dyn_compatible { trait_id: NotDyn, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "dyn compatible" failed at step #1 (src/file.rs:LL:CC) because
+                                    condition evaluted to false: `!requires_sized_self(&decls, &vars[0], &where_clause)`
+                  the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                    judgment had no applicable rules: `prove_via { goal: NotDyn(dyn NotDyn + static), via: Sized(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`
+                  the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                    judgment had no applicable rules: `prove_via { goal: NotDyn(dyn NotDyn + static), via: Super(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`"#]]);
+}
+
+/// A trait object implements only the auto traits it names.
+#[test]
+fn dyn_implements_named_auto_traits() {
+    test_prove(
+        decls(),
+        term("{} => {Send(dyn Other + Send + static)}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+#[test]
+fn dyn_does_not_implement_unnamed_auto_traits() {
+    test_prove(decls(), term("{} => {Send(dyn Other + static)}")).assert_err(expect![[r#"
+        judgment `prove { goal: {Send(dyn Other + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Super <ty> , trait Iterator <ty> where {Super(^ty0_0)}, auto trait Send <ty> , trait Other <ty> , trait NotDyn <ty> where {Sized(^ty0_0)}, #[lang(sized)] trait Sized <ty> , trait Convert <ty, ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {Send(dyn Other + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: Send(dyn Other + static), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "auto trait" failed at step #3 (src/file.rs:LL:CC) because
+                    pattern `Some(components)` did not match value `None`
+                  the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                    judgment had no applicable rules: `prove_via { goal: Send(dyn Other + static), via: Sized(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`
+                  the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                    judgment had no applicable rules: `prove_via { goal: Send(dyn Other + static), via: Super(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`"#]]);
+}
+
+/// The associated types of a trait object normalize to the values given in the type.
+#[test]
+fn dyn_projection_normalizes() {
+    test_prove(
+        decls(),
+        term("{} => {<dyn Iterator<Item = u32> + static as Iterator>::Item = u32}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+#[test]
+fn dyn_wf() {
+    test_prove(
+        decls(),
+        term("{} => {for<lt a> @wf(dyn Iterator<Item = u32> + Send + a)}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+/// The principal trait of a trait object must be dyn-compatible.
+#[test]
+fn dyn_wf_requires_dyn_compatible() {
+    test_prove(decls(), term("{} => {@wf(dyn NotDyn + static)}")).assert_err(expect![[r#"
+        judgment `prove { goal: {@ wf(dyn NotDyn + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Super <ty> , trait Iterator <ty> where {Super(^ty0_0)}, auto trait Send <ty> , trait Other <ty> , trait NotDyn <ty> where {Sized(^ty0_0)}, #[lang(sized)] trait Sized <ty> , trait Convert <ty, ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {@ wf(dyn NotDyn + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: @ wf(dyn NotDyn + static), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "parameter well formed" failed at step #0 (src/file.rs:LL:CC) because
+                    judgment `prove_wf { goal: dyn NotDyn + static, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                      the rule "trait objects" failed at step #3 (src/file.rs:LL:CC) because
+                        judgment `prove_after { constraints: Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {@ DynCompatible(NotDyn)}, assumptions: {} }` failed at the following rule(s):
+                          the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                            judgment `prove { goal: {@ DynCompatible(NotDyn)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Super <ty> , trait Iterator <ty> where {Super(^ty0_0)}, auto trait Send <ty> , trait Other <ty> , trait NotDyn <ty> where {Sized(^ty0_0)}, #[lang(sized)] trait Sized <ty> , trait Convert <ty, ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+                              failed at (src/file.rs:LL:CC) because
+                                judgment `prove_wc_list { goal: {@ DynCompatible(NotDyn)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                    judgment `prove_wc { goal: @ DynCompatible(NotDyn), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "dyn compatible" failed at step #0 (src/file.rs:LL:CC) because
+                                        judgment `prove_dyn_compatible { trait_id: NotDyn, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                          the rule "dyn compatible" failed at step #1 (src/file.rs:LL:CC) because
+                                            condition evaluted to false: `!requires_sized_self(&decls, &vars[0], &where_clause)`"#]]);
+}
+
+/// The value of each associated type must be given.
+#[test]
+fn dyn_wf_requires_projections() {
+    test_prove(decls(), term("{} => {@wf(dyn Iterator + static)}")).assert_err(expect![[r#"
+        judgment `prove { goal: {@ wf(dyn Iterator + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Super <ty> , trait Iterator <ty> where {Super(^ty0_0)}, auto trait Send <ty> , trait Other <ty> , trait NotDyn <ty> where {Sized(^ty0_0)}, #[lang(sized)] trait Sized <ty> , trait Convert <ty, ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {@ wf(dyn Iterator + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: @ wf(dyn Iterator + static), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "parameter well formed" failed at step #0 (src/file.rs:LL:CC) because
+                    judgment `prove_wf { goal: dyn Iterator + static, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                      the rule "trait objects" failed at step #2 (src/file.rs:LL:CC) because
+                        condition evaluted to false: `names_associated_tys(&decls, &dyn_name, &parameters)`"#]]);
+}
+
+/// The values of the associated types must meet the bounds declared on them.
+#[test]
+fn dyn_wf_requires_projection_bounds() {
+    let decls = Decls {
+        trait_decls: vec![
+            term("trait Copy<ty Self> where {}"),
+            term("trait Iterator<ty Self> where {}"),
+        ],
+        impl_decls: vec![term("impl Copy(u32) where {}")],
+        alias_wf_decls: vec![term("alias <ty T> wf <T as Iterator>::Item where {}")],
+        alias_bound_decls: vec![term(
+            "alias <ty T> <T as Iterator>::Item : <ty I> Copy(I) where {Iterator(T)}",
+        )],
+        ..Decls::empty()
+    };
+
+    test_prove(
+        decls.clone(),
+        term("{} => {@wf(dyn Iterator<Item = u32> + static)}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+
+    test_prove(
+        decls,
+        term("{} => {@wf(dyn Iterator<Item = i32> + static)}"),
+    )
+    .assert_err(expect![[r#"
+        judgment `prove { goal: {@ wf(dyn Iterator<Item = i32> + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Iterator <ty> ], [impl Copy(u32)], [], [], [alias <ty> <^ty0_0 as Iterator>::Item : <ty> Copy(^ty0_0) where {Iterator(^ty0_0)}], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {@ wf(dyn Iterator<Item = i32> + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: @ wf(dyn Iterator<Item = i32> + static), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "parameter well formed" failed at step #0 (src/file.rs:LL:CC) because
+                    judgment `prove_wf { goal: dyn Iterator<Item = i32> + static, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                      the rule "trait objects" failed at step #4 (src/file.rs:LL:CC) because
+                        judgment `prove_after { constraints: Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {Copy(i32), Iterator(dyn Iterator<Item = i32> + static)}, assumptions: {} }` failed at the following rule(s):
+                          the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                            judgment `prove { goal: {Copy(i32), Iterator(dyn Iterator<Item = i32> + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Iterator <ty> ], [impl Copy(u32)], [], [], [alias <ty> <^ty0_0 as Iterator>::Item : <ty> Copy(^ty0_0) where {Iterator(^ty0_0)}], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+                              failed at (src/file.rs:LL:CC) because
+                                judgment `prove_wc_list { goal: {Copy(i32), Iterator(dyn Iterator<Item = i32> + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                    judgment `prove_wc { goal: Copy(i32), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "positive impl" failed at step #4 (src/file.rs:LL:CC) because
+                                        judgment `prove { goal: {i32 = u32}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Iterator <ty> ], [impl Copy(u32)], [], [], [alias <ty> <^ty0_0 as Iterator>::Item : <ty> Copy(^ty0_0) where {Iterator(^ty0_0)}], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+                                          failed at (src/file.rs:LL:CC) because
+                                            judgment `prove_wc_list { goal: {i32 = u32}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                                judgment `prove_wc { goal: i32 = u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                  the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
+                                                    judgment `prove_eq { a: i32, b: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                      the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                        judgment had no applicable rules: `prove_normalize { p: i32, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                                                      the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                                        judgment `prove_eq { a: u32, b: i32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                                          the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                            judgment had no applicable rules: `prove_normalize { p: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                                      the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                                        expression evaluated to an empty collection: `decls.trait_invariants()`"#]]);
+}
+
+/// Only auto traits may be added to the principal trait.
+#[test]
+fn dyn_wf_requires_auto_traits() {
+    test_prove(decls(), term("{} => {@wf(dyn Other + Super + static)}")).assert_err(expect![[r#"
+        judgment `prove { goal: {@ wf(dyn Other + Super + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Super <ty> , trait Iterator <ty> where {Super(^ty0_0)}, auto trait Send <ty> , trait Other <ty> , trait NotDyn <ty> where {Sized(^ty0_0)}, #[lang(sized)] trait Sized <ty> , trait Convert <ty, ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {@ wf(dyn Other + Super + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: @ wf(dyn Other + Super + static), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "parameter well formed" failed at step #0 (src/file.rs:LL:CC) because
+                    judgment `prove_wf { goal: dyn Other + Super + static, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                      the rule "trait objects" failed at step #1 (src/file.rs:LL:CC) because
+                        condition evaluted to false: `dyn_name.auto_traits.iter().all(|t| decls.is_auto_trait(t))`"#]]);
+}
+
+/// The lifetime bound of a trait object is covariant.
+#[test]
+fn dyn_lifetime_bound_is_covariant() {
+    test_prove(
+        decls(),
+        term("{} => {for<lt a> dyn Other + static <: dyn Other + a}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
@@ -14,7 +14,7 @@ fn test_a() {
     )
     .assert_ok(expect![[r#"
         {
-          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {} },
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
         }
     "#]]);
 }
@@ -27,7 +27,7 @@ fn test_b() {
     )
     .assert_ok(expect![[r#"
         {
-          Constraints { env: Env { variables: [?ty_2, ?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => Vec<u32>, ?ty_2 => u32} },
+          Constraints { env: Env { variables: [?ty_2, ?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => Vec<u32>, ?ty_2 => u32}, outlives: {} },
         }
     "#]]);
 }
@@ -40,7 +40,7 @@ fn test_normalize_assoc_ty() {
     )
     .assert_ok(expect![[r#"
         {
-          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {} },
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
         }
     "#]]);
 }
@@ -52,7 +52,7 @@ fn test_normalize_assoc_ty_existential0() {
         term("exists<ty A> {} => {for<ty T> if { <T as Iterator>::Item = u32 } <A as Iterator>::Item = u32}"),
     ).assert_err(
     expect![[r#"
-        judgment `prove { goal: {for <ty> if {<^ty0_0 as Iterator>::Item = u32} <?ty_0 as Iterator>::Item = u32}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+        judgment `prove { goal: {for <ty> if {<^ty0_0 as Iterator>::Item = u32} <?ty_0 as Iterator>::Item = u32}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
           failed at (src/file.rs:LL:CC) because
             judgment `prove_wc_list { goal: {for <ty> if {<^ty0_0 as Iterator>::Item = u32} <?ty_0 as Iterator>::Item = u32}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` failed at the following rule(s):
               the rule "some" failed at step #0 (src/file.rs:LL:CC) because
@@ -61,6 +61,8 @@ fn test_normalize_assoc_ty_existential0() {
                     judgment `prove_wc { goal: if {<!ty_1 as Iterator>::Item = u32} <?ty_0 as Iterator>::Item = u32, assumptions: {}, env: Env { variables: [?ty_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
                       the rule "implies" failed at step #0 (src/file.rs:LL:CC) because
                         judgment `prove_wc { goal: <?ty_0 as Iterator>::Item = u32, assumptions: {<!ty_1 as Iterator>::Item = u32}, env: Env { variables: [?ty_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
+                          the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                            judgment had no applicable rules: `prove_via { goal: <?ty_0 as Iterator>::Item = u32, via: <!ty_1 as Iterator>::Item = u32, assumptions: {<!ty_1 as Iterator>::Item = u32}, env: Env { variables: [?ty_0, !ty_1], bias: Soundness } }`
                           the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
                             judgment `prove_eq { a: <?ty_0 as Iterator>::Item, b: u32, assumptions: {<!ty_1 as Iterator>::Item = u32}, env: Env { variables: [?ty_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
                               the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
@@ -90,25 +92,29 @@ fn test_normalize_assoc_ty_existential0() {
                                                       the rule "existential-universal" failed at step #0 (src/file.rs:LL:CC) because
                                                         condition evaluted to false: `env.universe(p) < env.universe(v)`
                                   the rule "normalize-via-impl" failed at step #0 (src/file.rs:LL:CC) because
-                                    expression evaluated to an empty collection: `decls.alias_eq_decls(&a.name)`
+                                    expression evaluated to an empty collection: `alias_eq_decls(&decls, &a)`
                               the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
                                 judgment `prove_eq { a: u32, b: <?ty_0 as Iterator>::Item, assumptions: {<!ty_1 as Iterator>::Item = u32}, env: Env { variables: [?ty_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
                                   the rule "normalize-l" failed at step #1 (src/file.rs:LL:CC) because
-                                    judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_0, !ty_1], bias: Soundness }, known_true: true, substitution: {} }, goal: {<!ty_1 as Iterator>::Item = <?ty_0 as Iterator>::Item}, assumptions: {<!ty_1 as Iterator>::Item = u32} }` failed at the following rule(s):
+                                    judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_0, !ty_1], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {<!ty_1 as Iterator>::Item = <?ty_0 as Iterator>::Item}, assumptions: {<!ty_1 as Iterator>::Item = u32} }` failed at the following rule(s):
                                       the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
-                                        judgment `prove { goal: {<!ty_0 as Iterator>::Item = <?ty_1 as Iterator>::Item}, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                        judgment `prove { goal: {<!ty_0 as Iterator>::Item = <?ty_1 as Iterator>::Item}, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
                                           failed at (src/file.rs:LL:CC) because
                                             judgment `prove_wc_list { goal: {<!ty_0 as Iterator>::Item = <?ty_1 as Iterator>::Item}, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
                                               the rule "some" failed at step #0 (src/file.rs:LL:CC) because
                                                 judgment `prove_wc { goal: <!ty_0 as Iterator>::Item = <?ty_1 as Iterator>::Item, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                  the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                                                    judgment had no applicable rules: `prove_via { goal: <!ty_0 as Iterator>::Item = <?ty_1 as Iterator>::Item, via: <!ty_0 as Iterator>::Item = u32, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }`
                                                   the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
                                                     judgment `prove_eq { a: <!ty_0 as Iterator>::Item, b: <?ty_1 as Iterator>::Item, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
                                                       the rule "alias" failed at step #3 (src/file.rs:LL:CC) because
-                                                        judgment `prove { goal: {!ty_0 = ?ty_1}, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                                        judgment `prove { goal: {!ty_0 = ?ty_1}, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
                                                           failed at (src/file.rs:LL:CC) because
                                                             judgment `prove_wc_list { goal: {!ty_0 = ?ty_1}, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
                                                               the rule "some" failed at step #0 (src/file.rs:LL:CC) because
                                                                 judgment `prove_wc { goal: !ty_0 = ?ty_1, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                                  the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                                                                    judgment had no applicable rules: `prove_via { goal: !ty_0 = ?ty_1, via: <!ty_0 as Iterator>::Item = u32, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }`
                                                                   the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
                                                                     judgment `prove_eq { a: !ty_0, b: ?ty_1, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
                                                                       the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
@@ -119,14 +125,20 @@ fn test_normalize_assoc_ty_existential0() {
                                                                                 pattern `None` did not match value `Some(!ty_0)`
                                                                               the rule "existential-universal" failed at step #0 (src/file.rs:LL:CC) because
                                                                                 condition evaluted to false: `env.universe(p) < env.universe(v)`
+                                                                          the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                                            judgment `prove_normalize { p: ?ty_1, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                                              the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                                                                judgment had no applicable rules: `prove_normalize_via { goal: ?ty_1, via: <!ty_0 as Iterator>::Item = u32, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }`
                                                       the rule "normalize-l" failed at step #1 (src/file.rs:LL:CC) because
-                                                        judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_1, !ty_0], bias: Soundness }, known_true: true, substitution: {} }, goal: {u32 = <?ty_1 as Iterator>::Item}, assumptions: {<!ty_0 as Iterator>::Item = u32} }` failed at the following rule(s):
+                                                        judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_1, !ty_0], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {u32 = <?ty_1 as Iterator>::Item}, assumptions: {<!ty_0 as Iterator>::Item = u32} }` failed at the following rule(s):
                                                           the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
-                                                            judgment `prove { goal: {u32 = <?ty_1 as Iterator>::Item}, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                                            judgment `prove { goal: {u32 = <?ty_1 as Iterator>::Item}, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
                                                               failed at (src/file.rs:LL:CC) because
                                                                 judgment `prove_wc_list { goal: {u32 = <?ty_1 as Iterator>::Item}, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
                                                                   the rule "some" failed at step #0 (src/file.rs:LL:CC) because
                                                                     judgment `prove_wc { goal: u32 = <?ty_1 as Iterator>::Item, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                                      the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                                                                        judgment had no applicable rules: `prove_via { goal: u32 = <?ty_1 as Iterator>::Item, via: <!ty_0 as Iterator>::Item = u32, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }`
                                                                       the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
                                                                         judgment `prove_eq { a: u32, b: <?ty_1 as Iterator>::Item, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
                                                                           the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
@@ -158,15 +170,17 @@ fn test_normalize_assoc_ty_existential0() {
                                                                                                       the rule "existential-universal" failed at step #0 (src/file.rs:LL:CC) because
                                                                                                         condition evaluted to false: `env.universe(p) < env.universe(v)`
                                                                                   the rule "normalize-via-impl" failed at step #0 (src/file.rs:LL:CC) because
-                                                                                    expression evaluated to an empty collection: `decls.alias_eq_decls(&a.name)`
+                                                                                    expression evaluated to an empty collection: `alias_eq_decls(&decls, &a)`
                                                       the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
                                                         judgment `prove_eq { a: <?ty_1 as Iterator>::Item, b: <!ty_0 as Iterator>::Item, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
                                                           the rule "alias" failed at step #3 (src/file.rs:LL:CC) because
-                                                            judgment `prove { goal: {?ty_1 = !ty_0}, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                                            judgment `prove { goal: {?ty_1 = !ty_0}, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
                                                               failed at (src/file.rs:LL:CC) because
                                                                 judgment `prove_wc_list { goal: {?ty_1 = !ty_0}, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
                                                                   the rule "some" failed at step #0 (src/file.rs:LL:CC) because
                                                                     judgment `prove_wc { goal: ?ty_1 = !ty_0, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                                      the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                                                                        judgment had no applicable rules: `prove_via { goal: ?ty_1 = !ty_0, via: <!ty_0 as Iterator>::Item = u32, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }`
                                                                       the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
                                                                         judgment `prove_eq { a: ?ty_1, b: !ty_0, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
                                                                           the rule "existential" failed at step #0 (src/file.rs:LL:CC) because
@@ -175,6 +189,10 @@ fn test_normalize_assoc_ty_existential0() {
                                                                                 pattern `None` did not match value `Some(!ty_0)`
                                                                               the rule "existential-universal" failed at step #0 (src/file.rs:LL:CC) because
                                                                                 condition evaluted to false: `env.universe(p) < env.universe(v)`
+                                                                          the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                                            judgment `prove_normalize { p: ?ty_1, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                                              the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                                                                judgment had no applicable rules: `prove_normalize_via { goal: ?ty_1, via: <!ty_0 as Iterator>::Item = u32, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }`
                                                           the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
                                                             judgment `prove_normalize { p: <?ty_1 as Iterator>::Item, assumptions: {<!ty_0 as Iterator>::Item = u32}, env: Env { variables: [?ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
                                                               the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
@@ -202,7 +220,7 @@ fn test_normalize_assoc_ty_existential0() {
                                                                                   the rule "existential-universal" failed at step #0 (src/file.rs:LL:CC) because
                                                                                     condition evaluted to false: `env.universe(p) < env.universe(v)`
                                                               the rule "normalize-via-impl" failed at step #0 (src/file.rs:LL:CC) because
-                                                                expression evaluated to an empty collection: `decls.alias_eq_decls(&a.name)`"#]]);
+                                                                expression evaluated to an empty collection: `alias_eq_decls(&decls, &a)`"#]]);
 }
 
 #[test]
@@ -218,7 +236,7 @@ fn test_normalize_assoc_ty_existential1() {
     )
     .assert_ok(expect![[r#"
         {
-          Constraints { env: Env { variables: [!ty_1, ?ty_2], bias: Soundness }, known_true: true, substitution: {?ty_2 => !ty_1} },
+          Constraints { env: Env { variables: [!ty_1, ?ty_2], bias: Soundness }, known_true: true, substitution: {?ty_2 => !ty_1}, outlives: {} },
         }
     "#]]);
 }
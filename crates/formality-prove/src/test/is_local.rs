@@ -13,7 +13,7 @@ fn test_forall_not_local() {
         term("{} => {for<ty T> @IsLocal(Debug(T))}"),
     ).assert_err(
     expect![[r#"
-        judgment `prove { goal: {for <ty> @ IsLocal(Debug(^ty0_0))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+        judgment `prove { goal: {for <ty> @ IsLocal(Debug(^ty0_0))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
           failed at (src/file.rs:LL:CC) because
             judgment `prove_wc_list { goal: {for <ty> @ IsLocal(Debug(^ty0_0))}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
               the rule "some" failed at step #0 (src/file.rs:LL:CC) because
@@ -22,9 +22,13 @@ fn test_forall_not_local() {
                     judgment `prove_wc { goal: @ IsLocal(Debug(!ty_1)), assumptions: {}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
                       the rule "trait ref is local" failed at step #0 (src/file.rs:LL:CC) because
                         judgment `is_local_trait_ref { goal: Debug(!ty_1), assumptions: {}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
+                          the rule "local parameter" failed at step #1 (src/file.rs:LL:CC) because
+                            judgment `is_local_parameter { goal: !ty_1, assumptions: {}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
+                              the rule "local parameter" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment had no applicable rules: `prove_normalize { p: !ty_1, assumptions: {}, env: Env { variables: [!ty_1], bias: Soundness } }`
                           the rule "local trait" failed at step #0 (src/file.rs:LL:CC) because
                             condition evaluted to false: `decls.is_local_trait_id(&goal.trait_id)`
-                              decls = decls(222, [], [], [], [], [], [], {}, {})
+                              decls = decls(222, [], [], [], [], [], [], [], [], [], {}, {})
                               &goal.trait_id = Debug"#]]);
 }
 
@@ -36,7 +40,7 @@ fn test_exists_not_local() {
     )
     .assert_ok(expect![[r#"
         {
-          Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: false, substitution: {} },
+          Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: false, substitution: {}, outlives: {} },
         }
     "#]])
 }
@@ -20,7 +20,7 @@ fn decls() -> Decls {
 fn direct_cycle() {
     test_prove(decls(), term("exists<ty A> {} => {A = Vec<A>}")).assert_err(
             expect![[r#"
-                judgment `prove { goal: {?ty_0 = Vec<?ty_0>}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl <ty> Foo(Vec<^ty0_0>)], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                judgment `prove { goal: {?ty_0 = Vec<?ty_0>}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl <ty> Foo(Vec<^ty0_0>)], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
                   failed at (src/file.rs:LL:CC) because
                     judgment `prove_wc_list { goal: {?ty_0 = Vec<?ty_0>}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` failed at the following rule(s):
                       the rule "some" failed at step #0 (src/file.rs:LL:CC) because
@@ -32,7 +32,13 @@ fn direct_cycle() {
                                   the rule "existential-nonvar" failed at step #1 (src/file.rs:LL:CC) because
                                     judgment `equate_variable` failed at the following rule(s):
                                       failed at (src/file.rs:LL:CC) because
-                                        `?ty_0` occurs in `Vec<?ty_0>`"#]]);
+                                        `?ty_0` occurs in `Vec<?ty_0>`
+                              the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment had no applicable rules: `prove_normalize { p: ?ty_0, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }`
+                              the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment `prove_eq { a: Vec<?ty_0>, b: ?ty_0, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                    judgment had no applicable rules: `prove_normalize { p: Vec<?ty_0>, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }`"#]]);
 }
 
 /// Test that `X = Vec<Y>` can be solved
@@ -40,7 +46,7 @@ fn direct_cycle() {
 fn eq_variable_to_rigid() {
     test_prove(decls(), term("exists<ty X, ty Y> {} => {X = Vec<Y>}")).assert_ok(expect![[r#"
         {
-          Constraints { env: Env { variables: [?ty_3, ?ty_1, ?ty_2], bias: Soundness }, known_true: true, substitution: {?ty_1 => Vec<?ty_3>, ?ty_2 => ?ty_3} },
+          Constraints { env: Env { variables: [?ty_3, ?ty_1, ?ty_2], bias: Soundness }, known_true: true, substitution: {?ty_1 => Vec<?ty_3>, ?ty_2 => ?ty_3}, outlives: {} },
         }
     "#]]);
 }
@@ -50,7 +56,7 @@ fn eq_variable_to_rigid() {
 fn eq_rigid_to_variable() {
     test_prove(decls(), term("exists<ty X, ty Y> {} => {Vec<Y> = X}")).assert_ok(expect![[r#"
         {
-          Constraints { env: Env { variables: [?ty_3, ?ty_1, ?ty_2], bias: Soundness }, known_true: true, substitution: {?ty_1 => Vec<?ty_3>, ?ty_2 => ?ty_3} },
+          Constraints { env: Env { variables: [?ty_3, ?ty_1, ?ty_2], bias: Soundness }, known_true: true, substitution: {?ty_1 => Vec<?ty_3>, ?ty_2 => ?ty_3}, outlives: {} },
         }
     "#]]);
 }
@@ -63,13 +69,13 @@ fn indirect_cycle_1() {
         term("exists<ty A, ty B> {} => {A = Vec<B>, B = A}"),
     ).assert_err(
     expect![[r#"
-        judgment `prove { goal: {?ty_0 = Vec<?ty_1>, ?ty_1 = ?ty_0}, assumptions: {}, env: Env { variables: [?ty_0, ?ty_1], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl <ty> Foo(Vec<^ty0_0>)], [], [], [], [], {}, {}) }` failed at the following rule(s):
+        judgment `prove { goal: {?ty_0 = Vec<?ty_1>, ?ty_1 = ?ty_0}, assumptions: {}, env: Env { variables: [?ty_0, ?ty_1], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl <ty> Foo(Vec<^ty0_0>)], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
           failed at (src/file.rs:LL:CC) because
             judgment `prove_wc_list { goal: {?ty_0 = Vec<?ty_1>, ?ty_1 = ?ty_0}, assumptions: {}, env: Env { variables: [?ty_0, ?ty_1], bias: Soundness } }` failed at the following rule(s):
               the rule "some" failed at step #1 (src/file.rs:LL:CC) because
-                judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_2, ?ty_0, ?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_0 => Vec<?ty_2>, ?ty_1 => ?ty_2} }, goal: {?ty_1 = ?ty_0}, assumptions: {} }` failed at the following rule(s):
+                judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_2, ?ty_0, ?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_0 => Vec<?ty_2>, ?ty_1 => ?ty_2}, outlives: {} }, goal: {?ty_1 = ?ty_0}, assumptions: {} }` failed at the following rule(s):
                   the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
-                    judgment `prove { goal: {?ty_0 = Vec<?ty_0>}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl <ty> Foo(Vec<^ty0_0>)], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                    judgment `prove { goal: {?ty_0 = Vec<?ty_0>}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl <ty> Foo(Vec<^ty0_0>)], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
                       failed at (src/file.rs:LL:CC) because
                         judgment `prove_wc_list { goal: {?ty_0 = Vec<?ty_0>}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` failed at the following rule(s):
                           the rule "some" failed at step #0 (src/file.rs:LL:CC) because
@@ -81,7 +87,13 @@ fn indirect_cycle_1() {
                                       the rule "existential-nonvar" failed at step #1 (src/file.rs:LL:CC) because
                                         judgment `equate_variable` failed at the following rule(s):
                                           failed at (src/file.rs:LL:CC) because
-                                            `?ty_0` occurs in `Vec<?ty_0>`"#]]);
+                                            `?ty_0` occurs in `Vec<?ty_0>`
+                                  the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                    judgment had no applicable rules: `prove_normalize { p: ?ty_0, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }`
+                                  the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                    judgment `prove_eq { a: Vec<?ty_0>, b: ?ty_0, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                        judgment had no applicable rules: `prove_normalize { p: Vec<?ty_0>, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }`"#]]);
 }
 
 /// Test that `X = Vec<X>` cannot be solved (when constructed over several steps)
@@ -92,13 +104,13 @@ fn indirect_cycle_2() {
         term("exists<ty A, ty B> {} => {B = A, A = Vec<B>}"),
     ).assert_err(
     expect![[r#"
-        judgment `prove { goal: {?ty_0 = Vec<?ty_1>, ?ty_1 = ?ty_0}, assumptions: {}, env: Env { variables: [?ty_0, ?ty_1], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl <ty> Foo(Vec<^ty0_0>)], [], [], [], [], {}, {}) }` failed at the following rule(s):
+        judgment `prove { goal: {?ty_0 = Vec<?ty_1>, ?ty_1 = ?ty_0}, assumptions: {}, env: Env { variables: [?ty_0, ?ty_1], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl <ty> Foo(Vec<^ty0_0>)], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
           failed at (src/file.rs:LL:CC) because
             judgment `prove_wc_list { goal: {?ty_0 = Vec<?ty_1>, ?ty_1 = ?ty_0}, assumptions: {}, env: Env { variables: [?ty_0, ?ty_1], bias: Soundness } }` failed at the following rule(s):
               the rule "some" failed at step #1 (src/file.rs:LL:CC) because
-                judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_2, ?ty_0, ?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_0 => Vec<?ty_2>, ?ty_1 => ?ty_2} }, goal: {?ty_1 = ?ty_0}, assumptions: {} }` failed at the following rule(s):
+                judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_2, ?ty_0, ?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_0 => Vec<?ty_2>, ?ty_1 => ?ty_2}, outlives: {} }, goal: {?ty_1 = ?ty_0}, assumptions: {} }` failed at the following rule(s):
                   the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
-                    judgment `prove { goal: {?ty_0 = Vec<?ty_0>}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl <ty> Foo(Vec<^ty0_0>)], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                    judgment `prove { goal: {?ty_0 = Vec<?ty_0>}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl <ty> Foo(Vec<^ty0_0>)], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
                       failed at (src/file.rs:LL:CC) because
                         judgment `prove_wc_list { goal: {?ty_0 = Vec<?ty_0>}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` failed at the following rule(s):
                           the rule "some" failed at step #0 (src/file.rs:LL:CC) because
@@ -110,5 +122,11 @@ fn indirect_cycle_2() {
                                       the rule "existential-nonvar" failed at step #1 (src/file.rs:LL:CC) because
                                         judgment `equate_variable` failed at the following rule(s):
                                           failed at (src/file.rs:LL:CC) because
-                                            `?ty_0` occurs in `Vec<?ty_0>`"#]]);
+                                            `?ty_0` occurs in `Vec<?ty_0>`
+                                  the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                    judgment had no applicable rules: `prove_normalize { p: ?ty_0, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }`
+                                  the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                    judgment `prove_eq { a: Vec<?ty_0>, b: ?ty_0, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                        judgment had no applicable rules: `prove_normalize { p: Vec<?ty_0>, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }`"#]]);
 }
@@ -1,4 +1,5 @@
 use expect_test::expect;
+use formality_core::judgment::with_proof_trees;
 use formality_macros::test;
 use formality_types::grammar::Wc;
 use formality_types::rust::term;
@@ -22,7 +23,7 @@ fn vec_u32_debug() {
     let goal: Wc = term("Debug(Vec<u32>)");
     prove(decls(), (), (), goal).assert_ok(expect![[r#"
         {
-          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {} },
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
         }
     "#]]);
 }
@@ -32,7 +33,47 @@ fn vec_vec_u32_debug() {
     let goal: Wc = term("Debug(Vec<Vec<u32>>)");
     prove(decls(), (), (), goal).assert_ok(expect![[r#"
         {
-          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {} },
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
         }
     "#]]);
 }
+
+#[test]
+fn vec_u32_debug_proof_tree() {
+    let goal: Wc = term("Debug(Vec<u32>)");
+    with_proof_trees(|| prove(decls(), (), (), goal)).assert_proofs(expect![[r#"
+        judgment `prove_wc_list { goal: {Debug(Vec<u32>)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "some"
+          judgment `prove_wc { goal: Debug(Vec<u32>), assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "positive impl"
+            judgment `prove_wc_list { goal: {Vec<u32> = Vec<?ty_0>}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` produced `Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }` by the rule "some"
+              judgment `prove_wc { goal: Vec<u32> = Vec<?ty_0>, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` produced `Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }` by the rule "eq"
+                judgment `prove_eq { a: Vec<u32>, b: Vec<?ty_0>, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` produced `Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }` by the rule "symmetric"
+                  judgment `prove_eq { a: Vec<?ty_0>, b: Vec<u32>, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` produced `Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }` by the rule "rigid"
+                    judgment `prove_wc_list { goal: {?ty_0 = u32}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` produced `Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }` by the rule "some"
+                      judgment `prove_wc { goal: ?ty_0 = u32, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` produced `Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }` by the rule "eq"
+                        judgment `prove_eq { a: ?ty_0, b: u32, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` produced `Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }` by the rule "existential"
+                          judgment `prove_existential_var_eq { v: ?ty_0, b: u32, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` produced `Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }` by the rule "existential-nonvar"
+                            judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }, goal: {}, assumptions: {} }` produced `Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }` by the rule "prove_after"
+                              judgment `prove_wc_list { goal: {}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "none"
+                      judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }, goal: {}, assumptions: {} }` produced `Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }` by the rule "prove_after"
+                        judgment `prove_wc_list { goal: {}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "none"
+              judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }, goal: {}, assumptions: {} }` produced `Constraints { env: Env { variables: [?ty_0], bias: Soundness }, known_true: true, substitution: {?ty_0 => u32}, outlives: {} }` by the rule "prove_after"
+                judgment `prove_wc_list { goal: {}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "none"
+            judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => u32}, outlives: {} }, goal: {Debug(?ty_1)}, assumptions: {} }` produced `Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => u32}, outlives: {} }` by the rule "prove_after"
+              judgment `prove_wc_list { goal: {Debug(u32)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "some"
+                judgment `prove_wc { goal: Debug(u32), assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "positive impl"
+                  judgment `prove_wc_list { goal: {u32 = u32}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "some"
+                    judgment `prove_wc { goal: u32 = u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "eq"
+                      judgment `prove_eq { a: u32, b: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "trivial"
+                    judgment `prove_after { constraints: Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {}, assumptions: {} }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "prove_after"
+                      judgment `prove_wc_list { goal: {}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "none"
+                  judgment `prove_after { constraints: Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {}, assumptions: {} }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "prove_after"
+                    judgment `prove_wc_list { goal: {}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "none"
+                  judgment `prove_after { constraints: Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {}, assumptions: {} }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "prove_after"
+                    judgment `prove_wc_list { goal: {}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "none"
+                judgment `prove_after { constraints: Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {}, assumptions: {} }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "prove_after"
+                  judgment `prove_wc_list { goal: {}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "none"
+            judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => u32}, outlives: {} }, goal: {}, assumptions: {} }` produced `Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => u32}, outlives: {} }` by the rule "prove_after"
+              judgment `prove_wc_list { goal: {}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "none"
+          judgment `prove_after { constraints: Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {}, assumptions: {} }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "prove_after"
+            judgment `prove_wc_list { goal: {}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` produced `Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }` by the rule "none""#]]);
+}
@@ -0,0 +1,379 @@
+use expect_test::expect;
+use formality_macros::test;
+use formality_types::rust::term;
+
+use crate::decls::Decls;
+
+use crate::test_util::test_prove;
+
+/// `&'static u32` can be used wherever `&'a u32` is expected.
+#[test]
+fn static_ref_sub_ref() {
+    test_prove(
+        Decls::empty(),
+        term("{} => {for<lt a> &static u32 <: &a u32}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+/// ...but not the other way around.
+#[test]
+fn ref_not_sub_static_ref() {
+    test_prove(
+        Decls::empty(),
+        term("{} => {for<lt a> &a u32 <: &static u32}"),
+    )
+    .assert_err(expect![[r#"
+        judgment `prove { goal: {for <lt> &^lt0_0 u32 <: &static u32}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {for <lt> &^lt0_0 u32 <: &static u32}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: for <lt> &^lt0_0 u32 <: &static u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
+                    judgment `prove_wc { goal: &!lt_1 u32 <: &static u32, assumptions: {}, env: Env { variables: [!lt_1], bias: Soundness } }` failed at the following rule(s):
+                      the rule "subtype" failed at step #0 (src/file.rs:LL:CC) because
+                        judgment `prove_sub { a: &!lt_1 u32, b: &static u32, assumptions: {}, env: Env { variables: [!lt_1], bias: Soundness } }` failed at the following rule(s):
+                          the rule "equate" failed at step #1 (src/file.rs:LL:CC) because
+                            condition evaluted to false: `!(is_rigid(&a) && is_rigid(&b))`
+                          the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                            judgment had no applicable rules: `prove_normalize { p: &!lt_1 u32, assumptions: {}, env: Env { variables: [!lt_1], bias: Soundness } }`
+                          the rule "normalize-r" failed at step #0 (src/file.rs:LL:CC) because
+                            judgment had no applicable rules: `prove_normalize { p: &static u32, assumptions: {}, env: Env { variables: [!lt_1], bias: Soundness } }`
+                          the rule "rigid" failed at step #5 (src/file.rs:LL:CC) because
+                            judgment `prove { goal: {u32 <: u32, !lt_0 <: static}, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                              failed at (src/file.rs:LL:CC) because
+                                judgment `prove_wc_list { goal: {u32 <: u32, !lt_0 <: static}, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "some" failed at step #1 (src/file.rs:LL:CC) because
+                                    judgment `prove_after { constraints: Constraints { env: Env { variables: [!lt_0], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {!lt_0 <: static}, assumptions: {} }` failed at the following rule(s):
+                                      the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                                        judgment `prove { goal: {!lt_0 <: static}, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                          failed at (src/file.rs:LL:CC) because
+                                            judgment `prove_wc_list { goal: {!lt_0 <: static}, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                                              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                                judgment `prove_wc { goal: !lt_0 <: static, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                                                  the rule "subtype" failed at step #0 (src/file.rs:LL:CC) because
+                                                    judgment `prove_sub { a: !lt_0, b: static, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                                                      the rule "equate" failed at step #0 (src/file.rs:LL:CC) because
+                                                        condition evaluted to false: `a.kind() != ParameterKind::Lt`
+                                                      the rule "lifetimes" failed at step #0 (src/file.rs:LL:CC) because
+                                                        judgment `prove_outlives { a: !lt_0, b: static, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                                                          the rule "existential lifetimes" failed at step #0 (src/file.rs:LL:CC) because
+                                                            condition evaluted to false: `is_existential(&a, ParameterKind::Lt) ||
+                                                            (is_universal(&a) && is_existential(&b, ParameterKind::Lt))`
+                                                          the rule "existential types" failed at step #0 (src/file.rs:LL:CC) because
+                                                            condition evaluted to false: `is_existential(&a, ParameterKind::Ty)`
+                                                              &a = !lt_0
+                                                              ParameterKind::Ty = ty
+                                                          the rule "transitive" failed at step #0 (src/file.rs:LL:CC) because
+                                                            expression evaluated to an empty collection: `&assumptions`
+                                                      the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                        judgment had no applicable rules: `prove_normalize { p: !lt_0, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }`
+                                                      the rule "normalize-r" failed at step #0 (src/file.rs:LL:CC) because
+                                                        judgment had no applicable rules: `prove_normalize { p: static, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }`"#]]);
+}
+
+/// Subtyping of lifetimes follows the outlives relations in the assumptions.
+#[test]
+fn ref_sub_ref_via_assumption() {
+    test_prove(
+        Decls::empty(),
+        term("{} => {for<lt a, lt b> if {a : b} &a u32 <: &b u32}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+/// Outlives relations in the assumptions are transitive.
+#[test]
+fn outlives_transitive() {
+    test_prove(
+        Decls::empty(),
+        term("{} => {for<lt a, lt b, lt c> if {a : b, b : c} a : c}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+/// `&'a mut T` is invariant in `T`.
+#[test]
+fn mut_ref_invariant() {
+    test_prove(
+        Decls::empty(),
+        term("{} => {for<lt a, lt b> if {a : b} &mut b &a u32 <: &mut b &b u32}"),
+    )
+    .assert_err(expect![[r#"
+        judgment `prove { goal: {for <lt, lt> if {^lt0_0 : ^lt0_1} &mut ^lt0_1 &^lt0_0 u32 <: &mut ^lt0_1 &^lt0_1 u32}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {for <lt, lt> if {^lt0_0 : ^lt0_1} &mut ^lt0_1 &^lt0_0 u32 <: &mut ^lt0_1 &^lt0_1 u32}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: for <lt, lt> if {^lt0_0 : ^lt0_1} &mut ^lt0_1 &^lt0_0 u32 <: &mut ^lt0_1 &^lt0_1 u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
+                    judgment `prove_wc { goal: if {!lt_1 : !lt_2} &mut !lt_2 &!lt_1 u32 <: &mut !lt_2 &!lt_2 u32, assumptions: {}, env: Env { variables: [!lt_1, !lt_2], bias: Soundness } }` failed at the following rule(s):
+                      the rule "implies" failed at step #0 (src/file.rs:LL:CC) because
+                        judgment `prove_wc { goal: &mut !lt_2 &!lt_1 u32 <: &mut !lt_2 &!lt_2 u32, assumptions: {!lt_1 : !lt_2}, env: Env { variables: [!lt_1, !lt_2], bias: Soundness } }` failed at the following rule(s):
+                          the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                            judgment had no applicable rules: `prove_via { goal: &mut !lt_2 &!lt_1 u32 <: &mut !lt_2 &!lt_2 u32, via: !lt_1 : !lt_2, assumptions: {!lt_1 : !lt_2}, env: Env { variables: [!lt_1, !lt_2], bias: Soundness } }`
+                          the rule "subtype" failed at step #0 (src/file.rs:LL:CC) because
+                            judgment `prove_sub { a: &mut !lt_2 &!lt_1 u32, b: &mut !lt_2 &!lt_2 u32, assumptions: {!lt_1 : !lt_2}, env: Env { variables: [!lt_1, !lt_2], bias: Soundness } }` failed at the following rule(s):
+                              the rule "equate" failed at step #1 (src/file.rs:LL:CC) because
+                                condition evaluted to false: `!(is_rigid(&a) && is_rigid(&b))`
+                              the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment `prove_normalize { p: &mut !lt_2 &!lt_1 u32, assumptions: {!lt_1 : !lt_2}, env: Env { variables: [!lt_1, !lt_2], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                    judgment had no applicable rules: `prove_normalize_via { goal: &mut !lt_2 &!lt_1 u32, via: !lt_1 : !lt_2, assumptions: {!lt_1 : !lt_2}, env: Env { variables: [!lt_1, !lt_2], bias: Soundness } }`
+                              the rule "normalize-r" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment `prove_normalize { p: &mut !lt_2 &!lt_2 u32, assumptions: {!lt_1 : !lt_2}, env: Env { variables: [!lt_1, !lt_2], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                    judgment had no applicable rules: `prove_normalize_via { goal: &mut !lt_2 &!lt_2 u32, via: !lt_1 : !lt_2, assumptions: {!lt_1 : !lt_2}, env: Env { variables: [!lt_1, !lt_2], bias: Soundness } }`
+                              the rule "rigid" failed at step #5 (src/file.rs:LL:CC) because
+                                judgment `prove { goal: {&!lt_0 u32 = &!lt_1 u32, !lt_1 <: !lt_1}, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                  failed at (src/file.rs:LL:CC) because
+                                    judgment `prove_wc_list { goal: {&!lt_0 u32 = &!lt_1 u32, !lt_1 <: !lt_1}, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                        judgment `prove_wc { goal: &!lt_0 u32 = &!lt_1 u32, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                          the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                                            judgment had no applicable rules: `prove_via { goal: &!lt_0 u32 = &!lt_1 u32, via: !lt_0 : !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }`
+                                          the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
+                                            judgment `prove_eq { a: &!lt_0 u32, b: &!lt_1 u32, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                              the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                judgment `prove_normalize { p: &!lt_0 u32, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                  the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                                    judgment had no applicable rules: `prove_normalize_via { goal: &!lt_0 u32, via: !lt_0 : !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }`
+                                              the rule "rigid" failed at step #3 (src/file.rs:LL:CC) because
+                                                judgment `prove { goal: {u32 = u32, !lt_0 = !lt_1}, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                                  failed at (src/file.rs:LL:CC) because
+                                                    judgment `prove_wc_list { goal: {u32 = u32, !lt_0 = !lt_1}, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                      the rule "some" failed at step #1 (src/file.rs:LL:CC) because
+                                                        judgment `prove_after { constraints: Constraints { env: Env { variables: [!lt_0, !lt_1], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {!lt_0 = !lt_1}, assumptions: {!lt_0 : !lt_1} }` failed at the following rule(s):
+                                                          the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                                                            judgment `prove { goal: {!lt_0 = !lt_1}, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                                              failed at (src/file.rs:LL:CC) because
+                                                                judgment `prove_wc_list { goal: {!lt_0 = !lt_1}, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                                  the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                                                    judgment `prove_wc { goal: !lt_0 = !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                                      the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                                                                        judgment had no applicable rules: `prove_via { goal: !lt_0 = !lt_1, via: !lt_0 : !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }`
+                                                                      the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
+                                                                        judgment `prove_eq { a: !lt_0, b: !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                                          the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                                            judgment `prove_normalize { p: !lt_0, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                                              the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                                                                judgment had no applicable rules: `prove_normalize_via { goal: !lt_0, via: !lt_0 : !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }`
+                                                                          the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                                                            judgment `prove_eq { a: !lt_1, b: !lt_0, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                                              the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                                                judgment `prove_normalize { p: !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                                                  the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                                                                    judgment had no applicable rules: `prove_normalize_via { goal: !lt_1, via: !lt_0 : !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }`
+                                              the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                                judgment `prove_eq { a: &!lt_1 u32, b: &!lt_0 u32, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                  the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                    judgment `prove_normalize { p: &!lt_1 u32, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                      the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                                        judgment had no applicable rules: `prove_normalize_via { goal: &!lt_1 u32, via: !lt_0 : !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }`
+                                                  the rule "rigid" failed at step #3 (src/file.rs:LL:CC) because
+                                                    judgment `prove { goal: {u32 = u32, !lt_1 = !lt_0}, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                                      failed at (src/file.rs:LL:CC) because
+                                                        judgment `prove_wc_list { goal: {u32 = u32, !lt_1 = !lt_0}, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                          the rule "some" failed at step #1 (src/file.rs:LL:CC) because
+                                                            judgment `prove_after { constraints: Constraints { env: Env { variables: [!lt_0, !lt_1], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {!lt_1 = !lt_0}, assumptions: {!lt_0 : !lt_1} }` failed at the following rule(s):
+                                                              the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                                                                judgment `prove { goal: {!lt_1 = !lt_0}, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                                                  failed at (src/file.rs:LL:CC) because
+                                                                    judgment `prove_wc_list { goal: {!lt_1 = !lt_0}, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                                                        judgment `prove_wc { goal: !lt_1 = !lt_0, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                                          the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                                                                            judgment had no applicable rules: `prove_via { goal: !lt_1 = !lt_0, via: !lt_0 : !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }`
+                                                                          the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
+                                                                            judgment `prove_eq { a: !lt_1, b: !lt_0, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                                              the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                                                judgment `prove_normalize { p: !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                                                  the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                                                                    judgment had no applicable rules: `prove_normalize_via { goal: !lt_1, via: !lt_0 : !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }`
+                                                                              the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
+                                                                                judgment `prove_eq { a: !lt_0, b: !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                                                  the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                                                                    judgment `prove_normalize { p: !lt_0, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                                                                                      the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                                                                                        judgment had no applicable rules: `prove_normalize_via { goal: !lt_0, via: !lt_0 : !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }`"#]]);
+}
+
+/// `&'a T: 'b` requires both `'a: 'b` and `T: 'b`.
+#[test]
+fn ref_outlives() {
+    test_prove(
+        Decls::empty(),
+        term("{} => {for<lt a, lt b, ty T> if {a : b, T : b} &a T : b}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+/// `&'a T: 'b` does not hold if we don't know that `T: 'b`.
+#[test]
+fn ref_outlives_missing_ty_bound() {
+    test_prove(
+        Decls::empty(),
+        term("{} => {for<lt a, lt b, ty T> if {a : b} &a T : b}"),
+    )
+    .assert_err(expect![[r#"
+        judgment `prove { goal: {for <lt, lt, ty> if {^lt0_0 : ^lt0_1} &^lt0_0 ^ty0_2 : ^lt0_1}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {for <lt, lt, ty> if {^lt0_0 : ^lt0_1} &^lt0_0 ^ty0_2 : ^lt0_1}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: for <lt, lt, ty> if {^lt0_0 : ^lt0_1} &^lt0_0 ^ty0_2 : ^lt0_1, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
+                    judgment `prove_wc { goal: if {!lt_1 : !lt_2} &!lt_1 !ty_3 : !lt_2, assumptions: {}, env: Env { variables: [!lt_1, !lt_2, !ty_3], bias: Soundness } }` failed at the following rule(s):
+                      the rule "implies" failed at step #0 (src/file.rs:LL:CC) because
+                        judgment `prove_wc { goal: &!lt_1 !ty_3 : !lt_2, assumptions: {!lt_1 : !lt_2}, env: Env { variables: [!lt_1, !lt_2, !ty_3], bias: Soundness } }` failed at the following rule(s):
+                          the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                            judgment had no applicable rules: `prove_via { goal: &!lt_1 !ty_3 : !lt_2, via: !lt_1 : !lt_2, assumptions: {!lt_1 : !lt_2}, env: Env { variables: [!lt_1, !lt_2, !ty_3], bias: Soundness } }`
+                          the rule "outlives" failed at step #0 (src/file.rs:LL:CC) because
+                            judgment `prove_outlives { a: &!lt_1 !ty_3, b: !lt_2, assumptions: {!lt_1 : !lt_2}, env: Env { variables: [!lt_1, !lt_2, !ty_3], bias: Soundness } }` failed at the following rule(s):
+                              the rule "existential lifetimes" failed at step #0 (src/file.rs:LL:CC) because
+                                condition evaluted to false: `is_existential(&a, ParameterKind::Lt) ||
+                                (is_universal(&a) && is_existential(&b, ParameterKind::Lt))`
+                              the rule "existential types" failed at step #0 (src/file.rs:LL:CC) because
+                                condition evaluted to false: `is_existential(&a, ParameterKind::Ty)`
+                                  &a = &!lt_1 !ty_3
+                                  ParameterKind::Ty = ty
+                              the rule "rigid" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment `prove { goal: {!ty_2 : !lt_1, !lt_0 : !lt_1}, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1, !ty_2], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                  failed at (src/file.rs:LL:CC) because
+                                    judgment `prove_wc_list { goal: {!ty_2 : !lt_1, !lt_0 : !lt_1}, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1, !ty_2], bias: Soundness } }` failed at the following rule(s):
+                                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                        judgment `prove_wc { goal: !ty_2 : !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1, !ty_2], bias: Soundness } }` failed at the following rule(s):
+                                          the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                                            judgment had no applicable rules: `prove_via { goal: !ty_2 : !lt_1, via: !lt_0 : !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1, !ty_2], bias: Soundness } }`
+                                          the rule "outlives" failed at step #0 (src/file.rs:LL:CC) because
+                                            judgment `prove_outlives { a: !ty_2, b: !lt_1, assumptions: {!lt_0 : !lt_1}, env: Env { variables: [!lt_0, !lt_1, !ty_2], bias: Soundness } }` failed at the following rule(s):
+                                              the rule "existential lifetimes" failed at step #0 (src/file.rs:LL:CC) because
+                                                condition evaluted to false: `is_existential(&a, ParameterKind::Lt) ||
+                                                (is_universal(&a) && is_existential(&b, ParameterKind::Lt))`
+                                              the rule "existential types" failed at step #0 (src/file.rs:LL:CC) because
+                                                condition evaluted to false: `is_existential(&a, ParameterKind::Ty)`
+                                                  &a = !ty_2
+                                                  ParameterKind::Ty = ty
+                                              the rule "transitive" failed at step #2 (src/file.rs:LL:CC) because
+                                                condition evaluted to false: `outlives_components(&x).contains(&a)`
+                              the rule "transitive" failed at step #2 (src/file.rs:LL:CC) because
+                                condition evaluted to false: `outlives_components(&x).contains(&a)`"#]]);
+}
+
+/// Assuming `&'a T: 'b` also assumes it of the components `'a` and `T`,
+/// as the lending iterator of `generic_associated_types.rs` relies on.
+#[test]
+fn outlives_components_of_assumption() {
+    test_prove(
+        Decls::empty(),
+        term("{} => {for<lt a, lt b, ty T> if {&a T : b} T : b, for<lt a, lt b, ty T> if {&a T : b} a : b}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+/// `&'a T` is well-formed if `T: 'a`.
+#[test]
+fn ref_wf() {
+    test_prove(
+        Decls::empty(),
+        term("{} => {for<lt a, ty T> if {T : a} @ wf(&a T)}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+/// `&'a T` is not well-formed if we don't know that `T: 'a`.
+#[test]
+fn ref_wf_missing_ty_bound() {
+    test_prove(Decls::empty(), term("{} => {for<lt a, ty T> @ wf(&a T)}"))
+        .assert_err(expect![[r#"
+            judgment `prove { goal: {for <lt, ty> @ wf(&^lt0_0 ^ty0_1)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+              failed at (src/file.rs:LL:CC) because
+                judgment `prove_wc_list { goal: {for <lt, ty> @ wf(&^lt0_0 ^ty0_1)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                    judgment `prove_wc { goal: for <lt, ty> @ wf(&^lt0_0 ^ty0_1), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                      the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
+                        judgment `prove_wc { goal: @ wf(&!lt_1 !ty_2), assumptions: {}, env: Env { variables: [!lt_1, !ty_2], bias: Soundness } }` failed at the following rule(s):
+                          the rule "parameter well formed" failed at step #0 (src/file.rs:LL:CC) because
+                            judgment `prove_wf { goal: &!lt_1 !ty_2, assumptions: {}, env: Env { variables: [!lt_1, !ty_2], bias: Soundness } }` failed at the following rule(s):
+                              the rule "references" failed at step #2 (src/file.rs:LL:CC) because
+                                judgment `prove_after { constraints: Constraints { env: Env { variables: [!lt_1, !ty_2], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }, goal: {!ty_2 : !lt_1}, assumptions: {} }` failed at the following rule(s):
+                                  the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                                    judgment `prove { goal: {!ty_0 : !lt_1}, assumptions: {}, env: Env { variables: [!lt_1, !ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+                                      failed at (src/file.rs:LL:CC) because
+                                        judgment `prove_wc_list { goal: {!ty_0 : !lt_1}, assumptions: {}, env: Env { variables: [!lt_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
+                                          the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                            judgment `prove_wc { goal: !ty_0 : !lt_1, assumptions: {}, env: Env { variables: [!lt_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
+                                              the rule "outlives" failed at step #0 (src/file.rs:LL:CC) because
+                                                judgment `prove_outlives { a: !ty_0, b: !lt_1, assumptions: {}, env: Env { variables: [!lt_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
+                                                  the rule "existential lifetimes" failed at step #0 (src/file.rs:LL:CC) because
+                                                    condition evaluted to false: `is_existential(&a, ParameterKind::Lt) ||
+                                                    (is_universal(&a) && is_existential(&b, ParameterKind::Lt))`
+                                                  the rule "existential types" failed at step #0 (src/file.rs:LL:CC) because
+                                                    condition evaluted to false: `is_existential(&a, ParameterKind::Ty)`
+                                                      &a = !ty_0
+                                                      ParameterKind::Ty = ty
+                                                  the rule "transitive" failed at step #0 (src/file.rs:LL:CC) because
+                                                    expression evaluated to an empty collection: `&assumptions`"#]]);
+}
+
+/// Relations involving existential lifetimes are returned as constraints
+/// rather than being proven.
+#[test]
+fn existential_lifetimes_deferred() {
+    test_prove(
+        Decls::empty(),
+        term("exists<lt a, lt b> {} => {&a u32 <: &b u32}"),
+    )
+    .assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [?lt_1, ?lt_2], bias: Soundness }, known_true: true, substitution: {}, outlives: {(?lt_1, ?lt_2)} },
+        }
+    "#]]);
+}
+
+/// An existential lifetime that must outlive every lifetime must be `'static`.
+#[test]
+fn existential_outlives_placeholder() {
+    test_prove(Decls::empty(), term("exists<lt a> {} => {for<lt b> a : b}")).assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [?lt_1], bias: Soundness }, known_true: true, substitution: {}, outlives: {(?lt_1, static)} },
+        }
+    "#]]);
+}
+
+/// No lifetime is outlived by every lifetime.
+#[test]
+fn placeholder_outlives_existential() {
+    test_prove(Decls::empty(), term("exists<lt a> {} => {for<lt b> b : a}")).assert_err(expect![[r#"
+        judgment `prove { goal: {for <lt> ^lt0_0 : ?lt_0}, assumptions: {}, env: Env { variables: [?lt_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {for <lt> ^lt0_0 : ?lt_0}, assumptions: {}, env: Env { variables: [?lt_0], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: for <lt> ^lt0_0 : ?lt_0, assumptions: {}, env: Env { variables: [?lt_0], bias: Soundness } }` failed at the following rule(s):
+                  the rule "forall" failed at step #3 (src/file.rs:LL:CC) because
+                    judgment `pop_subst` failed at the following rule(s):
+                      failed at (src/file.rs:LL:CC) because
+                        `!lt_1: ?lt_0` does not hold for all values of `!lt_1`"#]]);
+}
@@ -12,7 +12,7 @@ fn exists_u_for_t() {
     let decls = Decls::empty();
     test_prove(decls, term("exists<ty U> {} => {for<ty T> T = U}")).assert_err(
     expect![[r#"
-        judgment `prove { goal: {for <ty> ^ty0_0 = ?ty_0}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
+        judgment `prove { goal: {for <ty> ^ty0_0 = ?ty_0}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
           failed at (src/file.rs:LL:CC) because
             judgment `prove_wc_list { goal: {for <ty> ^ty0_0 = ?ty_0}, assumptions: {}, env: Env { variables: [?ty_0], bias: Soundness } }` failed at the following rule(s):
               the rule "some" failed at step #0 (src/file.rs:LL:CC) because
@@ -21,6 +21,8 @@ fn exists_u_for_t() {
                     judgment `prove_wc { goal: !ty_1 = ?ty_0, assumptions: {}, env: Env { variables: [?ty_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
                       the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
                         judgment `prove_eq { a: !ty_1, b: ?ty_0, assumptions: {}, env: Env { variables: [?ty_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
+                          the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                            judgment had no applicable rules: `prove_normalize { p: !ty_1, assumptions: {}, env: Env { variables: [?ty_0, !ty_1], bias: Soundness } }`
                           the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
                             judgment `prove_eq { a: ?ty_0, b: !ty_1, assumptions: {}, env: Env { variables: [?ty_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
                               the rule "existential" failed at step #0 (src/file.rs:LL:CC) because
@@ -28,7 +30,9 @@ fn exists_u_for_t() {
                                   the rule "existential-nonvar" failed at step #0 (src/file.rs:LL:CC) because
                                     pattern `None` did not match value `Some(!ty_1)`
                                   the rule "existential-universal" failed at step #0 (src/file.rs:LL:CC) because
-                                    condition evaluted to false: `env.universe(p) < env.universe(v)`"#]]);
+                                    condition evaluted to false: `env.universe(p) < env.universe(v)`
+                              the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment had no applicable rules: `prove_normalize { p: ?ty_0, assumptions: {}, env: Env { variables: [?ty_0, !ty_1], bias: Soundness } }`"#]]);
 }
 
 /// There is U that is equal to some T.
@@ -42,7 +46,23 @@ fn for_t_exists_u() {
 
     test_prove(decls, term("{} => {for<ty T> Test(T, T)}")).assert_ok(expect![[r#"
         {
-          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {} },
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
+        }
+    "#]]);
+}
+
+/// There is a lifetime `'b` outlived by any lifetime `'a`, namely `'a` itself.
+#[test]
+fn for_a_exists_b_outlived_by_a() {
+    let decls = Decls {
+        trait_decls: vec![term("trait Test<ty Self> where {}")],
+        impl_decls: vec![term("impl<lt a, lt b> Test(&a u32) where {a : b}")],
+        ..Decls::empty()
+    };
+
+    test_prove(decls, term("{} => {for<lt a> Test(&a u32)}")).assert_ok(expect![[r#"
+        {
+          Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} },
         }
     "#]]);
 }
@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
 use formality_core::{term, Upcast};
-use formality_prove::Safety;
+use formality_prove::{Auto, LangItem, Safety};
 use formality_types::{
     grammar::{
-        AdtId, AliasTy, AssociatedItemId, Binder, Const, ConstData, CrateId, Fallible, FieldId,
-        FnId, Lt, Parameter, Relation, TraitId, TraitRef, Ty, Wc, Wcs,
+        AdtId, AliasTy, AssociatedItemId, Binder, Coinductive, Const, ConstData, CrateId, Fallible,
+        FieldId, FnId, Lt, Parameter, Relation, TraitId, TraitRef, Ty, Wc, Wcs,
     },
     rust::Term,
 };
@@ -42,6 +42,41 @@ impl Program {
             Ok(traits.pop().unwrap())
         }
     }
+
+    pub fn fn_named(&self, fn_id: &FnId) -> Fallible<&Fn> {
+        let mut fns: Vec<&Fn> = self
+            .items_from_all_crates()
+            .filter_map(|crate_item| match crate_item {
+                CrateItem::Fn(f) if f.id == *fn_id => Some(f),
+                _ => None,
+            })
+            .collect();
+        if fns.is_empty() {
+            anyhow::bail!("no function named `{fn_id:?}`")
+        } else if fns.len() > 1 {
+            anyhow::bail!("multiple functions named `{fn_id:?}`")
+        } else {
+            Ok(fns.pop().unwrap())
+        }
+    }
+
+    pub fn adt_named(&self, adt_id: &AdtId) -> Fallible<Adt> {
+        let mut adts: Vec<Adt> = self
+            .items_from_all_crates()
+            .filter_map(|crate_item| match crate_item {
+                CrateItem::Struct(s) if s.id == *adt_id => Some(s.to_adt()),
+                CrateItem::Enum(e) if e.id == *adt_id => Some(e.to_adt()),
+                _ => None,
+            })
+            .collect();
+        if adts.is_empty() {
+            anyhow::bail!("no ADT named `{adt_id:?}`")
+        } else if adts.len() > 1 {
+            anyhow::bail!("multiple ADTs named `{adt_id:?}`")
+        } else {
+            Ok(adts.pop().unwrap())
+        }
+    }
 }
 
 #[term(crate $id { $*items })]
@@ -63,6 +98,8 @@ pub enum CrateItem {
     #[cast]
     NegTraitImpl(NegTraitImpl),
     #[cast]
+    InherentImpl(InherentImpl),
+    #[cast]
     Fn(Fn),
     #[cast]
     Test(Test),
@@ -174,9 +211,12 @@ pub struct Variant {
     pub fields: Vec<Field>,
 }
 
-#[term($?safety trait $id $binder)]
+#[term($?lang_item $?safety $?auto $?coinductive trait $id $binder)]
 pub struct Trait {
+    pub lang_item: LangItem,
     pub safety: Safety,
+    pub auto: Auto,
+    pub coinductive: Coinductive,
     pub id: TraitId,
     pub binder: TraitBinder<TraitBoundData>,
 }
@@ -241,19 +281,31 @@ pub enum FnBody {
     MirFnBody(MirFnBody),
 }
 
-#[term(type $id $binder ;)]
+#[term(type $id $binder)]
 pub struct AssociatedTy {
     pub id: AssociatedItemId,
     pub binder: Binder<AssociatedTyBoundData>,
 }
 
-#[term(: $ensures $:where $,where_clauses)]
+#[term(: $ensures $:where $,where_clauses $default)]
 pub struct AssociatedTyBoundData {
     /// So e.g. `type Item : [Sized]` would be encoded as `<type I> (I: Sized)`.
     pub ensures: Vec<WhereBound>,
 
     /// Where clauses that must hold.
     pub where_clauses: Vec<WhereClause>,
+
+    /// The type used by impls that do not give a value for the associated type.
+    pub default: MaybeAssociatedTyDefault,
+}
+
+#[term]
+pub enum MaybeAssociatedTyDefault {
+    #[grammar(;)]
+    NoDefault,
+
+    #[grammar(= $v0 ;)]
+    DefaultTy(Ty),
 }
 
 #[term($?safety impl $binder)]
@@ -303,6 +355,21 @@ impl NegTraitImplBoundData {
     }
 }
 
+/// An impl without a trait, like `impl<ty T> Foo<T> { ... }`, which must be for
+/// an ADT of the current crate. Its associated types (unstable in Rust) are checked
+/// but cannot be referred to.
+#[term(impl $binder)]
+pub struct InherentImpl {
+    pub binder: Binder<InherentImplBoundData>,
+}
+
+#[term($self_ty $:where $,where_clauses { $*impl_items })]
+pub struct InherentImplBoundData {
+    pub self_ty: Ty,
+    pub where_clauses: Vec<WhereClause>,
+    pub impl_items: Vec<ImplItem>,
+}
+
 #[term]
 pub enum ImplItem {
     #[cast]
@@ -349,6 +416,7 @@ impl WhereClause {
                 Some(Wc::for_all(&vars, wc))
             }
             WhereClauseData::TypeOfConst(_, _) => None,
+            WhereClauseData::MaybeImplemented(_, _) => None,
         }
     }
 
@@ -399,6 +467,10 @@ impl WhereClause {
                 wcs.push(ty_param.well_formed());
                 wcs.into_iter().map(|r| r.upcast()).collect()
             }
+            WhereClauseData::MaybeImplemented(ty, _) => {
+                let ty_param: Parameter = ty.upcast();
+                ty_param.well_formed().upcast()
+            }
         }
     }
 }
@@ -419,6 +491,10 @@ pub enum WhereClauseData {
 
     #[grammar(type_of_const $v0 is $v1)]
     TypeOfConst(Const, Ty),
+
+    /// `T: ?Sized` removes the implicit `T: Sized` bound; it is not a bound in itself.
+    #[grammar($v0 : ? $v1)]
+    MaybeImplemented(Ty, TraitId),
 }
 
 #[term($data)]
@@ -430,6 +506,34 @@ impl WhereBound {
     pub fn data(&self) -> &WhereBoundData {
         &self.data
     }
+
+    /// The goals for this bound to be well-formed when applied to `self_ty`.
+    pub fn well_formed(&self, self_ty: impl Upcast<Ty>) -> Wcs {
+        let self_ty: Ty = self_ty.upcast();
+        match self.data() {
+            WhereBoundData::IsImplemented(trait_id, parameters) => {
+                trait_id.with(self_ty, parameters).well_formed().upcast()
+            }
+            WhereBoundData::Outlives(lt) => {
+                let ty_param: Parameter = self_ty.upcast();
+                let lt_param: Parameter = lt.upcast();
+                [
+                    ty_param.well_formed().upcast(),
+                    lt_param.well_formed().upcast(),
+                ]
+                .into_iter()
+                .collect()
+            }
+            WhereBoundData::ForAll(binder) => {
+                let (vars, bound) = binder.open();
+                bound
+                    .well_formed(self_ty)
+                    .into_iter()
+                    .map(|wc| Wc::for_all(&vars, wc))
+                    .collect()
+            }
+        }
+    }
 }
 
 #[term]
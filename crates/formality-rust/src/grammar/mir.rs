@@ -1,7 +1,7 @@
-use super::VariantId;
+use super::{FieldName, VariantId};
 use formality_core::{Downcast, DowncastFrom, Upcast};
 use formality_macros::term;
-use formality_types::grammar::{AdtId, Binder, FieldId, FnId, Lt, Parameter, RefKind, Ty};
+use formality_types::grammar::{AdtId, Binder, FnId, Lt, Parameter, RefKind, ScalarId, Ty};
 
 #[term(mir($binder))]
 pub struct MirFnBody {
@@ -90,6 +90,8 @@ pub enum BinaryComparisonOp {
 #[term]
 pub enum AggregateKind {
     Tuple,
+    /// Builds the (only) variant of a struct.
+    Struct(AdtId, Vec<Parameter>),
     Adt(AdtId, VariantId, Vec<Parameter>),
 }
 
@@ -114,7 +116,7 @@ pub enum Operand {
 
 #[term]
 pub enum Constant {
-    Number(usize),
+    Number(usize, ScalarId),
     True,
     False,
     FnPtr(FnId, Vec<Parameter>),
@@ -133,7 +135,7 @@ pub enum Projection {
     Deref,
 
     #[grammar($v0)]
-    Field(FieldId),
+    Field(FieldName),
 
     #[grammar([$v0])]
     Index(LocalId),
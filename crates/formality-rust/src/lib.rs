@@ -5,5 +5,7 @@ use formality_types::rust::FormalityLang;
 
 pub mod grammar;
 pub mod prove;
+mod sized;
 mod test;
 mod trait_binder;
+mod variance;
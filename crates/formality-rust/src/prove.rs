@@ -1,16 +1,24 @@
-use crate::grammar::{
-    Adt, AdtBoundData, AssociatedTy, AssociatedTyBoundData, AssociatedTyValue,
-    AssociatedTyValueBoundData, Crate, CrateItem, ImplItem, NegTraitImpl, NegTraitImplBoundData,
-    Program, Trait, TraitBoundData, TraitImpl, TraitImplBoundData, TraitItem, WhereBound,
-    WhereBoundData, WhereClause, WhereClauseData,
+use crate::{
+    grammar::{
+        Adt, AdtBoundData, AssociatedTy, AssociatedTyBoundData, AssociatedTyValue,
+        AssociatedTyValueBoundData, Crate, CrateItem, Fn, FnBoundData, ImplItem,
+        MaybeAssociatedTyDefault, NegTraitImpl, NegTraitImplBoundData, Program, Trait,
+        TraitBoundData, TraitImpl, TraitImplBoundData, TraitItem, WhereBound, WhereBoundData,
+        WhereClause, WhereClauseData,
+    },
+    variance,
 };
 use formality_core::{seq, Set, To, Upcast, Upcasted};
 use formality_prove as prove;
 use formality_types::grammar::{
-    AdtId, AliasTy, Binder, BoundVar, ParameterKind, Predicate, Relation, TraitId, Ty, Wc, Wcs,
+    AdtId, AliasTy, AssociatedItemId, Binder, BoundVar, Parameter, ParameterKind, Predicate,
+    Relation, TraitId, TraitRef, Ty, Wc, Wcs,
 };
 
 impl Program {
+    /// Lowers the program to the prover's declarations. The implicit `Sized` bounds
+    /// on type parameters are only included if they were added to the program first
+    /// (see [`Program::with_implicit_sized_bounds`]).
     pub fn to_prove_decls(&self) -> prove::Decls {
         formality_prove::Decls {
             max_size: formality_prove::Decls::DEFAULT_MAX_SIZE,
@@ -19,13 +27,16 @@ impl Program {
             neg_impl_decls: self.neg_impl_decls(),
             alias_eq_decls: self.alias_eq_decls(),
             alias_bound_decls: self.alias_bound_decls(),
+            alias_wf_decls: self.alias_wf_decls(),
             adt_decls: self.adt_decls(),
+            fn_decls: self.fn_decls(),
+            trait_fn_decls: self.trait_fn_decls(),
             local_trait_ids: self.local_trait_ids(),
             local_adt_ids: self.local_adt_ids(),
         }
     }
 
-    fn trait_decls(&self) -> Vec<prove::TraitDecl> {
+    pub(crate) fn trait_decls(&self) -> Vec<prove::TraitDecl> {
         self.crates.iter().flat_map(|c| c.trait_decls()).collect()
     }
 
@@ -43,7 +54,7 @@ impl Program {
     fn alias_eq_decls(&self) -> Vec<prove::AliasEqDecl> {
         self.crates
             .iter()
-            .flat_map(|c| c.alias_eq_decls())
+            .flat_map(|c| c.alias_eq_decls(self))
             .collect()
     }
 
@@ -54,8 +65,52 @@ impl Program {
             .collect()
     }
 
+    fn alias_wf_decls(&self) -> Vec<prove::AliasWfDecl> {
+        self.crates
+            .iter()
+            .flat_map(|c| c.alias_wf_decls())
+            .collect()
+    }
+
     fn adt_decls(&self) -> Vec<prove::AdtDecl> {
-        self.crates.iter().flat_map(|c| c.adt_decls()).collect()
+        let adts: Vec<Adt> = self.crates.iter().flat_map(|c| c.adts()).collect();
+        let variances = variance::infer_adt_variances(&adts);
+        adts.into_iter()
+            .map(|Adt { id, binder }| {
+                let (
+                    vars,
+                    AdtBoundData {
+                        where_clauses,
+                        variants,
+                    },
+                ) = binder.open();
+                prove::AdtDecl {
+                    variances: variances[&id].clone(),
+                    id,
+                    binder: Binder::new(
+                        vars,
+                        prove::AdtDeclBoundData {
+                            where_clause: where_clauses.iter().flat_map(|wc| wc.to_wcs()).collect(),
+                            field_tys: variants
+                                .iter()
+                                .flat_map(|v| v.fields.iter().map(|f| f.ty.clone()))
+                                .collect(),
+                        },
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    fn fn_decls(&self) -> Vec<prove::FnDecl> {
+        self.crates.iter().flat_map(|c| c.fn_decls()).collect()
+    }
+
+    fn trait_fn_decls(&self) -> Vec<prove::TraitFnDecl> {
+        self.crates
+            .iter()
+            .flat_map(|c| c.trait_fn_decls())
+            .collect()
     }
 
     fn local_trait_ids(&self) -> Set<TraitId> {
@@ -76,11 +131,18 @@ impl Program {
 }
 
 impl Crate {
-    fn trait_decls(&self) -> Vec<prove::TraitDecl> {
+    pub(crate) fn trait_decls(&self) -> Vec<prove::TraitDecl> {
         self.items
             .iter()
             .flat_map(|item| match item {
-                CrateItem::Trait(Trait { id, binder, safety }) => {
+                CrateItem::Trait(Trait {
+                    id,
+                    binder,
+                    lang_item,
+                    safety,
+                    auto,
+                    coinductive,
+                }) => {
                     let (
                         vars,
                         TraitBoundData {
@@ -89,7 +151,10 @@ impl Crate {
                         },
                     ) = binder.open();
                     Some(prove::TraitDecl {
+                        lang_item: *lang_item,
                         safety: safety.clone(),
+                        auto: *auto,
+                        coinductive: *coinductive,
                         id: id.clone(),
                         binder: Binder::new(
                             vars,
@@ -168,7 +233,11 @@ impl Crate {
             .collect()
     }
 
-    fn alias_eq_decls(&self) -> Vec<prove::AliasEqDecl> {
+    /// Lowers the associated type values given by the impls in this crate. For each associated
+    /// type with a default that an impl does not give a value for, the default is lowered as
+    /// if the impl had given it. Defaults are never lowered for the trait itself, so code within
+    /// the trait (e.g., default fn bodies) cannot assume them, as an impl might override them.
+    fn alias_eq_decls(&self, program: &Program) -> Vec<prove::AliasEqDecl> {
         self.items
             .iter()
             .flat_map(|item| match item {
@@ -183,41 +252,98 @@ impl Crate {
                             impl_items,
                         },
                     ) = binder.open();
+                    let trait_args: Vec<Parameter> =
+                        seq![self_ty.to(), ..trait_parameters.iter().cloned()];
 
-                    Vec::from_iter(impl_items.iter().flat_map(|impl_item| match impl_item {
-                        ImplItem::Fn(_) => None,
-                        ImplItem::AssociatedTyValue(AssociatedTyValue {
-                            id: item_id,
-                            binder,
-                        }) => {
-                            let (
-                                assoc_vars,
-                                AssociatedTyValueBoundData {
-                                    where_clauses: assoc_wc,
+                    let alias_eq_decl = |item_id: &AssociatedItemId,
+                                         assoc_vars: &[BoundVar],
+                                         ty: Ty,
+                                         assoc_wc: &[WhereClause]| {
+                        prove::AliasEqDecl {
+                            binder: Binder::new(
+                                (&impl_vars, assoc_vars),
+                                prove::AliasEqDeclBoundData {
+                                    alias: AliasTy::associated_ty(
+                                        &trait_id,
+                                        item_id,
+                                        assoc_vars.len(),
+                                        seq![
+                                            ..trait_args.iter().cloned(),
+                                            ..assoc_vars.iter().upcasted(),
+                                        ],
+                                    ),
                                     ty,
+                                    where_clause: (&impl_wc, assoc_wc).to_wcs(),
                                 },
-                            ) = binder.open();
-                            Some(prove::AliasEqDecl {
-                                binder: Binder::new(
-                                    (&impl_vars, &assoc_vars),
-                                    prove::AliasEqDeclBoundData {
-                                        alias: AliasTy::associated_ty(
-                                            &trait_id,
-                                            item_id,
-                                            assoc_vars.len(),
-                                            seq![
-                                                self_ty.to(),
-                                                ..trait_parameters.iter().cloned(),
-                                                ..assoc_vars.iter().upcasted(),
-                                            ],
-                                        ),
+                            ),
+                        }
+                    };
+
+                    let mut decls = vec![];
+                    for impl_item in &impl_items {
+                        match impl_item {
+                            ImplItem::Fn(_) => {}
+                            ImplItem::AssociatedTyValue(AssociatedTyValue {
+                                id: item_id,
+                                binder,
+                            }) => {
+                                let (
+                                    assoc_vars,
+                                    AssociatedTyValueBoundData {
+                                        where_clauses: assoc_wc,
                                         ty,
-                                        where_clause: (&impl_wc, assoc_wc).to_wcs(),
                                     },
-                                ),
-                            })
+                                ) = binder.open();
+                                decls.push(alias_eq_decl(item_id, &assoc_vars, ty, &assoc_wc));
+                            }
                         }
-                    }))
+                    }
+
+                    // If the trait is missing or given the wrong number of parameters,
+                    // the impl is rejected by the checker.
+                    let trait_items = match program.trait_named(&trait_id) {
+                        Ok(trait_decl) => match trait_decl.binder.instantiate_with(&trait_args) {
+                            Ok(TraitBoundData {
+                                where_clauses: _,
+                                trait_items,
+                            }) => trait_items,
+                            Err(_) => vec![],
+                        },
+                        Err(_) => vec![],
+                    };
+                    for trait_item in &trait_items {
+                        match trait_item {
+                            TraitItem::Fn(_) => {}
+                            TraitItem::AssociatedTy(AssociatedTy {
+                                id: item_id,
+                                binder,
+                            }) => {
+                                let overridden = impl_items.iter().any(|impl_item| {
+                                    matches!(impl_item, ImplItem::AssociatedTyValue(v) if v.id == *item_id)
+                                });
+                                let (
+                                    assoc_vars,
+                                    AssociatedTyBoundData {
+                                        ensures: _,
+                                        where_clauses: assoc_wc,
+                                        default,
+                                    },
+                                ) = binder.open();
+                                match default {
+                                    MaybeAssociatedTyDefault::DefaultTy(ty) if !overridden => {
+                                        decls.push(alias_eq_decl(
+                                            item_id,
+                                            &assoc_vars,
+                                            ty,
+                                            &assoc_wc,
+                                        ));
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                    }
+                    decls
                 }
                 _ => vec![],
             })
@@ -229,7 +355,10 @@ impl Crate {
             .iter()
             .flat_map(|item| match item {
                 CrateItem::Trait(Trait {
+                    lang_item: _,
                     safety: _,
+                    auto: _,
+                    coinductive: _,
                     id: trait_id,
                     binder,
                 }) => {
@@ -252,6 +381,7 @@ impl Crate {
                                 AssociatedTyBoundData {
                                     ensures,
                                     where_clauses: assoc_wc,
+                                    default: _,
                                 },
                             ) = binder.open();
                             let alias = AliasTy::associated_ty(
@@ -273,7 +403,14 @@ impl Crate {
                                             prove::AliasBoundDeclBoundData {
                                                 alias: alias.clone(),
                                                 ensures,
-                                                where_clause: (&trait_wc, &assoc_wc).to_wcs(),
+                                                // The bounds only hold for aliases of
+                                                // implemented trait-refs.
+                                                where_clause: (
+                                                    alias.trait_ref(),
+                                                    &trait_wc,
+                                                    &assoc_wc,
+                                                )
+                                                    .to_wcs(),
                                             },
                                         ),
                                     }
@@ -287,7 +424,64 @@ impl Crate {
             .collect()
     }
 
-    fn adt_decls(&self) -> Vec<prove::AdtDecl> {
+    fn alias_wf_decls(&self) -> Vec<prove::AliasWfDecl> {
+        self.items
+            .iter()
+            .flat_map(|item| match item {
+                CrateItem::Trait(Trait {
+                    lang_item: _,
+                    safety: _,
+                    auto: _,
+                    coinductive: _,
+                    id: trait_id,
+                    binder,
+                }) => {
+                    let (
+                        trait_vars,
+                        TraitBoundData {
+                            where_clauses: _,
+                            trait_items,
+                        },
+                    ) = binder.open();
+
+                    Vec::from_iter(trait_items.iter().flat_map(|trait_item| match trait_item {
+                        TraitItem::Fn(_) => None,
+                        TraitItem::AssociatedTy(AssociatedTy {
+                            id: item_id,
+                            binder,
+                        }) => {
+                            let (
+                                assoc_vars,
+                                AssociatedTyBoundData {
+                                    ensures: _,
+                                    where_clauses: assoc_wc,
+                                    default: _,
+                                },
+                            ) = binder.open();
+                            let alias = AliasTy::associated_ty(
+                                trait_id,
+                                item_id,
+                                assoc_vars.len(),
+                                (&trait_vars, &assoc_vars),
+                            );
+                            Some(prove::AliasWfDecl {
+                                binder: Binder::new(
+                                    (&trait_vars, &assoc_vars),
+                                    prove::AliasWfDeclBoundData {
+                                        alias,
+                                        where_clause: assoc_wc.to_wcs(),
+                                    },
+                                ),
+                            })
+                        }
+                    }))
+                }
+                _ => vec![],
+            })
+            .collect()
+    }
+
+    fn adts(&self) -> Vec<Adt> {
         self.items
             .iter()
             .flat_map(|item| match item {
@@ -295,23 +489,91 @@ impl Crate {
                 CrateItem::Enum(e) => Some(e.to_adt()),
                 _ => None,
             })
-            .map(|Adt { id, binder }| {
-                let (
-                    vars,
-                    AdtBoundData {
-                        where_clauses,
-                        variants: _,
-                    },
-                ) = binder.open();
-                prove::AdtDecl {
-                    id: id.clone(),
-                    binder: Binder::new(
+            .collect()
+    }
+
+    fn fn_decls(&self) -> Vec<prove::FnDecl> {
+        self.items
+            .iter()
+            .flat_map(|item| match item {
+                CrateItem::Fn(Fn { id, binder }) => {
+                    let (
                         vars,
-                        prove::AdtDeclBoundData {
-                            where_clause: where_clauses.iter().flat_map(|wc| wc.to_wcs()).collect(),
+                        FnBoundData {
+                            input_tys,
+                            output_ty,
+                            where_clauses,
+                            body: _,
                         },
-                    ),
+                    ) = binder.open();
+                    Some(prove::FnDecl {
+                        id: id.clone(),
+                        binder: Binder::new(
+                            vars,
+                            prove::FnDeclBoundData {
+                                input_tys,
+                                output_ty,
+                                where_clause: where_clauses.to_wcs(),
+                            },
+                        ),
+                    })
                 }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn trait_fn_decls(&self) -> Vec<prove::TraitFnDecl> {
+        self.items
+            .iter()
+            .flat_map(|item| match item {
+                CrateItem::Trait(Trait {
+                    lang_item: _,
+                    safety: _,
+                    auto: _,
+                    coinductive: _,
+                    id: trait_id,
+                    binder,
+                }) => {
+                    let (
+                        trait_vars,
+                        TraitBoundData {
+                            where_clauses: _,
+                            trait_items,
+                        },
+                    ) = binder.open();
+
+                    Vec::from_iter(trait_items.iter().flat_map(|trait_item| match trait_item {
+                        TraitItem::Fn(Fn { id, binder }) => {
+                            let (
+                                fn_vars,
+                                FnBoundData {
+                                    input_tys,
+                                    output_ty,
+                                    where_clauses,
+                                    body: _,
+                                },
+                            ) = binder.open();
+                            Some(prove::TraitFnDecl {
+                                trait_id: trait_id.clone(),
+                                id: id.clone(),
+                                binder: Binder::new(
+                                    &trait_vars,
+                                    Binder::new(
+                                        fn_vars,
+                                        prove::FnDeclBoundData {
+                                            input_tys,
+                                            output_ty,
+                                            where_clause: where_clauses.to_wcs(),
+                                        },
+                                    ),
+                                ),
+                            })
+                        }
+                        TraitItem::AssociatedTy(_) => None,
+                    }))
+                }
+                _ => vec![],
             })
             .collect()
     }
@@ -325,6 +587,7 @@ impl Crate {
                 CrateItem::Trait(_) => None,
                 CrateItem::TraitImpl(_) => None,
                 CrateItem::NegTraitImpl(_) => None,
+                CrateItem::InherentImpl(_) => None,
                 CrateItem::Fn(_) => None,
                 CrateItem::Test(_) => None,
             })
@@ -359,6 +622,7 @@ upcast_to_wcs! {
     Wcs,
     Predicate,
     Relation,
+    TraitRef,
 }
 
 impl ToWcs for () {
@@ -426,6 +690,7 @@ impl ToWcs for WhereClause {
             WhereClauseData::TypeOfConst(ct, ty) => {
                 Predicate::ConstHasType(ct.clone(), ty.clone()).upcast()
             }
+            WhereClauseData::MaybeImplemented(_, _) => Wcs::t(),
         }
     }
 }
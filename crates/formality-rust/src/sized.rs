@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use formality_prove::{Decls, LangItem};
+use formality_types::{
+    grammar::{Binder, BoundVar, ParameterKind, TraitId, Ty},
+    rust::Term,
+};
+
+use crate::grammar::{
+    AssociatedTy, AssociatedTyBoundData, AssociatedTyValue, AssociatedTyValueBoundData, Crate,
+    CrateItem, Enum, Fn, FnBoundData, ImplItem, InherentImpl, InherentImplBoundData, NegTraitImpl,
+    NegTraitImplBoundData, Program, Struct, StructBoundData, Test, TestBoundData, Trait,
+    TraitBinder, TraitBoundData, TraitImpl, TraitImplBoundData, TraitItem, WhereClause,
+    WhereClauseData,
+};
+
+impl Program {
+    /// Returns the program with an explicit `T: Sized` where-clause for each type
+    /// parameter `T`, as Rust adds implicitly, unless the parameter is relaxed with
+    /// `T: ?Sized`. The `Self` parameter of traits gets no such bound.
+    ///
+    /// Programs that do not declare the `Sized` lang item are returned unchanged.
+    /// Adding the bounds is idempotent.
+    pub fn with_implicit_sized_bounds(&self) -> Program {
+        let trait_decls = Decls {
+            trait_decls: self.trait_decls(),
+            ..Decls::empty()
+        };
+        let Some(sized) = trait_decls.lang_item_trait(LangItem::Sized) else {
+            return self.clone();
+        };
+        let bounds = ImplicitSizedBounds { sized };
+        Program {
+            crates: self
+                .crates
+                .iter()
+                .map(|Crate { id, items }| Crate {
+                    id: id.clone(),
+                    items: items.iter().map(|item| bounds.crate_item(item)).collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+struct ImplicitSizedBounds {
+    sized: TraitId,
+}
+
+impl ImplicitSizedBounds {
+    fn crate_item(&self, item: &CrateItem) -> CrateItem {
+        match item {
+            CrateItem::Struct(Struct { id, binder }) => CrateItem::Struct(Struct {
+                id: id.clone(),
+                binder: self.binder(binder, 0, |data: &mut StructBoundData| {
+                    &mut data.where_clauses
+                }),
+            }),
+            CrateItem::Enum(Enum { id, binder }) => CrateItem::Enum(Enum {
+                id: id.clone(),
+                binder: self.binder(binder, 0, |data| &mut data.where_clauses),
+            }),
+            CrateItem::Trait(t) => {
+                let binder =
+                    self.binder(&t.binder.explicit_binder, 1, |data: &mut TraitBoundData| {
+                        data.trait_items = data
+                            .trait_items
+                            .iter()
+                            .map(|item| self.trait_item(item))
+                            .collect();
+                        &mut data.where_clauses
+                    });
+                CrateItem::Trait(Trait {
+                    binder: TraitBinder {
+                        explicit_binder: binder,
+                    },
+                    ..t.clone()
+                })
+            }
+            CrateItem::TraitImpl(TraitImpl { safety, binder }) => CrateItem::TraitImpl(TraitImpl {
+                safety: safety.clone(),
+                binder: self.binder(binder, 0, |data: &mut TraitImplBoundData| {
+                    data.impl_items = data
+                        .impl_items
+                        .iter()
+                        .map(|item| self.impl_item(item))
+                        .collect();
+                    &mut data.where_clauses
+                }),
+            }),
+            CrateItem::NegTraitImpl(NegTraitImpl { safety, binder }) => {
+                CrateItem::NegTraitImpl(NegTraitImpl {
+                    safety: safety.clone(),
+                    binder: self.binder(binder, 0, |data: &mut NegTraitImplBoundData| {
+                        &mut data.where_clauses
+                    }),
+                })
+            }
+            CrateItem::InherentImpl(InherentImpl { binder }) => {
+                CrateItem::InherentImpl(InherentImpl {
+                    binder: self.binder(binder, 0, |data: &mut InherentImplBoundData| {
+                        data.impl_items = data
+                            .impl_items
+                            .iter()
+                            .map(|item| self.impl_item(item))
+                            .collect();
+                        &mut data.where_clauses
+                    }),
+                })
+            }
+            CrateItem::Fn(f) => CrateItem::Fn(self.function(f)),
+            CrateItem::Test(Test { binder }) => CrateItem::Test(Test {
+                binder: self.binder(binder, 0, |data: &mut TestBoundData| &mut data.assumptions),
+            }),
+        }
+    }
+
+    fn trait_item(&self, item: &TraitItem) -> TraitItem {
+        match item {
+            TraitItem::Fn(f) => TraitItem::Fn(self.function(f)),
+            TraitItem::AssociatedTy(AssociatedTy { id, binder }) => {
+                TraitItem::AssociatedTy(AssociatedTy {
+                    id: id.clone(),
+                    binder: self.binder(binder, 0, |data: &mut AssociatedTyBoundData| {
+                        &mut data.where_clauses
+                    }),
+                })
+            }
+        }
+    }
+
+    fn impl_item(&self, item: &ImplItem) -> ImplItem {
+        match item {
+            ImplItem::Fn(f) => ImplItem::Fn(self.function(f)),
+            ImplItem::AssociatedTyValue(AssociatedTyValue { id, binder }) => {
+                ImplItem::AssociatedTyValue(AssociatedTyValue {
+                    id: id.clone(),
+                    binder: self.binder(binder, 0, |data: &mut AssociatedTyValueBoundData| {
+                        &mut data.where_clauses
+                    }),
+                })
+            }
+        }
+    }
+
+    fn function(&self, Fn { id, binder }: &Fn) -> Fn {
+        Fn {
+            id: id.clone(),
+            binder: self.binder(binder, 0, |data: &mut FnBoundData| &mut data.where_clauses),
+        }
+    }
+
+    /// Adds the implicit bounds for the type parameters of `binder`, except the first `skip` ones,
+    /// to the where-clauses selected by `where_clauses` (which may also transform nested items).
+    fn binder<T: Term>(
+        &self,
+        binder: &Binder<T>,
+        skip: usize,
+        where_clauses: impl FnOnce(&mut T) -> &mut Vec<WhereClause>,
+    ) -> Binder<T> {
+        let (vars, mut data) = binder.open();
+        let clauses = where_clauses(&mut data);
+        for var in vars.iter().skip(skip) {
+            if let Some(bound) = self.implicit_bound(var, clauses) {
+                clauses.push(bound);
+            }
+        }
+        Binder::new(vars, data)
+    }
+
+    fn implicit_bound(&self, var: &BoundVar, clauses: &[WhereClause]) -> Option<WhereClause> {
+        if var.kind != ParameterKind::Ty {
+            return None;
+        }
+        let ty: Ty = Ty::new(*var);
+        let bound = WhereClauseData::IsImplemented(ty.clone(), self.sized.clone(), vec![]);
+        let relaxed = WhereClauseData::MaybeImplemented(ty, self.sized.clone());
+        if clauses
+            .iter()
+            .any(|wc| *wc.data() == bound || *wc.data() == relaxed)
+        {
+            return None;
+        }
+        Some(WhereClause {
+            data: Arc::new(bound),
+        })
+    }
+}
@@ -83,7 +83,10 @@ fn test_parse_rust_like_trait_syntax() {
                     items: [
                         Trait(
                             Trait {
+                                lang_item: None,
                                 safety: Safe,
+                                auto: No,
+                                coinductive: No,
                                 id: Foo,
                                 binder: <ty, ty> where ^ty0_1 : Bar <^ty0_0> { },
                             },
@@ -0,0 +1,138 @@
+use formality_core::{visit::CoreVisit, Map};
+use formality_types::grammar::{
+    AdtId, BoundVar, LtData, Parameter, RigidName, RigidTy, TyData, Variable, Variance,
+};
+
+use crate::grammar::{Adt, AdtBoundData};
+
+/// Infers the variance of each generic parameter of `adts` from the types of their fields.
+///
+/// Each parameter starts out bivariant (`None`), i.e., unconstrained, and becomes more
+/// constrained as we find the positions where it appears. Since ADTs may refer to one
+/// another (or themselves), we iterate until we reach a fixed point. Parameters that
+/// never appear in a field are treated as invariant.
+pub(crate) fn infer_adt_variances(adts: &[Adt]) -> Map<AdtId, Vec<Variance>> {
+    let mut inference = VarianceInference {
+        variances: adts
+            .iter()
+            .map(|adt| (adt.id.clone(), vec![None; adt.binder.len()]))
+            .collect(),
+    };
+
+    loop {
+        let mut changed = false;
+        for adt in adts {
+            let variances = inference.adt_variances(adt);
+            if inference.variances[&adt.id] != variances {
+                inference.variances.insert(adt.id.clone(), variances);
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    inference
+        .variances
+        .into_iter()
+        .map(|(id, variances)| {
+            let variances = variances
+                .into_iter()
+                .map(|v| v.unwrap_or(Variance::Invariant))
+                .collect();
+            (id, variances)
+        })
+        .collect()
+}
+
+struct VarianceInference {
+    variances: Map<AdtId, Vec<Option<Variance>>>,
+}
+
+impl VarianceInference {
+    /// Computes the variances of `adt` given the current variances of all ADTs.
+    fn adt_variances(&self, adt: &Adt) -> Vec<Option<Variance>> {
+        let (vars, AdtBoundData { variants, .. }) = adt.binder.open();
+        let mut variances = vec![None; vars.len()];
+        for variant in &variants {
+            for field in &variant.fields {
+                self.add_parameter(
+                    &vars,
+                    Variance::Covariant,
+                    &field.ty.to_parameter(),
+                    &mut variances,
+                );
+            }
+        }
+        variances
+    }
+
+    /// Records that `parameter` appears in a position with variance `ambient`.
+    fn add_parameter(
+        &self,
+        vars: &[BoundVar],
+        ambient: Variance,
+        parameter: &Parameter,
+        variances: &mut [Option<Variance>],
+    ) {
+        match parameter {
+            Parameter::Ty(ty) => match ty.data() {
+                TyData::Variable(v) => Self::add_variable(vars, ambient, v, variances),
+                TyData::RigidTy(RigidTy { name, parameters }) => {
+                    let name_variances: Vec<Option<Variance>> = match name {
+                        RigidName::AdtId(adt_id) => match self.variances.get(adt_id) {
+                            Some(v) => v.clone(),
+                            None => vec![Some(Variance::Invariant); parameters.len()],
+                        },
+                        _ => name
+                            .builtin_variances(parameters.len())
+                            .unwrap()
+                            .into_iter()
+                            .map(Some)
+                            .collect(),
+                    };
+                    for (v, p) in name_variances.into_iter().zip(parameters) {
+                        // A parameter of a bivariant position is unconstrained.
+                        if let Some(v) = v {
+                            self.add_parameter(vars, ambient.xform(v), p, variances);
+                        }
+                    }
+                }
+                TyData::AliasTy(_) | TyData::PredicateTy(_) => {
+                    Self::add_invariant(vars, parameter, variances)
+                }
+            },
+            Parameter::Lt(lt) => match lt.data() {
+                LtData::Variable(v) => Self::add_variable(vars, ambient, v, variances),
+                LtData::Static => {}
+            },
+            Parameter::Const(_) => Self::add_invariant(vars, parameter, variances),
+        }
+    }
+
+    /// Records that all variables in `parameter` appear in invariant positions.
+    fn add_invariant(vars: &[BoundVar], parameter: &Parameter, variances: &mut [Option<Variance>]) {
+        for v in parameter.free_variables() {
+            Self::add_variable(vars, Variance::Invariant, &v, variances);
+        }
+    }
+
+    fn add_variable(
+        vars: &[BoundVar],
+        ambient: Variance,
+        v: &Variable,
+        variances: &mut [Option<Variance>],
+    ) {
+        let Variable::BoundVar(bv) = v else {
+            return;
+        };
+        if let Some(index) = vars.iter().position(|var| var == bv) {
+            variances[index] = Some(match variances[index] {
+                None => ambient,
+                Some(v) => v.meet(ambient),
+            });
+        }
+    }
+}
@@ -43,11 +43,21 @@ pub enum Predicate {
 
     #[grammar(@ConstHasType($v0, $v1))]
     ConstHasType(Const, Ty),
+
+    /// True if the trait can be the principal trait of a trait object type `dyn Trait`.
+    #[grammar(@DynCompatible($v0))]
+    DynCompatible(TraitId),
 }
 
 /// A coinductive predicate is one that can be proven via a cycle.
+/// Traits are inductive unless declared as `coinductive trait`.
+#[term]
+#[derive(Copy, Default)]
 pub enum Coinductive {
+    #[default]
+    #[grammar(inductive)]
     No,
+    #[grammar(coinductive)]
     Yes,
 }
 
@@ -97,6 +107,7 @@ pub enum Skeleton {
     WellFormedTraitRef(TraitId),
     IsLocal(TraitId),
     ConstHasType,
+    DynCompatible(TraitId),
 
     Equals,
     Sub,
@@ -143,6 +154,9 @@ impl Predicate {
                 Skeleton::ConstHasType,
                 vec![ct.clone().upcast(), ty.clone().upcast()],
             ),
+            Predicate::DynCompatible(trait_id) => {
+                (Skeleton::DynCompatible(trait_id.clone()), vec![])
+            }
         }
     }
 }
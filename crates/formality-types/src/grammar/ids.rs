@@ -1,8 +1,11 @@
 use formality_core::id;
 
 id!(FnId);
+id!(ClosureId);
 id!(AdtId);
-id!(TraitId);
+// So that `T: a` is an outlives bound when `a` is a lifetime variable,
+// rather than a bound naming a trait `a`.
+id!(TraitId, reject_variables);
 id!(AssociatedItemId);
 id!(CrateId);
 id!(FieldId);
@@ -7,8 +7,8 @@ mod term_impls;
 use formality_core::{DowncastTo, To, Upcast, UpcastFrom};
 
 use super::{
-    consts::Const, AdtId, AssociatedItemId, Binder, BoundVar, ExistentialVar, FnId, TraitId,
-    UniversalVar, Variable,
+    consts::Const, AdtId, AssociatedItemId, Binder, BoundVar, ClosureId, ExistentialVar, FnId,
+    TraitId, TraitRef, UniversalVar, Variable,
 };
 
 #[term]
@@ -155,6 +155,125 @@ pub enum RigidName {
     Tuple(usize),
     FnPtr(usize),
     FnDef(FnId),
+
+    /// The type of the closure `ClosureId` that takes `usize` arguments and is callable
+    /// through the trait for its [`ClosureKind`]. Its parameters are the argument types,
+    /// then the return type, then the types of the captured upvars.
+    Closure(ClosureId, ClosureKind, usize),
+
+    /// A trait object type `dyn Trait<..> + 'a`; see [`DynName`] for its parameters.
+    #[cast]
+    DynTrait(DynName),
+}
+
+/// The name of a trait object type like `dyn Iterator<Item = u32> + Send + 'a`.
+/// Its parameters are those of the principal trait (other than `Self`), then the values
+/// of the associated types named in `projections`, in order, then the lifetime bound.
+#[term(($trait_id $[projections] $[auto_traits]))]
+pub struct DynName {
+    /// The principal trait, which the trait object implements through its vtable.
+    pub trait_id: TraitId,
+
+    /// The associated types of the principal trait (or its supertraits) whose values
+    /// are given in the type, like `Item` in `dyn Iterator<Item = u32>`.
+    pub projections: Vec<AssociatedItemId>,
+
+    /// The auto traits that the trait object also implements, like `Send`.
+    pub auto_traits: Vec<TraitId>,
+}
+
+impl DynName {
+    /// Splits the parameters of a trait object type named `self` into those of the
+    /// principal trait, the values of the projections, and the lifetime bound.
+    pub fn split_parameters<'p>(
+        &self,
+        parameters: &'p [Parameter],
+    ) -> (&'p [Parameter], &'p [Parameter], &'p Parameter) {
+        let (lifetime, parameters) = parameters.split_last().unwrap();
+        let (trait_parameters, values) =
+            parameters.split_at(parameters.len() - self.projections.len());
+        (trait_parameters, values, lifetime)
+    }
+
+    /// The principal trait-ref of the trait object `self_ty`, whose name is `self`,
+    /// e.g., `dyn Foo<u32> + 'a: Foo<u32>`.
+    pub fn principal_trait_ref(&self, self_ty: &RigidTy) -> TraitRef {
+        let (trait_parameters, _, _) = self.split_parameters(&self_ty.parameters);
+        self.trait_id.with(self_ty, trait_parameters.to_vec())
+    }
+}
+
+/// The most permissive of the `Fn`, `FnMut` and `FnOnce` traits that a closure implements.
+/// A closure implements the trait for its kind and all traits that come later in this list.
+#[term]
+#[derive(Copy)]
+pub enum ClosureKind {
+    #[grammar(fn)]
+    Fn,
+    #[grammar(fn_mut)]
+    FnMut,
+    #[grammar(fn_once)]
+    FnOnce,
+}
+
+impl RigidName {
+    /// The variance of each of the `arity` parameters of a built-in rigid type,
+    /// or `None` for ADTs, whose variance is inferred from their fields.
+    pub fn builtin_variances(&self, arity: usize) -> Option<Vec<Variance>> {
+        match self {
+            // `&'a T` is covariant in both `'a` and `T`.
+            RigidName::Ref(RefKind::Shared) => Some(vec![Variance::Covariant; arity]),
+
+            // `&'a mut T` is covariant in `'a` but invariant in `T`.
+            RigidName::Ref(RefKind::Mut) => Some(
+                (0..arity)
+                    .map(|i| {
+                        if i == 0 {
+                            Variance::Covariant
+                        } else {
+                            Variance::Invariant
+                        }
+                    })
+                    .collect(),
+            ),
+
+            RigidName::Tuple(_) => Some(vec![Variance::Covariant; arity]),
+
+            // Function pointers are contravariant in their inputs and covariant in
+            // their output, which comes last.
+            RigidName::FnPtr(_) => Some(
+                (0..arity)
+                    .map(|i| {
+                        if i + 1 == arity {
+                            Variance::Covariant
+                        } else {
+                            Variance::Contravariant
+                        }
+                    })
+                    .collect(),
+            ),
+
+            RigidName::ScalarId(_) | RigidName::FnDef(_) | RigidName::Closure(..) => {
+                Some(vec![Variance::Invariant; arity])
+            }
+
+            // `dyn Trait<..> + 'a` is covariant in `'a`, which comes last, and invariant
+            // in everything else.
+            RigidName::DynTrait(_) => Some(
+                (0..arity)
+                    .map(|i| {
+                        if i + 1 == arity {
+                            Variance::Covariant
+                        } else {
+                            Variance::Invariant
+                        }
+                    })
+                    .collect(),
+            ),
+
+            RigidName::AdtId(_) => None,
+        }
+    }
 }
 
 #[term]
@@ -215,6 +334,19 @@ impl AliasTy {
             parameters,
         }
     }
+
+    /// The trait-ref that must be implemented for this alias to be well-formed,
+    /// e.g., `T: Iterator` for `<T as Iterator>::Item`. Its parameters are those of
+    /// the alias, minus those of the associated type itself.
+    pub fn trait_ref(&self) -> TraitRef {
+        let AliasName::AssociatedTyId(AssociatedTyName {
+            trait_id,
+            item_id: _,
+            item_arity,
+        }) = &self.name;
+        let trait_arity = self.parameters.len() - item_arity;
+        TraitRef::new(trait_id, self.parameters[..trait_arity].to_vec())
+    }
 }
 
 #[term]
@@ -293,6 +425,31 @@ pub enum Variance {
     Invariant,
 }
 
+impl Variance {
+    /// The variance of a position with variance `inner` that appears
+    /// within a position with variance `self`: e.g., the `T` in `fn(&T)` is
+    /// contravariant, as `T` is covariant within `&T`, which is contravariant within `fn(_)`.
+    pub fn xform(self, inner: Variance) -> Variance {
+        match (self, inner) {
+            (Variance::Covariant, v) => v,
+            (Variance::Contravariant, Variance::Covariant) => Variance::Contravariant,
+            (Variance::Contravariant, Variance::Contravariant) => Variance::Covariant,
+            (Variance::Contravariant, Variance::Invariant) => Variance::Invariant,
+            (Variance::Invariant, _) => Variance::Invariant,
+        }
+    }
+
+    /// The variance of a parameter that appears both in positions of variance
+    /// `self` and in positions of variance `other`.
+    pub fn meet(self, other: Variance) -> Variance {
+        if self == other {
+            self
+        } else {
+            Variance::Invariant
+        }
+    }
+}
+
 #[term]
 #[cast]
 #[customize(constructors)] // FIXME: figure out upcasts with arc or special-case
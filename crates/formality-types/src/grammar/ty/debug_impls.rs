@@ -1,4 +1,6 @@
-use super::{AliasName, AliasTy, AssociatedTyName, Parameter, RefKind, RigidName, RigidTy};
+use super::{
+    AliasName, AliasTy, AssociatedTyName, DynName, Parameter, RefKind, RigidName, RigidTy,
+};
 use std::fmt::Debug;
 
 // ANCHOR: RigidTy_impl
@@ -32,6 +34,22 @@ impl Debug for RigidTy {
                     write!(f, "()")
                 }
             }
+            RigidName::FnPtr(arity) if parameters.len() == *arity + 1 => {
+                write!(f, "fn")?;
+                write_signature(f, &parameters[..=*arity])
+            }
+            RigidName::Closure(id, kind, arity) if parameters.len() > *arity => {
+                write!(f, "closure {:?}: {:?}", id, kind)?;
+                write_signature(f, &parameters[..=*arity])?;
+                let upvars = &parameters[*arity + 1..];
+                if !upvars.is_empty() {
+                    write!(f, " {:?}", PrettyParameters::new("[", "]", upvars))?;
+                }
+                Ok(())
+            }
+            RigidName::DynTrait(dyn_name) if parameters.len() > dyn_name.projections.len() => {
+                write_dyn(f, dyn_name, parameters)
+            }
             _ => {
                 write!(f, "{:?}{:?}", name, PrettyParameters::angle(parameters))
             }
@@ -40,6 +58,51 @@ impl Debug for RigidTy {
 }
 // ANCHOR_END: RigidTy_impl
 
+/// Writes `(A, B) -> R`, given the argument types followed by the return type.
+fn write_signature(f: &mut std::fmt::Formatter<'_>, tys: &[Parameter]) -> std::fmt::Result {
+    let (output, inputs) = tys.split_last().unwrap();
+    write!(f, "(")?;
+    for (i, input) in inputs.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{:?}", input)?;
+    }
+    write!(f, ") -> {:?}", output)
+}
+
+/// Writes `dyn Trait<A, Item = B> + Send + 'a`.
+fn write_dyn(
+    f: &mut std::fmt::Formatter<'_>,
+    dyn_name: &DynName,
+    parameters: &[Parameter],
+) -> std::fmt::Result {
+    let DynName {
+        trait_id,
+        projections,
+        auto_traits,
+    } = dyn_name;
+    let (trait_parameters, values, lifetime) = dyn_name.split_parameters(parameters);
+    write!(f, "dyn {:?}", trait_id)?;
+    let arguments: Vec<String> = trait_parameters
+        .iter()
+        .map(|p| format!("{:?}", p))
+        .chain(
+            projections
+                .iter()
+                .zip(values)
+                .map(|(id, value)| format!("{:?} = {:?}", id, value)),
+        )
+        .collect();
+    if !arguments.is_empty() {
+        write!(f, "<{}>", arguments.join(", "))?;
+    }
+    for auto_trait in auto_traits {
+        write!(f, " + {:?}", auto_trait)?;
+    }
+    write!(f, " + {:?}", lifetime)
+}
+
 impl Debug for AliasTy {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let AliasTy { name, parameters } = self;
@@ -3,11 +3,12 @@
 use formality_core::parse::{
     ActiveVariant, CoreParse, ParseError, ParseResult, Parser, Precedence, Scope,
 };
-use formality_core::Upcast;
 use formality_core::{seq, Set};
+use formality_core::{Upcast, Upcasted};
 
 use crate::grammar::{
-    AdtId, AssociatedItemId, Bool, ConstData, RefKind, RigidName, Scalar, TraitId,
+    AdtId, AssociatedItemId, Bool, ClosureId, ClosureKind, ConstData, DynName, RefKind, RigidName,
+    Scalar, TraitId,
 };
 
 use super::{AliasTy, AssociatedTyName, Lt, Parameter, ParameterKind, RigidTy, ScalarId, Ty};
@@ -28,6 +29,7 @@ impl CoreParse<Rust> for RigidTy {
             parser.parse_variant("Adt", Precedence::default(), |p| {
                 // Don't accept scalar-ids as Adt names.
                 p.reject_nonterminal::<ScalarId>()?;
+                p.reject_custom_keywords(&["fn", "closure", "dyn"])?;
 
                 let name: AdtId = p.nonterminal()?;
                 let parameters: Vec<Parameter> = parse_parameters(p)?;
@@ -70,6 +72,60 @@ impl CoreParse<Rust> for RigidTy {
                     parameters: types.upcast(),
                 })
             });
+
+            // Parse `fn(A, B) -> R`
+            parser.parse_variant("FnPtr", Precedence::default(), |p| {
+                p.expect_keyword("fn")?;
+                let (inputs, output) = parse_signature(p)?;
+                Ok(RigidTy {
+                    name: RigidName::FnPtr(inputs.len()),
+                    parameters: inputs.into_iter().chain(Some(output)).upcasted().collect(),
+                })
+            });
+
+            // Parse `closure C: fn_mut(A, B) -> R [U1, U2]`, where the `U`s are the upvar types
+            parser.parse_variant("Closure", Precedence::default(), |p| {
+                p.expect_keyword("closure")?;
+                let id: ClosureId = p.nonterminal()?;
+                p.expect_char(':')?;
+                let kind: ClosureKind = p.nonterminal()?;
+                let (inputs, output) = parse_signature(p)?;
+                let upvars: Vec<Ty> = p.delimited_nonterminal('[', true, ']')?;
+                Ok(RigidTy {
+                    name: RigidName::Closure(id, kind, inputs.len()),
+                    parameters: inputs
+                        .into_iter()
+                        .chain(Some(output))
+                        .chain(upvars)
+                        .upcasted()
+                        .collect(),
+                })
+            });
+
+            // Parse `dyn Trait<A, Item = B> + Send + 'a`, where the lifetime bound is required
+            parser.parse_variant("Dyn", Precedence::default(), |p| {
+                p.expect_keyword("dyn")?;
+                let trait_id: TraitId = p.nonterminal()?;
+                let (mut parameters, projections) = parse_dyn_arguments(p)?;
+                let mut auto_traits = vec![];
+                let lt: Lt = loop {
+                    p.expect_char('+')?;
+                    if let Some(lt) = p.opt_nonterminal::<Lt>()? {
+                        break lt;
+                    }
+                    auto_traits.push(p.nonterminal::<TraitId>()?);
+                };
+                parameters.push(lt.upcast());
+                Ok(RigidTy {
+                    name: DynName {
+                        trait_id,
+                        projections,
+                        auto_traits,
+                    }
+                    .upcast(),
+                    parameters,
+                })
+            });
         })
     }
 }
@@ -107,6 +163,57 @@ impl CoreParse<Rust> for AliasTy {
     }
 }
 
+/// Parses `(A, B) -> R` into the argument types and the return type.
+fn parse_signature<'t>(
+    p: &mut ActiveVariant<'_, 't, Rust>,
+) -> Result<(Vec<Ty>, Ty), Set<ParseError<'t>>> {
+    let inputs: Vec<Ty> = p.delimited_nonterminal('(', false, ')')?;
+    p.expect_char('-')?;
+    p.expect_char('>')?;
+    let output: Ty = p.nonterminal()?;
+    Ok((inputs, output))
+}
+
+/// Parses the optional `<A, Item = B>` after the trait of a `dyn` type into the trait's
+/// parameters followed by the values of the projections, and the names of the projections.
+fn parse_dyn_arguments<'t>(
+    p: &mut ActiveVariant<'_, 't, Rust>,
+) -> Result<(Vec<Parameter>, Vec<AssociatedItemId>), Set<ParseError<'t>>> {
+    let mut parameters = vec![];
+    let mut projections = vec![];
+    let mut values = vec![];
+    if p.expect_char('<').is_err() {
+        return Ok((parameters, projections));
+    }
+    loop {
+        if starts_with_projection(p.text()) {
+            projections.push(p.nonterminal::<AssociatedItemId>()?);
+            p.expect_char('=')?;
+            values.push(p.nonterminal::<Ty>()?.upcast());
+        } else if let Some(parameter) = p.opt_nonterminal::<Parameter>()? {
+            parameters.push(parameter);
+        } else {
+            break;
+        }
+        if p.expect_char(',').is_err() {
+            break;
+        }
+    }
+    p.expect_char('>')?;
+    parameters.extend(values);
+    Ok((parameters, projections))
+}
+
+/// True if `text` begins with `Id =` (but not `Id =>`).
+fn starts_with_projection(text: &str) -> bool {
+    let text = text.trim_start();
+    let id_len = text
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(text.len());
+    let rest = text[id_len..].trim_start();
+    id_len > 0 && rest.starts_with('=') && !rest.starts_with("=>")
+}
+
 fn parse_parameters<'t>(
     p: &mut ActiveVariant<'_, 't, Rust>,
 ) -> Result<Vec<Parameter>, Set<ParseError<'t>>> {
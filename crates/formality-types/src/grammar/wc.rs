@@ -17,6 +17,10 @@ impl Wcs {
         set![].upcast()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+
     /// Goal(s) to prove `a` and `b` are equal (they must have equal length)
     pub fn all_eq(a: impl Upcast<Vec<Parameter>>, b: impl Upcast<Vec<Parameter>>) -> Wcs {
         let a: Vec<Parameter> = a.upcast();
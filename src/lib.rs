@@ -8,14 +8,17 @@ extern crate rustc_interface;
 extern crate rustc_middle;
 extern crate stable_mir;
 
-use std::{path::PathBuf, sync::Arc};
+use std::{any::TypeId, path::PathBuf, sync::Arc};
 
+use anyhow::Context;
 use clap::Parser;
-use formality_check::check_all_crates;
+use formality_check::{
+    check_all_crates, check_all_crates_collecting_errors, check_all_crates_with_spans,
+};
 use formality_core::Set;
 use formality_prove::{test_util::TestAssertion, Constraints};
-use formality_rust::grammar::Program;
-use formality_types::rust::try_term;
+use formality_rust::grammar::{CrateItem, Program, WhereClause};
+use formality_types::rust::{try_term, try_term_with_spans};
 
 #[cfg(test)]
 mod test;
@@ -38,19 +41,43 @@ struct Args {
     #[arg(long)]
     out_dir: Option<PathBuf>,
 
+    #[arg(long)]
+    all_errors: bool,
+
     input_path: String,
 }
 
+/// The types of terms whose locations errors report.
+fn spanned_types() -> [TypeId; 2] {
+    [TypeId::of::<CrateItem>(), TypeId::of::<WhereClause>()]
+}
+
 pub fn main() -> anyhow::Result<()> {
     let args = Args::parse();
     let input: String = std::fs::read_to_string(&args.input_path)?;
-    let program: Program = try_term(&input)?;
+    let (program, spans) = try_term_with_spans(&input, &spanned_types())?;
 
     if args.print_rust {
         eprintln!("{:#?}", program);
     }
 
-    check_all_crates(&program)
+    if args.all_errors {
+        let errors = check_all_crates_collecting_errors(&program, &spans);
+        for error in &errors {
+            eprintln!("Error: {error:?}\n");
+        }
+        if !errors.is_empty() {
+            anyhow::bail!(
+                "failed to check `{}` due to {} error(s)",
+                args.input_path,
+                errors.len()
+            );
+        }
+        return Ok(());
+    }
+
+    check_all_crates_with_spans(&program, &spans)
+        .with_context(|| format!("failed to check `{}`", args.input_path))
 }
 
 #[macro_export]
@@ -74,12 +101,25 @@ pub fn test_program_ok(input: &str) -> anyhow::Result<()> {
     check_all_crates(&program)
 }
 
+/// Checks the program in `input`, returning all the errors found rather than just the first.
+pub fn test_program_all_errors(input: &str) -> anyhow::Result<Vec<anyhow::Error>> {
+    let (program, spans): (Program, _) = try_term_with_spans(input, &spanned_types())?;
+    Ok(check_all_crates_collecting_errors(&program, &spans))
+}
+
+/// Like [`test_program_ok`], but errors report the location of the failing crate item
+/// and where-clause in `input`.
+pub fn test_program_with_spans(input: &str) -> anyhow::Result<()> {
+    let (program, spans): (Program, _) = try_term_with_spans(input, &spanned_types())?;
+    check_all_crates_with_spans(&program, &spans)
+}
+
 pub fn test_where_clause(program: &str, assertion: &str) -> anyhow::Result<Set<Constraints>> {
     formality_core::with_tracing_logs(|| {
         let program: Program = try_term(program)?;
         check_all_crates(&program)?;
         let assertion: Arc<TestAssertion> = try_term(assertion)?;
-        let decls = program.to_prove_decls();
+        let decls = program.with_implicit_sized_bounds().to_prove_decls();
         Ok(formality_prove::test_util::test_prove(decls, assertion).into_set()?)
     })
 }
@@ -0,0 +1,171 @@
+/// The bounds on an associated type hold for a projection that cannot be normalized,
+/// without restating them as where-clauses.
+#[test]
+fn rigid_projection_has_item_bound() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Copy {}
+
+                trait Iterator {
+                    type Item : [Copy];
+                }
+
+                test <ty T> where T: Iterator {
+                    <T as Iterator>::Item: Copy,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn item_bound_implies_supertraits() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait PartialOrd {}
+                trait Ord where Self: PartialOrd {}
+
+                trait Iterator {
+                    type Item : [Ord];
+                }
+
+                test <ty T> where T: Iterator {
+                    <T as Iterator>::Item: PartialOrd,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn item_bound_used_in_fn() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Copy {}
+
+                struct NeedsCopy<ty T> where T: Copy {}
+
+                trait Iterator {
+                    type Item : [Copy];
+                }
+
+                fn foo<ty T>(NeedsCopy<<T as Iterator>::Item>) -> () where T: Iterator { trusted }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+/// The bounds only hold where the where-clauses of the associated type do.
+#[test]
+fn item_bound_requires_where_clauses() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Copy {}
+
+                trait Iterator {
+                    type Item<ty U> : [Copy] where U: Copy;
+                }
+
+                test <ty T, ty U> where T: Iterator {
+                    <T as Iterator>::Item<U>: Copy,
+                }
+            }
+        ]
+
+        [ "alias bound", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Copy(<!ty_0 as Iterator>::Item<!ty_1>)}, assumptions: {Iterator(!ty_0)}, env: Env { variables: [!ty_0, !ty_1], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Iterator <ty> ], [], [], [], [alias <ty, ty> <^ty0_0 as Iterator>::Item<^ty0_1> : <ty> Copy(^ty0_0) where {Copy(^ty0_1), Iterator(^ty0_0)}], [alias <ty, ty> wf <^ty0_0 as Iterator>::Item<^ty0_1> where {Copy(^ty0_1)}], [], [], [], {Copy, Iterator}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Copy(!ty_1), assumptions: {Iterator(!ty_0)}, env: Env { variables: [!ty_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_wc ("alias bound") > prove_after ("prove_after") > prove > prove_wc_list ("some")
+              judgment `prove_wc { goal: Copy(<!ty_0 as Iterator>::Item<!ty_1>), assumptions: {Iterator(!ty_0)}, env: Env { variables: [!ty_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+/// The where-clauses of the traits in the bounds of an associated type must hold.
+#[test]
+fn item_bound_not_well_formed() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Copy {}
+
+                trait Foo<ty U> where U: Copy {}
+
+                struct String {}
+
+                trait Iterator {
+                    type Item : [Foo<String>];
+                }
+            }
+        ]
+
+        [ "check_trait(Iterator)", "Copy(String)", ]
+
+        expect_test::expect![[r#"
+            check_trait(Iterator)
+
+            Caused by:
+                judgment `prove { goal: {@ WellFormedTraitRef(Foo(<!ty_0 as Iterator>::Item, String))}, assumptions: {Iterator(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Foo <ty, ty> where {Copy(^ty0_1)}, trait Iterator <ty> ], [], [], [], [alias <ty> <^ty0_0 as Iterator>::Item : <ty> Foo(^ty0_0, String) where {Iterator(^ty0_0)}], [alias <ty> wf <^ty0_0 as Iterator>::Item], [adt String ], [], [], {Copy, Foo, Iterator}, {String}) }` failed, most specifically because:
+                  judgment `prove_wc { goal: Copy(String), assumptions: {Iterator(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                    the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                      expression evaluated to an empty collection: `decls.trait_invariants()`
+                  via prove > prove_wc_list ("some") > prove_wc ("trait well formed") > prove > prove_wc_list ("some")
+                  judgment `prove_wc { goal: @ WellFormedTraitRef(Foo(<!ty_0 as Iterator>::Item, String)), assumptions: {Iterator(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                    the rule "assumption - predicate" failed at step #1 (src/file.rs:LL:CC) because
+                      judgment had no applicable rules: `prove_via { goal: @ WellFormedTraitRef(Foo(<!ty_0 as Iterator>::Item, String)), via: Iterator(!ty_0), assumptions: {Iterator(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }`
+                  via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+/// The bounds on an associated type only hold for projections of implemented traits:
+/// `<u32 as Iterator>::Item` names no type, since `u32: Iterator` does not hold.
+#[test]
+fn item_bound_requires_trait_ref() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Copy {}
+
+                trait Iterator {
+                    type Item : [Copy];
+                }
+
+                test {
+                    <u32 as Iterator>::Item: Copy,
+                }
+            }
+        ]
+
+        [ "prove_wc (\"alias bound\") > prove_after", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Copy(<u32 as Iterator>::Item)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Iterator <ty> ], [], [], [], [alias <ty> <^ty0_0 as Iterator>::Item : <ty> Copy(^ty0_0) where {Iterator(^ty0_0)}], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [], [], {Copy, Iterator}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Iterator(u32), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_wc ("alias bound") > prove_after ("prove_after") > prove > prove_wc_list ("some")
+              judgment `prove_wc { goal: Copy(<u32 as Iterator>::Item), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
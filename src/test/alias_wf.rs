@@ -0,0 +1,128 @@
+/// A projection is only well-formed if its trait-ref is implemented.
+#[test]
+fn projection_requires_impl() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Iterator {
+                    type Item : [];
+                }
+
+                fn foo(<u32 as Iterator>::Item) -> () { trusted }
+            }
+        ]
+
+        [ "Iterator(u32)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {@ wf(<u32 as Iterator>::Item)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Iterator <ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [fn foo (<u32 as Iterator>::Item) -> ()], [], {Iterator}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Iterator(u32), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_wc ("parameter well formed") > prove_wf ("aliases") > prove_after ("prove_after") > prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn projection_with_impl_is_wf() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Iterator {
+                    type Item : [];
+                }
+
+                impl Iterator for u32 {
+                    type Item = ();
+                }
+
+                fn foo(<u32 as Iterator>::Item) -> () { trusted }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+/// Generic projections need a where-clause to show the trait-ref is implemented.
+#[test]
+fn generic_projection_requires_where_clause() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Iterator {
+                    type Item : [];
+                }
+
+                fn foo<ty T>(<T as Iterator>::Item) -> () { trusted }
+            }
+        ]
+
+        [ "Iterator(!ty_0)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {@ wf(<!ty_0 as Iterator>::Item)}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait Iterator <ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [fn foo <ty> (<^ty0_0 as Iterator>::Item) -> ()], [], {Iterator}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Iterator(!ty_0), assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_wc ("parameter well formed") > prove_wf ("aliases") > prove_after ("prove_after") > prove > prove_wc_list ("some")"#]]
+    )
+}
+
+/// The where-clauses of a generic associated type must hold for the projection to be well-formed.
+#[test]
+fn gat_where_clauses_must_hold() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Baz {}
+
+                trait Family {
+                    type Member<ty U> : [] where U: Baz;
+                }
+
+                impl Family for () {
+                    type Member<ty U> = U where U: Baz;
+                }
+
+                fn foo(<() as Family>::Member<u32>) -> () { trusted }
+            }
+        ]
+
+        [ "Baz(u32)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {@ wf(<() as Family>::Member<u32>)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Baz <ty> , trait Family <ty> ], [impl Family(())], [], [alias <ty> <() as Family>::Member<^ty0_0> = ^ty0_0 where {Baz(^ty0_0)}], [], [alias <ty, ty> wf <^ty0_0 as Family>::Member<^ty0_1> where {Baz(^ty0_1)}], [], [fn foo (<() as Family>::Member<u32>) -> ()], [], {Baz, Family}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Baz(u32), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_wc ("parameter well formed") > prove_wf ("aliases") > prove_after ("prove_after") > prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn gat_where_clauses_hold() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Baz {}
+
+                impl Baz for u32 {}
+
+                trait Family {
+                    type Member<ty U> : [] where U: Baz;
+                }
+
+                impl Family for () {
+                    type Member<ty U> = U where U: Baz;
+                }
+
+                fn foo<ty U>(<() as Family>::Member<U>, <() as Family>::Member<u32>) -> () where U: Baz { trusted }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
@@ -0,0 +1,99 @@
+/// The outermost message and the error code of each error.
+fn summarize(errors: Vec<anyhow::Error>) -> Vec<String> {
+    errors
+        .iter()
+        .map(|e| format!("{:?}: {e}", formality_check::error_code(e)))
+        .collect()
+}
+
+#[test]
+fn independent_errors_are_all_reported() {
+    let errors = crate::test_program_all_errors(
+        "[
+    crate core {
+        trait Foo {}
+        unsafe trait Bar {}
+
+        impl Bar for u32 {}
+        unsafe impl Foo for u32 {}
+        impl Foo for u32 {}
+    },
+    crate foo {
+        struct S {}
+        struct S {}
+        impl Foo for u8 {}
+    }
+]",
+    )
+    .unwrap();
+    expect_test::expect![[r#"
+        [
+            "Some(\"E0200\"): in the item at 6:9",
+            "Some(\"E0199\"): in the item at 7:9",
+            "Some(\"E0119\"): impls may overlap:\nunsafe impl Foo for u32 { }\nimpl Foo for u32 { }",
+            "Some(\"E0428\"): the item name `S` is defined multiple times",
+            "Some(\"E0117\"): orphan_check(impl Foo for u8 { })",
+        ]
+    "#]]
+    .assert_debug_eq(&summarize(errors));
+}
+
+#[test]
+fn no_errors() {
+    let errors = crate::test_program_all_errors(
+        "[
+    crate core {
+        trait Foo {}
+        impl Foo for u32 {}
+    }
+]",
+    )
+    .unwrap();
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn equal_items_report_their_own_location() {
+    let errors = crate::test_program_all_errors(
+        "[
+    crate core {
+        trait Foo {}
+        struct Bad<ty T> where T: Foo {}
+        fn f(Bad<u8>) -> () { trusted }
+    },
+    crate foo {
+        fn f(Bad<u8>) -> () { trusted }
+    }
+]",
+    )
+    .unwrap();
+    expect_test::expect![[r#"
+        [
+            "None: in the item at 5:9",
+            "None: in the item at 8:9",
+        ]
+    "#]]
+    .assert_debug_eq(&summarize(errors));
+}
+
+#[test]
+fn each_duplicate_is_reported() {
+    let errors = crate::test_program_all_errors(
+        "[
+    crate core {
+        struct S {}
+        trait Foo {}
+        struct S {}
+        trait Foo {}
+    }
+]",
+    )
+    .unwrap();
+    expect_test::expect![[r#"
+        [
+            "Some(\"E0428\"): the item name `S` is defined multiple times",
+            "Some(\"E0428\"): the trait name `Foo` is defined multiple times",
+        ]
+    "#]]
+    .assert_debug_eq(&summarize(errors));
+}
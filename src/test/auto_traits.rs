@@ -0,0 +1,222 @@
+#![allow(non_snake_case)]
+
+#[test]
+fn structural_impl() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate Foo {
+                auto trait Send {}
+
+                struct Foo { x: u32 }
+                struct Bar<ty T> where T: static { t: T, r: &static T }
+
+                test {
+                    Foo: Send,
+                    Bar<Foo>: Send,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn structural_impl_requires_parameters() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate Foo {
+                auto trait Send {}
+
+                struct Bar<ty T> { t: T }
+
+                test <ty T> where T: Send {
+                    Bar<T>: Send,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn structural_impl_missing_parameter_bound() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                auto trait Send {}
+
+                struct Bar<ty T> { t: T }
+
+                test <ty T> {
+                    Bar<T>: Send,
+                }
+            }
+        ]
+
+        [ "the rule \"auto trait\" failed", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Send(Bar<!ty_0>)}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [auto trait Send <ty> ], [], [], [], [], [], [adt Bar <ty> fields [^ty0_0] variances [+]], [], [], {Send}, {Bar}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Send(!ty_0), assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "auto trait" failed at step #1 (src/file.rs:LL:CC) because
+                  pattern `Some(RigidTy { name, parameters })` did not match value `None`
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_wc ("auto trait") > prove > prove_wc_list ("some")
+              judgment `prove_wc { goal: Send(Bar<!ty_0>), assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn negative_impl_opts_out() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                auto trait Send {}
+
+                struct Ptr {}
+                impl !Send for Ptr {}
+
+                struct Wrapper { p: Ptr }
+
+                test {
+                    Wrapper: Send,
+                }
+            }
+        ]
+
+        [ "condition evaluted to false: `!decls.has_explicit_impl_for(&trait_ref.trait_id, &name)`", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Send(Wrapper)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [auto trait Send <ty> ], [], [impl ! Send(Ptr)], [], [], [], [adt Ptr , adt Wrapper fields [Ptr]], [], [], {Send}, {Ptr, Wrapper}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Send(Ptr), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "auto trait" failed at step #2 (src/file.rs:LL:CC) because
+                  condition evaluted to false: `!decls.has_explicit_impl_for(&trait_ref.trait_id, &name)`
+                    decls = decls(222, [auto trait Send <ty> ], [], [impl ! Send(Ptr)], [], [], [], [adt Ptr , adt Wrapper fields [Ptr]], [], [], {Send}, {Ptr, Wrapper})
+                    &trait_ref.trait_id = Send
+                    &name = (adt Ptr)
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_wc ("auto trait") > prove > prove_wc_list ("some")
+              judgment `prove_wc { goal: Send(Wrapper), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn positive_impl_overrides_structural_impl() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate Foo {
+                auto trait Send {}
+
+                struct Ptr {}
+                impl !Send for Ptr {}
+
+                struct Wrapper { p: Ptr }
+                impl Send for Wrapper {}
+
+                test {
+                    Wrapper: Send,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn recursive_type() {
+    crate::assert_ok!(
+        // Proving `List: Send` requires `List: Send` again, which holds coinductively.
+        //@check-pass
+        [
+            crate Foo {
+                auto trait Send {}
+
+                enum List {
+                    Nil {},
+                    Cons { head: u32, tail: &static List },
+                }
+
+                test {
+                    List: Send,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn recursive_type_with_negative_impl_field() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                auto trait Send {}
+
+                struct Ptr {}
+                impl !Send for Ptr {}
+
+                enum List {
+                    Nil {},
+                    Cons { head: Ptr, tail: &static List },
+                }
+
+                test {
+                    List: Send,
+                }
+            }
+        ]
+
+        [ "condition evaluted to false: `!decls.has_explicit_impl_for(&trait_ref.trait_id, &name)`", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Send(List)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [auto trait Send <ty> ], [], [impl ! Send(Ptr)], [], [], [], [adt Ptr , adt List fields [Ptr, &static List]], [], [], {Send}, {List, Ptr}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Send(Ptr), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "auto trait" failed at step #2 (src/file.rs:LL:CC) because
+                  condition evaluted to false: `!decls.has_explicit_impl_for(&trait_ref.trait_id, &name)`
+                    decls = decls(222, [auto trait Send <ty> ], [], [impl ! Send(Ptr)], [], [], [], [adt Ptr , adt List fields [Ptr, &static List]], [], [], {Send}, {List, Ptr})
+                    &trait_ref.trait_id = Send
+                    &name = (adt Ptr)
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_wc ("auto trait") > prove > prove_wc_list ("some")
+              judgment `prove_wc { goal: Send(List), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn auto_trait_with_supertrait() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                trait Copy {}
+                auto trait Send where Self: Copy {}
+            }
+        ]
+
+        [ "auto traits cannot have super traits or lifetime bounds", ]
+
+        expect_test::expect![[r#"
+            check_trait(Send)
+
+            Caused by:
+                auto traits cannot have super traits or lifetime bounds"#]]
+    )
+}
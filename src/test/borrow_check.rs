@@ -0,0 +1,321 @@
+#![allow(non_snake_case)]
+
+#[test]
+fn shared_borrows() {
+    crate::assert_ok!(
+        // Any number of shared borrows may be live at once, and the borrowed place may be read.
+        //@check-pass
+        [
+            crate Foo {
+                // fn f(x: u32) -> u32 { let r1 = &x; let r2 = &x; let y = x; *r1 + *r2 }
+                fn f(u32) -> u32 = mir(<lt a, lt b> locals_and_blocks(
+                    [(mut ret: u32), (shared x: u32), (shared r1: &a u32), (shared r2: &b u32), (shared y: u32)],
+                    [basic_block_decl(bb0, [
+                        ((r1) = ref(a, shared, (x))),
+                        ((r2) = ref(b, shared, (x))),
+                        ((y) = use(copy((x)))),
+                        ((ret) = apply(copy((r1 *)), +, copy((r2 *))))
+                    ], return)]
+                ));
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn mutable_borrow_after_last_use_of_shared_borrow() {
+    crate::assert_ok!(
+        // The shared borrow is no longer live once `r1` is dead.
+        //@check-pass
+        [
+            crate Foo {
+                // fn f(x: u32) -> u32 { let r1 = &x; let y = *r1; let r2 = &mut x; *r2 = y; x }
+                fn f(u32) -> u32 = mir(<lt a, lt b> locals_and_blocks(
+                    [(mut ret: u32), (mut x: u32), (shared r1: &a u32), (shared r2: &mut b u32), (shared y: u32)],
+                    [basic_block_decl(bb0, [
+                        ((r1) = ref(a, shared, (x))),
+                        ((y) = use(copy((r1 *)))),
+                        ((r2) = ref(b, mut, (x))),
+                        ((r2 *) = use(copy((y)))),
+                        ((ret) = use(copy((x))))
+                    ], return)]
+                ));
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn mutable_borrow_while_shared_borrow_live() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                // fn f(x: u32) -> u32 { let r1 = &x; let r2 = &mut x; *r1 }
+                fn f(u32) -> u32 = mir(<lt a, lt b> locals_and_blocks(
+                    [(mut ret: u32), (mut x: u32), (shared r1: &a u32), (shared r2: &mut b u32)],
+                    [basic_block_decl(bb0, [
+                        ((r1) = ref(a, shared, (x))),
+                        ((r2) = ref(b, mut, (x))),
+                        ((ret) = use(copy((r1 *))))
+                    ], return)]
+                ));
+            }
+        ]
+
+        [ "cannot borrow `(x)` as mutable because it is also borrowed as immutable", ]
+
+        expect_test::expect!["cannot borrow `(x)` as mutable because it is also borrowed as immutable"]
+    )
+}
+
+#[test]
+fn loan_flows_through_assignment() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                // fn f(x: u32) -> u32 { let r1 = &mut x; let r2 = r1; let y = x; *r2 }
+                fn f(u32) -> u32 = mir(<lt a, lt b> locals_and_blocks(
+                    [(mut ret: u32), (mut x: u32), (shared r1: &mut a u32), (shared r2: &mut b u32), (shared y: u32)],
+                    [basic_block_decl(bb0, [
+                        ((r1) = ref(a, mut, (x))),
+                        ((r2) = use(move((r1)))),
+                        ((y) = use(copy((x)))),
+                        ((ret) = use(copy((r2 *))))
+                    ], return)]
+                ));
+            }
+        ]
+
+        [ "cannot use `(x)` because it was mutably borrowed", ]
+
+        expect_test::expect!["cannot use `(x)` because it was mutably borrowed"]
+    )
+}
+
+#[test]
+fn loan_live_across_blocks() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                fn f(u32) -> u32 = mir(<lt a> locals_and_blocks(
+                    [(mut ret: u32), (mut x: u32), (shared r1: &a u32)],
+                    [
+                        basic_block_decl(bb0, [((r1) = ref(a, shared, (x)))], goto(bb1)),
+                        basic_block_decl(bb1, [((x) = use(const(number(22, u32))))], goto(bb2)),
+                        basic_block_decl(bb2, [((ret) = use(copy((r1 *))))], return)
+                    ]
+                ));
+            }
+        ]
+
+        [ "cannot assign to `(x)` because it is borrowed", ]
+
+        expect_test::expect!["cannot assign to `(x)` because it is borrowed"]
+    )
+}
+
+#[test]
+fn move_out_of_borrowed_place() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                struct Pair { a: u32, b: u32 }
+
+                fn f(Pair) -> u32 = mir(<lt a> locals_and_blocks(
+                    [(mut ret: u32), (shared p: Pair), (shared r: &a u32), (shared q: Pair)],
+                    [basic_block_decl(bb0, [
+                        ((r) = ref(a, shared, (p b))),
+                        ((q) = use(move((p)))),
+                        ((ret) = use(copy((r *))))
+                    ], return)]
+                ));
+            }
+        ]
+
+        [ "cannot move out of `(p)` because it is borrowed", ]
+
+        expect_test::expect!["cannot move out of `(p)` because it is borrowed"]
+    )
+}
+
+#[test]
+fn use_after_move() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                struct Pair { a: u32, b: u32 }
+
+                fn f(Pair) -> u32 = mir(locals_and_blocks(
+                    [(mut ret: u32), (shared p: Pair), (shared q: Pair)],
+                    [
+                        basic_block_decl(bb0, [((q) = use(move((p))))], goto(bb1)),
+                        basic_block_decl(bb1, [((ret) = use(copy((p a))))], return)
+                    ]
+                ));
+            }
+        ]
+
+        [ "use of moved or uninitialized value", ]
+
+        expect_test::expect!["use of moved or uninitialized value: `(p)` is not initialized when `(p a)` is used"]
+    )
+}
+
+#[test]
+fn reinitialize_after_move() {
+    crate::assert_ok!(
+        // Assigning to a moved place makes it usable again.
+        //@check-pass
+        [
+            crate Foo {
+                struct Pair { a: u32, b: u32 }
+
+                fn f(Pair) -> u32 = mir(locals_and_blocks(
+                    [(mut ret: u32), (mut p: Pair), (shared q: Pair)],
+                    [basic_block_decl(bb0, [
+                        ((q) = use(move((p)))),
+                        ((p) = use(move((q)))),
+                        ((ret) = use(copy((p a))))
+                    ], return)]
+                ));
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn return_uninitialized() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                fn f(u32) -> u32 = mir(locals_and_blocks(
+                    [(mut ret: u32), (shared x: u32)],
+                    [basic_block_decl(bb0, [], return)]
+                ));
+            }
+        ]
+
+        [ "use of moved or uninitialized value", ]
+
+        expect_test::expect!["use of moved or uninitialized value: `(ret)` is not initialized when `(ret)` is used"]
+    )
+}
+
+#[test]
+fn mutable_borrow_of_immutable_local() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                fn f(u32) -> u32 = mir(<lt a> locals_and_blocks(
+                    [(mut ret: u32), (shared x: u32), (shared r: &mut a u32)],
+                    [basic_block_decl(bb0, [
+                        ((r) = ref(a, mut, (x))),
+                        ((ret) = use(copy((x))))
+                    ], return)]
+                ));
+            }
+        ]
+
+        [ "is not declared as mutable", ]
+
+        expect_test::expect!["cannot borrow `(x)` as mutable, as `x` is not declared as mutable"]
+    )
+}
+
+#[test]
+fn assign_through_shared_reference() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                // fn f(x: u32) -> u32 { let r = &x; *r = 1; x }
+                fn f(u32) -> u32 = mir(<lt a> locals_and_blocks(
+                    [(mut ret: u32), (mut x: u32), (shared r: &a u32)],
+                    [basic_block_decl(bb0, [
+                        ((r) = ref(a, shared, (x))),
+                        ((r *) = use(const(number(1, u32)))),
+                        ((ret) = use(copy((x))))
+                    ], return)]
+                ));
+            }
+        ]
+
+        [ "which is behind a `&` reference", ]
+
+        expect_test::expect!["cannot assign to `(r *)`, which is behind a `&` reference"]
+    )
+}
+
+#[test]
+fn mutable_borrow_through_shared_reference() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                // fn f(x: &u32) -> u32 { let r = &mut *x; *r }
+                fn f<lt a>(&a u32) -> u32 = mir(<lt b, lt c> locals_and_blocks(
+                    [(mut ret: u32), (shared x: &b u32), (shared r: &mut c u32)],
+                    [basic_block_decl(bb0, [
+                        ((r) = ref(c, mut, (x *))),
+                        ((ret) = use(copy((r *))))
+                    ], return)]
+                ));
+            }
+        ]
+
+        [ "as it is behind a `&` reference", ]
+
+        expect_test::expect!["cannot borrow `(x *)` as mutable, as it is behind a `&` reference"]
+    )
+}
+
+#[test]
+fn assign_twice_to_immutable_local() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                // fn f() -> u32 { let x = 1; x = 2; x }
+                fn f() -> u32 = mir(locals_and_blocks(
+                    [(mut ret: u32), (shared x: u32)],
+                    [basic_block_decl(bb0, [
+                        ((x) = use(const(number(1, u32)))),
+                        ((x) = use(const(number(2, u32)))),
+                        ((ret) = use(copy((x))))
+                    ], return)]
+                ));
+            }
+        ]
+
+        [ "cannot assign twice to immutable variable", ]
+
+        expect_test::expect!["cannot assign twice to immutable variable `x`"]
+    )
+}
+
+#[test]
+fn deferred_initialization_of_immutable_local() {
+    crate::assert_ok!(
+        // An immutable local may be assigned once, and mutated through a mutable reference it holds.
+        //@check-pass
+        [
+            crate Foo {
+                // fn f(y: u32) -> u32 { let x; x = y; let r = &mut y; *r = x; y }
+                fn f(u32) -> u32 = mir(<lt a> locals_and_blocks(
+                    [(mut ret: u32), (mut y: u32), (shared x: u32), (shared r: &mut a u32)],
+                    [basic_block_decl(bb0, [
+                        ((x) = use(copy((y)))),
+                        ((r) = ref(a, mut, (y))),
+                        ((r *) = use(copy((x)))),
+                        ((ret) = use(copy((y))))
+                    ], return)]
+                ));
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
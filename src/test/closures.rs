@@ -0,0 +1,235 @@
+#![allow(non_snake_case)]
+
+#[test]
+fn closure_kinds() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(fn_once)] trait FnOnce<ty Args> {
+                    type Output : [];
+                }
+                #[lang(fn_mut)] trait FnMut<ty Args> where Self: FnOnce<Args> {}
+                #[lang(fn)] trait Fn<ty Args> where Self: FnMut<Args> {}
+
+                test {
+                    closure A: fn(u32) -> bool: Fn<(u32)>,
+                    closure A: fn(u32) -> bool: FnOnce<(u32)>,
+                    closure B: fn_mut(u32) -> bool [u8]: FnMut<(u32)>,
+                    closure C: fn_once() -> () [u8]: FnOnce<()>,
+                    fn(u32, bool) -> u8: Fn<(u32, bool)>,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn output_normalizes_to_return_type() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(fn_once)] trait FnOnce<ty Args> {
+                    type Output : [];
+                }
+
+                test {
+                    <closure B: fn_mut(u32) -> bool [u8] as FnOnce<(u32)>>::Output => bool,
+                    <fn(u32, bool) -> u8 as FnOnce<(u32, bool)>>::Output => u8,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn fn_once_closure_not_fn_mut() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(fn_once)] trait FnOnce<ty Args> {
+                    type Output : [];
+                }
+                #[lang(fn_mut)] trait FnMut<ty Args> where Self: FnOnce<Args> {}
+
+                test {
+                    closure C: fn_once() -> () [u8]: FnMut<()>,
+                }
+            }
+        ]
+
+        [ "judgment `prove_wc { goal: FnMut(closure C: fn_once() -> () [u8], ())", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {FnMut(closure C: fn_once() -> () [u8], ())}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(fn_once)] trait FnOnce <ty, ty> , #[lang(fn_mut)] trait FnMut <ty, ty> where {FnOnce(^ty0_0, ^ty0_1)}], [], [], [], [], [alias <ty, ty> wf <^ty0_0 as FnOnce<^ty0_1>>::Output], [], [], [], {FnMut, FnOnce}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: FnMut(closure C: fn_once() -> () [u8], ()), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_via { goal: FnMut(closure C: fn_once() -> () [u8], ()), via: FnOnce(?ty_1, ?ty_2), assumptions: {}, env: Env { variables: [?ty_1, ?ty_2], bias: Soundness } }`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn closure_wrong_args() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(fn_once)] trait FnOnce<ty Args> {
+                    type Output : [];
+                }
+
+                test {
+                    closure A: fn(u32) -> bool: FnOnce<(bool)>,
+                }
+            }
+        ]
+
+        [ "judgment `prove_eq { a: (u32), b: (bool)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {FnOnce(closure A: fn(u32) -> bool, (bool))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(fn_once)] trait FnOnce <ty, ty> ], [], [], [], [], [alias <ty, ty> wf <^ty0_0 as FnOnce<^ty0_1>>::Output], [], [], [], {FnOnce}, {}) }` failed, most specifically because:
+              judgment `prove_eq { a: (bool), b: (u32), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_normalize { p: (bool), assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("built-in impl") > prove > prove_wc_list ("some") > prove_wc ("eq")
+              judgment `prove_eq { a: (u32), b: (bool), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_normalize { p: (u32), assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("built-in impl") > prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("symmetric")
+              judgment `prove_eq { a: bool, b: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_normalize { p: bool, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("built-in impl") > prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("rigid") > prove > prove_wc_list ("some") > prove_wc ("eq")
+              ...and 2 less specific failure(s)"#]]
+    )
+}
+
+#[test]
+fn closure_copy_if_upvars_copy() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(copy)] trait Copy {}
+
+                struct NotCopy {}
+
+                test {
+                    closure A: fn() -> () [u32, &static u8]: Copy,
+                    closure B: fn() -> () [NotCopy]: Copy,
+                }
+            }
+        ]
+
+        [ "judgment `prove_wc { goal: Copy(NotCopy)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Copy(closure A: fn() -> () [u32, &static u8]), Copy(closure B: fn() -> () [NotCopy])}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(copy)] trait Copy <ty> ], [], [], [], [], [], [adt NotCopy ], [], [], {Copy}, {NotCopy}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Copy(NotCopy), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("built-in impl") > prove > prove_wc_list ("some")
+              judgment `prove_wc { goal: Copy(closure B: fn() -> () [NotCopy]), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn higher_ranked_closure_bound() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(fn_once)] trait FnOnce<ty Args> {
+                    type Output : [];
+                }
+                #[lang(fn_mut)] trait FnMut<ty Args> where Self: FnOnce<Args> {}
+                #[lang(fn)] trait Fn<ty Args> where Self: FnMut<Args> {}
+
+                // fn f<F>() where F: for<'a> Fn(&'a u8) -> u32
+                test <ty F> where for<lt a> F: Fn<(&a u8)>, for<lt a> <F as FnOnce<(&a u8)>>::Output => u32 {
+                    F: Fn<(&static u8)>,
+                    for<lt b> F: FnOnce<(&b u8)>,
+                    <F as FnOnce<(&static u8)>>::Output => u32,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn higher_ranked_closure_bound_not_satisfied() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(fn_once)] trait FnOnce<ty Args> {
+                    type Output : [];
+                }
+                #[lang(fn_mut)] trait FnMut<ty Args> where Self: FnOnce<Args> {}
+                #[lang(fn)] trait Fn<ty Args> where Self: FnMut<Args> {}
+
+                // `F: Fn(&'static u8)` does not imply `for<'a> F: Fn(&'a u8)`
+                test <ty F> where F: Fn<(&static u8)> {
+                    for<lt a> F: Fn<(&a u8)>,
+                }
+            }
+        ]
+
+        [ "judgment `prove_wc { goal: (&static u8) = (&!lt_1 u8)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {for <lt> Fn(!ty_0, (&^lt0_0 u8))}, assumptions: {Fn(!ty_0, (&static u8))}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [#[lang(fn_once)] trait FnOnce <ty, ty> , #[lang(fn_mut)] trait FnMut <ty, ty> where {FnOnce(^ty0_0, ^ty0_1)}, #[lang(fn)] trait Fn <ty, ty> where {FnMut(^ty0_0, ^ty0_1)}], [], [], [], [], [alias <ty, ty> wf <^ty0_0 as FnOnce<^ty0_1>>::Output], [], [], [], {Fn, FnMut, FnOnce}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Fn(!ty_0, (&!lt_1 u8)), assumptions: {Fn(!ty_0, (&static u8))}, env: Env { variables: [!ty_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_via { goal: Fn(!ty_0, (&!lt_1 u8)), via: FnMut(?ty_2, ?ty_3), assumptions: {Fn(!ty_0, (&static u8))}, env: Env { variables: [!ty_0, !lt_1, ?ty_2, ?ty_3], bias: Soundness } }`
+                the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_via { goal: Fn(!ty_0, (&!lt_1 u8)), via: FnOnce(?ty_2, ?ty_3), assumptions: {Fn(!ty_0, (&static u8))}, env: Env { variables: [!ty_0, !lt_1, ?ty_2, ?ty_3], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("forall")
+              judgment `prove_wc { goal: (&static u8) = (&!lt_1 u8), assumptions: {Fn(!ty_0, (&static u8))}, env: Env { variables: [!ty_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_via { goal: (&static u8) = (&!lt_1 u8), via: Fn(!ty_0, (&static u8)), assumptions: {Fn(!ty_0, (&static u8))}, env: Env { variables: [!ty_0, !lt_1], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("forall") > prove_wc ("assumption - predicate") > prove_via ("predicate-congruence-axiom") > prove > prove_wc_list ("some")
+              judgment `prove_normalize { p: (&static u8), assumptions: {Fn(!ty_0, (&static u8))}, env: Env { variables: [!ty_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_normalize_via { goal: (&static u8), via: Fn(!ty_0, (&static u8)), assumptions: {Fn(!ty_0, (&static u8))}, env: Env { variables: [!ty_0, !lt_1], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("forall") > prove_wc ("assumption - predicate") > prove_via ("predicate-congruence-axiom") > prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("normalize-l")
+              ...and 9 less specific failure(s)"#]]
+    )
+}
+
+#[test]
+fn call_closure_through_generic_fn() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(fn_once)] trait FnOnce<ty Args> {
+                    type Output : [];
+                }
+                #[lang(fn_mut)] trait FnMut<ty Args> where Self: FnOnce<Args> {}
+                #[lang(fn)] trait Fn<ty Args> where Self: FnMut<Args> {}
+
+                fn apply<ty F>(F, u32) -> u32 where F: Fn<(u32)>, <F as FnOnce<(u32)>>::Output => u32 { trusted }
+
+                // fn g(c: C) -> u32 { apply(c, 1) }
+                fn g(closure C: fn(u32) -> u32) -> u32 = mir(locals_and_blocks(
+                    [(mut ret: u32), (shared c: closure C: fn(u32) -> u32)],
+                    [
+                        basic_block_decl(bb0, [], call(const(fn_ptr(apply, [closure C: fn(u32) -> u32])), [copy((c)), const(number(1, u32))], (ret), [bb1])),
+                        basic_block_decl(bb1, [], return)
+                    ]
+                ));
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
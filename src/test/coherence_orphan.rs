@@ -19,27 +19,24 @@ fn neg_CoreTrait_for_CoreStruct_in_Foo() {
             orphan_check_neg(impl ! CoreTrait for CoreStruct {})
 
             Caused by:
-                judgment `prove { goal: {@ IsLocal(CoreTrait(CoreStruct))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait CoreTrait <ty> ], [], [impl ! CoreTrait(CoreStruct)], [], [], [adt CoreStruct ], {}, {}) }` failed at the following rule(s):
-                  failed at (src/file.rs:LL:CC) because
-                    judgment `prove_wc_list { goal: {@ IsLocal(CoreTrait(CoreStruct))}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                        judgment `prove_wc { goal: @ IsLocal(CoreTrait(CoreStruct)), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                          the rule "trait ref is local" failed at step #0 (src/file.rs:LL:CC) because
-                            judgment `is_local_trait_ref { goal: CoreTrait(CoreStruct), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                              the rule "local parameter" failed at step #1 (src/file.rs:LL:CC) because
-                                judgment `is_local_parameter { goal: CoreStruct, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                                  the rule "fundamental rigid type" failed at step #0 (src/file.rs:LL:CC) because
-                                    condition evaluted to false: `is_fundamental(&decls, &name)`
-                                      &decls = decls(222, [trait CoreTrait <ty> ], [], [impl ! CoreTrait(CoreStruct)], [], [], [adt CoreStruct ], {}, {})
-                                      &name = (adt CoreStruct)
-                                  the rule "local rigid type" failed at step #0 (src/file.rs:LL:CC) because
-                                    condition evaluted to false: `decls.is_local_adt_id(&a)`
-                                      decls = decls(222, [trait CoreTrait <ty> ], [], [impl ! CoreTrait(CoreStruct)], [], [], [adt CoreStruct ], {}, {})
-                                      &a = CoreStruct
-                              the rule "local trait" failed at step #0 (src/file.rs:LL:CC) because
-                                condition evaluted to false: `decls.is_local_trait_id(&goal.trait_id)`
-                                  decls = decls(222, [trait CoreTrait <ty> ], [], [impl ! CoreTrait(CoreStruct)], [], [], [adt CoreStruct ], {}, {})
-                                  &goal.trait_id = CoreTrait"#]]
+                0: the impl `impl ! CoreTrait for CoreStruct {}` violates the orphan rules
+                1: judgment `prove { goal: {@ IsLocal(CoreTrait(CoreStruct))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait CoreTrait <ty> ], [], [impl ! CoreTrait(CoreStruct)], [], [], [], [adt CoreStruct ], [], [], {}, {}) }` failed, most specifically because:
+                     judgment `is_local_parameter { goal: CoreStruct, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "fundamental rigid type" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `is_fundamental(&decls, &name)`
+                           &decls = decls(222, [trait CoreTrait <ty> ], [], [impl ! CoreTrait(CoreStruct)], [], [], [], [adt CoreStruct ], [], [], {}, {})
+                           &name = (adt CoreStruct)
+                       the rule "local rigid type" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `decls.is_local_adt_id(&a)`
+                           decls = decls(222, [trait CoreTrait <ty> ], [], [impl ! CoreTrait(CoreStruct)], [], [], [], [adt CoreStruct ], [], [], {}, {})
+                           &a = CoreStruct
+                     via prove > prove_wc_list ("some") > prove_wc ("trait ref is local") > is_local_trait_ref ("local parameter")
+                     judgment `is_local_trait_ref { goal: CoreTrait(CoreStruct), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "local trait" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `decls.is_local_trait_id(&goal.trait_id)`
+                           decls = decls(222, [trait CoreTrait <ty> ], [], [impl ! CoreTrait(CoreStruct)], [], [], [], [adt CoreStruct ], [], [], {}, {})
+                           &goal.trait_id = CoreTrait
+                     via prove > prove_wc_list ("some") > prove_wc ("trait ref is local")"#]]
     )
 }
 
@@ -70,29 +67,24 @@ fn mirror_CoreStruct() {
             orphan_check(impl CoreTrait for <CoreStruct as Mirror>::Assoc { })
 
             Caused by:
-                judgment `prove { goal: {@ IsLocal(CoreTrait(<CoreStruct as Mirror>::Assoc))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait CoreTrait <ty> , trait Mirror <ty> ], [impl <ty> Mirror(^ty0_0), impl CoreTrait(<CoreStruct as Mirror>::Assoc)], [], [alias <ty> <^ty0_0 as Mirror>::Assoc = ^ty0_0], [], [adt CoreStruct ], {}, {}) }` failed at the following rule(s):
-                  failed at (src/file.rs:LL:CC) because
-                    judgment `prove_wc_list { goal: {@ IsLocal(CoreTrait(<CoreStruct as Mirror>::Assoc))}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                        judgment `prove_wc { goal: @ IsLocal(CoreTrait(<CoreStruct as Mirror>::Assoc)), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                          the rule "trait ref is local" failed at step #0 (src/file.rs:LL:CC) because
-                            judgment `is_local_trait_ref { goal: CoreTrait(<CoreStruct as Mirror>::Assoc), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                              the rule "local parameter" failed at step #1 (src/file.rs:LL:CC) because
-                                judgment `is_local_parameter { goal: <CoreStruct as Mirror>::Assoc, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                                  the rule "local parameter" failed at step #2 (src/file.rs:LL:CC) because
-                                    judgment `is_local_parameter { goal: CoreStruct, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                                      the rule "fundamental rigid type" failed at step #0 (src/file.rs:LL:CC) because
-                                        condition evaluted to false: `is_fundamental(&decls, &name)`
-                                          &decls = decls(222, [trait CoreTrait <ty> , trait Mirror <ty> ], [impl <ty> Mirror(^ty0_0), impl CoreTrait(<CoreStruct as Mirror>::Assoc)], [], [alias <ty> <^ty0_0 as Mirror>::Assoc = ^ty0_0], [], [adt CoreStruct ], {}, {})
-                                          &name = (adt CoreStruct)
-                                      the rule "local rigid type" failed at step #0 (src/file.rs:LL:CC) because
-                                        condition evaluted to false: `decls.is_local_adt_id(&a)`
-                                          decls = decls(222, [trait CoreTrait <ty> , trait Mirror <ty> ], [impl <ty> Mirror(^ty0_0), impl CoreTrait(<CoreStruct as Mirror>::Assoc)], [], [alias <ty> <^ty0_0 as Mirror>::Assoc = ^ty0_0], [], [adt CoreStruct ], {}, {})
-                                          &a = CoreStruct
-                              the rule "local trait" failed at step #0 (src/file.rs:LL:CC) because
-                                condition evaluted to false: `decls.is_local_trait_id(&goal.trait_id)`
-                                  decls = decls(222, [trait CoreTrait <ty> , trait Mirror <ty> ], [impl <ty> Mirror(^ty0_0), impl CoreTrait(<CoreStruct as Mirror>::Assoc)], [], [alias <ty> <^ty0_0 as Mirror>::Assoc = ^ty0_0], [], [adt CoreStruct ], {}, {})
-                                  &goal.trait_id = CoreTrait"#]]
+                0: the impl `impl CoreTrait for <CoreStruct as Mirror>::Assoc { }` violates the orphan rules
+                1: judgment `prove { goal: {@ IsLocal(CoreTrait(<CoreStruct as Mirror>::Assoc))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait CoreTrait <ty> , trait Mirror <ty> ], [impl <ty> Mirror(^ty0_0), impl CoreTrait(<CoreStruct as Mirror>::Assoc)], [], [alias <ty> <^ty0_0 as Mirror>::Assoc = ^ty0_0], [], [alias <ty> wf <^ty0_0 as Mirror>::Assoc], [adt CoreStruct ], [], [], {}, {}) }` failed, most specifically because:
+                     judgment `is_local_parameter { goal: CoreStruct, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "fundamental rigid type" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `is_fundamental(&decls, &name)`
+                           &decls = decls(222, [trait CoreTrait <ty> , trait Mirror <ty> ], [impl <ty> Mirror(^ty0_0), impl CoreTrait(<CoreStruct as Mirror>::Assoc)], [], [alias <ty> <^ty0_0 as Mirror>::Assoc = ^ty0_0], [], [alias <ty> wf <^ty0_0 as Mirror>::Assoc], [adt CoreStruct ], [], [], {}, {})
+                           &name = (adt CoreStruct)
+                       the rule "local rigid type" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `decls.is_local_adt_id(&a)`
+                           decls = decls(222, [trait CoreTrait <ty> , trait Mirror <ty> ], [impl <ty> Mirror(^ty0_0), impl CoreTrait(<CoreStruct as Mirror>::Assoc)], [], [alias <ty> <^ty0_0 as Mirror>::Assoc = ^ty0_0], [], [alias <ty> wf <^ty0_0 as Mirror>::Assoc], [adt CoreStruct ], [], [], {}, {})
+                           &a = CoreStruct
+                     via prove > prove_wc_list ("some") > prove_wc ("trait ref is local") > is_local_trait_ref ("local parameter") > is_local_parameter ("local parameter")
+                     judgment `is_local_trait_ref { goal: CoreTrait(<CoreStruct as Mirror>::Assoc), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "local trait" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `decls.is_local_trait_id(&goal.trait_id)`
+                           decls = decls(222, [trait CoreTrait <ty> , trait Mirror <ty> ], [impl <ty> Mirror(^ty0_0), impl CoreTrait(<CoreStruct as Mirror>::Assoc)], [], [alias <ty> <^ty0_0 as Mirror>::Assoc = ^ty0_0], [], [alias <ty> wf <^ty0_0 as Mirror>::Assoc], [adt CoreStruct ], [], [], {}, {})
+                           &goal.trait_id = CoreTrait
+                     via prove > prove_wc_list ("some") > prove_wc ("trait ref is local")"#]]
     )
 }
 
@@ -158,17 +150,22 @@ fn uncovered_T() {
             orphan_check(impl <ty> CoreTrait <FooStruct> for ^ty0_0 { })
 
             Caused by:
-                judgment `prove { goal: {@ IsLocal(CoreTrait(!ty_0, FooStruct))}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait CoreTrait <ty, ty> ], [impl <ty> CoreTrait(^ty0_0, FooStruct)], [], [], [], [adt FooStruct ], {}, {FooStruct}) }` failed at the following rule(s):
-                  failed at (src/file.rs:LL:CC) because
-                    judgment `prove_wc_list { goal: {@ IsLocal(CoreTrait(!ty_0, FooStruct))}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
-                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                        judgment `prove_wc { goal: @ IsLocal(CoreTrait(!ty_0, FooStruct)), assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
-                          the rule "trait ref is local" failed at step #0 (src/file.rs:LL:CC) because
-                            judgment `is_local_trait_ref { goal: CoreTrait(!ty_0, FooStruct), assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
-                              the rule "local trait" failed at step #0 (src/file.rs:LL:CC) because
-                                condition evaluted to false: `decls.is_local_trait_id(&goal.trait_id)`
-                                  decls = decls(222, [trait CoreTrait <ty, ty> ], [impl <ty> CoreTrait(^ty0_0, FooStruct)], [], [], [], [adt FooStruct ], {}, {FooStruct})
-                                  &goal.trait_id = CoreTrait"#]]
+                0: the impl `impl <ty> CoreTrait <FooStruct> for ^ty0_0 { }` violates the orphan rules
+                1: judgment `prove { goal: {@ IsLocal(CoreTrait(!ty_0, FooStruct))}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait CoreTrait <ty, ty> ], [impl <ty> CoreTrait(^ty0_0, FooStruct)], [], [], [], [], [adt FooStruct ], [], [], {}, {FooStruct}) }` failed, most specifically because:
+                     judgment `is_local_parameter { goal: !ty_0, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                       the rule "local parameter" failed at step #0 (src/file.rs:LL:CC) because
+                         judgment had no applicable rules: `prove_normalize { p: !ty_0, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }`
+                     via prove > prove_wc_list ("some") > prove_wc ("trait ref is local") > is_local_trait_ref ("local parameter")
+                     judgment `is_not_downstream { parameter: !ty_0, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                       the rule "via normalize" failed at step #0 (src/file.rs:LL:CC) because
+                         judgment had no applicable rules: `prove_normalize { p: !ty_0, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }`
+                     via prove > prove_wc_list ("some") > prove_wc ("trait ref is local") > is_local_trait_ref ("local parameter")
+                     judgment `is_local_trait_ref { goal: CoreTrait(!ty_0, FooStruct), assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                       the rule "local trait" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `decls.is_local_trait_id(&goal.trait_id)`
+                           decls = decls(222, [trait CoreTrait <ty, ty> ], [impl <ty> CoreTrait(^ty0_0, FooStruct)], [], [], [], [], [adt FooStruct ], [], [], {}, {FooStruct})
+                           &goal.trait_id = CoreTrait
+                     via prove > prove_wc_list ("some") > prove_wc ("trait ref is local")"#]]
     )
 }
 
@@ -199,25 +196,20 @@ fn alias_to_unit() {
             orphan_check(impl CoreTrait for <FooStruct as Unit>::Assoc { })
 
             Caused by:
-                judgment `prove { goal: {@ IsLocal(CoreTrait(<FooStruct as Unit>::Assoc))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait CoreTrait <ty> , trait Unit <ty> ], [impl <ty> Unit(^ty0_0), impl CoreTrait(<FooStruct as Unit>::Assoc)], [], [alias <ty> <^ty0_0 as Unit>::Assoc = ()], [], [adt FooStruct ], {}, {FooStruct}) }` failed at the following rule(s):
-                  failed at (src/file.rs:LL:CC) because
-                    judgment `prove_wc_list { goal: {@ IsLocal(CoreTrait(<FooStruct as Unit>::Assoc))}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                        judgment `prove_wc { goal: @ IsLocal(CoreTrait(<FooStruct as Unit>::Assoc)), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                          the rule "trait ref is local" failed at step #0 (src/file.rs:LL:CC) because
-                            judgment `is_local_trait_ref { goal: CoreTrait(<FooStruct as Unit>::Assoc), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                              the rule "local parameter" failed at step #1 (src/file.rs:LL:CC) because
-                                judgment `is_local_parameter { goal: <FooStruct as Unit>::Assoc, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                                  the rule "local parameter" failed at step #2 (src/file.rs:LL:CC) because
-                                    judgment `is_local_parameter { goal: (), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                                      the rule "fundamental rigid type" failed at step #0 (src/file.rs:LL:CC) because
-                                        condition evaluted to false: `is_fundamental(&decls, &name)`
-                                          &decls = decls(222, [trait CoreTrait <ty> , trait Unit <ty> ], [impl <ty> Unit(^ty0_0), impl CoreTrait(<FooStruct as Unit>::Assoc)], [], [alias <ty> <^ty0_0 as Unit>::Assoc = ()], [], [adt FooStruct ], {}, {FooStruct})
-                                          &name = tuple(0)
-                              the rule "local trait" failed at step #0 (src/file.rs:LL:CC) because
-                                condition evaluted to false: `decls.is_local_trait_id(&goal.trait_id)`
-                                  decls = decls(222, [trait CoreTrait <ty> , trait Unit <ty> ], [impl <ty> Unit(^ty0_0), impl CoreTrait(<FooStruct as Unit>::Assoc)], [], [alias <ty> <^ty0_0 as Unit>::Assoc = ()], [], [adt FooStruct ], {}, {FooStruct})
-                                  &goal.trait_id = CoreTrait"#]]
+                0: the impl `impl CoreTrait for <FooStruct as Unit>::Assoc { }` violates the orphan rules
+                1: judgment `prove { goal: {@ IsLocal(CoreTrait(<FooStruct as Unit>::Assoc))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait CoreTrait <ty> , trait Unit <ty> ], [impl <ty> Unit(^ty0_0), impl CoreTrait(<FooStruct as Unit>::Assoc)], [], [alias <ty> <^ty0_0 as Unit>::Assoc = ()], [], [alias <ty> wf <^ty0_0 as Unit>::Assoc], [adt FooStruct ], [], [], {}, {FooStruct}) }` failed, most specifically because:
+                     judgment `is_local_parameter { goal: (), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "fundamental rigid type" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `is_fundamental(&decls, &name)`
+                           &decls = decls(222, [trait CoreTrait <ty> , trait Unit <ty> ], [impl <ty> Unit(^ty0_0), impl CoreTrait(<FooStruct as Unit>::Assoc)], [], [alias <ty> <^ty0_0 as Unit>::Assoc = ()], [], [alias <ty> wf <^ty0_0 as Unit>::Assoc], [adt FooStruct ], [], [], {}, {FooStruct})
+                           &name = tuple(0)
+                     via prove > prove_wc_list ("some") > prove_wc ("trait ref is local") > is_local_trait_ref ("local parameter") > is_local_parameter ("local parameter")
+                     judgment `is_local_trait_ref { goal: CoreTrait(<FooStruct as Unit>::Assoc), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "local trait" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `decls.is_local_trait_id(&goal.trait_id)`
+                           decls = decls(222, [trait CoreTrait <ty> , trait Unit <ty> ], [impl <ty> Unit(^ty0_0), impl CoreTrait(<FooStruct as Unit>::Assoc)], [], [alias <ty> <^ty0_0 as Unit>::Assoc = ()], [], [alias <ty> wf <^ty0_0 as Unit>::Assoc], [adt FooStruct ], [], [], {}, {FooStruct})
+                           &goal.trait_id = CoreTrait
+                     via prove > prove_wc_list ("some") > prove_wc ("trait ref is local")"#]]
     )
 }
 
@@ -240,27 +232,24 @@ fn CoreTrait_for_CoreStruct_in_Foo() {
             orphan_check(impl CoreTrait for CoreStruct { })
 
             Caused by:
-                judgment `prove { goal: {@ IsLocal(CoreTrait(CoreStruct))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait CoreTrait <ty> ], [impl CoreTrait(CoreStruct)], [], [], [], [adt CoreStruct ], {}, {}) }` failed at the following rule(s):
-                  failed at (src/file.rs:LL:CC) because
-                    judgment `prove_wc_list { goal: {@ IsLocal(CoreTrait(CoreStruct))}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                        judgment `prove_wc { goal: @ IsLocal(CoreTrait(CoreStruct)), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                          the rule "trait ref is local" failed at step #0 (src/file.rs:LL:CC) because
-                            judgment `is_local_trait_ref { goal: CoreTrait(CoreStruct), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                              the rule "local parameter" failed at step #1 (src/file.rs:LL:CC) because
-                                judgment `is_local_parameter { goal: CoreStruct, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                                  the rule "fundamental rigid type" failed at step #0 (src/file.rs:LL:CC) because
-                                    condition evaluted to false: `is_fundamental(&decls, &name)`
-                                      &decls = decls(222, [trait CoreTrait <ty> ], [impl CoreTrait(CoreStruct)], [], [], [], [adt CoreStruct ], {}, {})
-                                      &name = (adt CoreStruct)
-                                  the rule "local rigid type" failed at step #0 (src/file.rs:LL:CC) because
-                                    condition evaluted to false: `decls.is_local_adt_id(&a)`
-                                      decls = decls(222, [trait CoreTrait <ty> ], [impl CoreTrait(CoreStruct)], [], [], [], [adt CoreStruct ], {}, {})
-                                      &a = CoreStruct
-                              the rule "local trait" failed at step #0 (src/file.rs:LL:CC) because
-                                condition evaluted to false: `decls.is_local_trait_id(&goal.trait_id)`
-                                  decls = decls(222, [trait CoreTrait <ty> ], [impl CoreTrait(CoreStruct)], [], [], [], [adt CoreStruct ], {}, {})
-                                  &goal.trait_id = CoreTrait"#]]
+                0: the impl `impl CoreTrait for CoreStruct { }` violates the orphan rules
+                1: judgment `prove { goal: {@ IsLocal(CoreTrait(CoreStruct))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait CoreTrait <ty> ], [impl CoreTrait(CoreStruct)], [], [], [], [], [adt CoreStruct ], [], [], {}, {}) }` failed, most specifically because:
+                     judgment `is_local_parameter { goal: CoreStruct, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "fundamental rigid type" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `is_fundamental(&decls, &name)`
+                           &decls = decls(222, [trait CoreTrait <ty> ], [impl CoreTrait(CoreStruct)], [], [], [], [], [adt CoreStruct ], [], [], {}, {})
+                           &name = (adt CoreStruct)
+                       the rule "local rigid type" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `decls.is_local_adt_id(&a)`
+                           decls = decls(222, [trait CoreTrait <ty> ], [impl CoreTrait(CoreStruct)], [], [], [], [], [adt CoreStruct ], [], [], {}, {})
+                           &a = CoreStruct
+                     via prove > prove_wc_list ("some") > prove_wc ("trait ref is local") > is_local_trait_ref ("local parameter")
+                     judgment `is_local_trait_ref { goal: CoreTrait(CoreStruct), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "local trait" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `decls.is_local_trait_id(&goal.trait_id)`
+                           decls = decls(222, [trait CoreTrait <ty> ], [impl CoreTrait(CoreStruct)], [], [], [], [], [adt CoreStruct ], [], [], {}, {})
+                           &goal.trait_id = CoreTrait
+                     via prove > prove_wc_list ("some") > prove_wc ("trait ref is local")"#]]
     )
 }
 
@@ -18,27 +18,19 @@ fn nonsense_rigid_const_bound() {
 
             Caused by:
                 0: prove_where_clauses_well_formed([type_of_const value(0, bool) is u32])
-                1: judgment `prove { goal: {u32 = bool, @ wf(u32), @ wf(const value(0, bool))}, assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> where {@ ConstHasType(value(0, bool) , u32)}], [], [], [], [], [], {Foo}, {}) }` failed at the following rule(s):
-                     failed at (src/file.rs:LL:CC) because
-                       judgment `prove_wc_list { goal: {u32 = bool, @ wf(u32), @ wf(const value(0, bool))}, assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                         the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                           judgment `prove_wc { goal: u32 = bool, assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                             the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
-                               judgment had no applicable rules: `prove_via { goal: u32 = bool, via: @ ConstHasType(value(0, bool) , u32), assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }`
-                             the rule "eq" failed at step #0 (src/file.rs:LL:CC) because
-                               judgment `prove_eq { a: u32, b: bool, assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                                 the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
-                                   judgment `prove_normalize { p: u32, assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                                     the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
-                                       judgment had no applicable rules: `prove_normalize_via { goal: u32, via: @ ConstHasType(value(0, bool) , u32), assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }`
-                                 the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
-                                   judgment `prove_eq { a: bool, b: u32, assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                                     the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
-                                       judgment `prove_normalize { p: bool, assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                                         the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
-                                           judgment had no applicable rules: `prove_normalize_via { goal: bool, via: @ ConstHasType(value(0, bool) , u32), assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }`
-                                     the rule "symmetric" failed at step #0 (src/file.rs:LL:CC) because
-                                       cyclic proof attempt: `prove_eq { a: u32, b: bool, assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }`"#]]
+                1: judgment `prove { goal: {u32 = bool, @ wf(u32), @ wf(const value(0, bool))}, assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> where {@ ConstHasType(value(0, bool) , u32)}], [], [], [], [], [], [], [], [], {Foo}, {}) }` failed, most specifically because:
+                     judgment `prove_wc { goal: u32 = bool, assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                         judgment had no applicable rules: `prove_via { goal: u32 = bool, via: @ ConstHasType(value(0, bool) , u32), assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }`
+                     via prove > prove_wc_list ("some")
+                     judgment `prove_normalize { p: u32, assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                         judgment had no applicable rules: `prove_normalize_via { goal: u32, via: @ ConstHasType(value(0, bool) , u32), assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }`
+                     via prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("normalize-l")
+                     judgment `prove_normalize { p: bool, assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                         judgment had no applicable rules: `prove_normalize_via { goal: bool, via: @ ConstHasType(value(0, bool) , u32), assumptions: {@ ConstHasType(value(0, bool) , u32)}, env: Env { variables: [], bias: Soundness } }`
+                     via prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("symmetric") > prove_eq ("normalize-l")"#]]
     )
 }
 
@@ -76,13 +68,19 @@ fn mismatch() {
             check_trait_impl(impl Foo <const value(42, u32)> for u32 { })
 
             Caused by:
-                judgment `prove { goal: {Foo(u32, const value(42, u32))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty, const> where {@ ConstHasType(^const0_1 , bool)}], [impl Foo(u32, const value(42, u32))], [], [], [], [], {Foo}, {}) }` failed at the following rule(s):
-                  failed at (src/file.rs:LL:CC) because
-                    judgment `prove_wc_list { goal: {Foo(u32, const value(42, u32))}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                        judgment `prove_wc { goal: Foo(u32, const value(42, u32)), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                          the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
-                            expression evaluated to an empty collection: `decls.trait_invariants()`"#]]
+                judgment `prove { goal: {Foo(u32, const value(42, u32))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty, const> where {@ ConstHasType(^const0_1 , bool)}], [impl Foo(u32, const value(42, u32))], [], [], [], [], [], [], [], {Foo}, {}) }` failed, most specifically because:
+                  judgment `prove_eq { a: u32, b: bool, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                      judgment had no applicable rules: `prove_normalize { p: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                  via prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("const has ty") > prove > prove_wc_list ("some") > prove_wc ("eq")
+                  judgment `prove_eq { a: bool, b: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                      judgment had no applicable rules: `prove_normalize { p: bool, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                  via prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("const has ty") > prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("symmetric")
+                  judgment `prove_wc { goal: Foo(u32, const value(42, u32)), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                      expression evaluated to an empty collection: `decls.trait_invariants()`
+                  via prove > prove_wc_list ("some")"#]]
     )
 }
 
@@ -135,23 +133,20 @@ fn generic_mismatch() {
             check_trait_impl(impl <const> Foo <const ^const0_0> for u32 where type_of_const ^const0_0 is u32 { })
 
             Caused by:
-                judgment `prove { goal: {Foo(u32, const !const_0)}, assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness }, decls: decls(222, [trait Foo <ty, const> where {@ ConstHasType(^const0_1 , bool)}], [impl <const> Foo(u32, const ^const0_0) where {@ ConstHasType(^const0_0 , u32)}], [], [], [], [], {Foo}, {}) }` failed at the following rule(s):
-                  failed at (src/file.rs:LL:CC) because
-                    judgment `prove_wc_list { goal: {Foo(u32, const !const_0)}, assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness } }` failed at the following rule(s):
-                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                        judgment `prove_wc { goal: Foo(u32, const !const_0), assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness } }` failed at the following rule(s):
-                          the rule "positive impl" failed at step #7 (src/file.rs:LL:CC) because
-                            judgment `prove_after { constraints: Constraints { env: Env { variables: [!const_0, ?const_1], bias: Soundness }, known_true: true, substitution: {?const_1 => const !const_0} }, goal: {@ ConstHasType(?const_1 , bool)}, assumptions: {@ ConstHasType(!const_0 , u32)} }` failed at the following rule(s):
-                              the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
-                                judgment `prove { goal: {@ ConstHasType(!const_0 , bool)}, assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness }, decls: decls(222, [trait Foo <ty, const> where {@ ConstHasType(^const0_1 , bool)}], [impl <const> Foo(u32, const ^const0_0) where {@ ConstHasType(^const0_0 , u32)}], [], [], [], [], {Foo}, {}) }` failed at the following rule(s):
-                                  failed at (src/file.rs:LL:CC) because
-                                    judgment `prove_wc_list { goal: {@ ConstHasType(!const_0 , bool)}, assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness } }` failed at the following rule(s):
-                                      the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                                        judgment `prove_wc { goal: @ ConstHasType(!const_0 , bool), assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness } }` failed at the following rule(s):
-                                          the rule "const has ty" failed at step #0 (src/file.rs:LL:CC) because
-                                            pattern `Some((_, const_ty))` did not match value `None`
-                          the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
-                            expression evaluated to an empty collection: `decls.trait_invariants()`"#]]
+                judgment `prove { goal: {Foo(u32, const !const_0)}, assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness }, decls: decls(222, [trait Foo <ty, const> where {@ ConstHasType(^const0_1 , bool)}], [impl <const> Foo(u32, const ^const0_0) where {@ ConstHasType(^const0_0 , u32)}], [], [], [], [], [], [], [], {Foo}, {}) }` failed, most specifically because:
+                  judgment `prove_wc { goal: u32 = bool, assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness } }` failed at the following rule(s):
+                    the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                      judgment had no applicable rules: `prove_via { goal: u32 = bool, via: @ ConstHasType(!const_0 , u32), assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness } }`
+                  via prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("assumption - predicate") > prove_via ("predicate-congruence-axiom") > prove > prove_wc_list ("some")
+                  judgment `prove_normalize { p: u32, assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness } }` failed at the following rule(s):
+                    the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                      judgment had no applicable rules: `prove_normalize_via { goal: u32, via: @ ConstHasType(!const_0 , u32), assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness } }`
+                  via prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("assumption - predicate") > prove_via ("predicate-congruence-axiom") > prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("normalize-l")
+                  judgment `prove_normalize { p: bool, assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness } }` failed at the following rule(s):
+                    the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                      judgment had no applicable rules: `prove_normalize_via { goal: bool, via: @ ConstHasType(!const_0 , u32), assumptions: {@ ConstHasType(!const_0 , u32)}, env: Env { variables: [!const_0], bias: Soundness } }`
+                  via prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("assumption - predicate") > prove_via ("predicate-congruence-axiom") > prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("symmetric") > prove_eq ("normalize-l")
+                  ...and 2 less specific failure(s)"#]]
     )
 }
 
@@ -0,0 +1,292 @@
+/// Methods with a receiver are callable on a trait object, and items bounded by
+/// `where Self: Sized` are not, so neither prevents using the trait as `dyn Trait`.
+#[test]
+fn dyn_compatible_trait() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+
+                trait Foo {
+                    type Item : [Sized];
+
+                    fn by_ref<lt a>(&a Self, u32) -> <Self as Foo>::Item;
+                    fn by_mut<lt a>(&mut a Self) -> ();
+                    fn generic<ty T>(&static Self, T) -> () where Self: Sized;
+                    fn new() -> Self where Self: Sized;
+                }
+
+                fn use_foo<lt a>(&a dyn Foo<Item = u32> + a) -> () { trusted }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn generic_method() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Foo {
+                    fn generic<ty T>(&static Self, T) -> ();
+                }
+
+                fn use_foo(&static dyn Foo + static) -> () { trusted }
+            }
+        ]
+
+        [ "the trait `Foo` is not dyn compatible", "fn_binder.kinds().iter().all(|kind| *kind == ParameterKind::Lt)", ]
+
+        expect_test::expect![[r#"
+            the trait `Foo` is not dyn compatible
+
+            Caused by:
+                judgment `prove { goal: {@ DynCompatible(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [], [], [], [], [], [], [fn use_foo (&static dyn Foo + static) -> ()], [fn Foo :: generic <ty> <ty> (&static ^ty1_0, ^ty0_0) -> ()], {Foo}, {}) }` failed, most specifically because:
+                  judgment `prove_dyn_compatible_fn { trait_fn: fn Foo :: generic <ty> <ty> (&static ^ty1_0, ^ty0_0) -> (), _assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "dispatchable" failed at step #1 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `fn_binder.kinds().iter().all(|kind| *kind == ParameterKind::Lt)`
+                    the rule "requires Sized" failed at step #2 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `requires_sized_self(&_decls, &vars[0], &where_clause)`
+                  via prove > prove_wc_list ("some") > prove_wc ("dyn compatible") > prove_dyn_compatible ("dyn compatible")"#]]
+    )
+}
+
+#[test]
+fn method_without_receiver() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Foo {
+                    fn make() -> u32;
+                }
+
+                fn use_foo(&static dyn Foo + static) -> () { trusted }
+            }
+        ]
+
+        [ "the trait `Foo` is not dyn compatible", "pattern `Some((receiver, others))` did not match value `None`", ]
+
+        expect_test::expect![[r#"
+            the trait `Foo` is not dyn compatible
+
+            Caused by:
+                judgment `prove { goal: {@ DynCompatible(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [], [], [], [], [], [], [fn use_foo (&static dyn Foo + static) -> ()], [fn Foo :: make <ty> () -> u32], {Foo}, {}) }` failed, most specifically because:
+                  judgment `prove_dyn_compatible_fn { trait_fn: fn Foo :: make <ty> () -> u32, _assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "dispatchable" failed at step #3 (src/file.rs:LL:CC) because
+                      pattern `Some((receiver, others))` did not match value `None`
+                    the rule "requires Sized" failed at step #2 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `requires_sized_self(&_decls, &vars[0], &where_clause)`
+                  via prove > prove_wc_list ("some") > prove_wc ("dyn compatible") > prove_dyn_compatible ("dyn compatible")"#]]
+    )
+}
+
+/// `Self` may only appear as the receiver or as the self type of a projection.
+#[test]
+fn self_in_signature() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Foo {
+                    fn eq<lt a>(&a Self, &a Self) -> bool;
+                }
+
+                fn use_foo(&static dyn Foo + static) -> () { trusted }
+            }
+        ]
+
+        [ "the trait `Foo` is not dyn compatible", "mentions_outside_projections(ty, &vars[0])", ]
+
+        expect_test::expect![[r#"
+            the trait `Foo` is not dyn compatible
+
+            Caused by:
+                judgment `prove { goal: {@ DynCompatible(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [], [], [], [], [], [], [fn use_foo (&static dyn Foo + static) -> ()], [fn Foo :: eq <ty> <lt> (&^lt0_0 ^ty1_0, &^lt0_0 ^ty1_0) -> bool], {Foo}, {}) }` failed, most specifically because:
+                  judgment `prove_dyn_compatible_fn { trait_fn: fn Foo :: eq <ty> <lt> (&^lt0_0 ^ty1_0, &^lt0_0 ^ty1_0) -> bool, _assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "dispatchable" failed at step #5 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `!others.iter().chain(Some(&output_ty)).any(|ty|
+                      mentions_outside_projections(ty, &vars[0]))`
+                    the rule "requires Sized" failed at step #2 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `requires_sized_self(&_decls, &vars[0], &where_clause)`
+                  via prove > prove_wc_list ("some") > prove_wc ("dyn compatible") > prove_dyn_compatible ("dyn compatible")"#]]
+    )
+}
+
+#[test]
+fn sized_supertrait() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+                trait Foo where Self: Sized {}
+
+                fn use_foo(&static dyn Foo + static) -> () { trusted }
+            }
+        ]
+
+        [ "the trait `Foo` is not dyn compatible", "!requires_sized_self(&decls, &vars[0], &where_clause)", ]
+
+        expect_test::expect![[r#"
+            the trait `Foo` is not dyn compatible
+
+            Caused by:
+                judgment `prove { goal: {@ DynCompatible(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(sized)] trait Sized <ty> , trait Foo <ty> where {Sized(^ty0_0)}], [], [], [], [], [], [], [fn use_foo (&static dyn Foo + static) -> ()], [], {Foo, Sized}, {}) }` failed, most specifically because:
+                  judgment `prove_dyn_compatible { trait_id: Foo, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "dyn compatible" failed at step #1 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `!requires_sized_self(&decls, &vars[0], &where_clause)`
+                  via prove > prove_wc_list ("some") > prove_wc ("dyn compatible")"#]]
+    )
+}
+
+#[test]
+fn generic_associated_type() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Foo {
+                    type Item<lt a> : [];
+                }
+
+                fn use_foo(&static dyn Foo + static) -> () { trusted }
+            }
+        ]
+
+        [ "the trait `Foo` is not dyn compatible", "item_arity == 0", ]
+
+        expect_test::expect![[r#"
+            the trait `Foo` is not dyn compatible
+
+            Caused by:
+                judgment `prove { goal: {@ DynCompatible(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [], [], [], [], [alias <ty, lt> wf <^ty0_0 as Foo>::Item<^lt0_1>], [], [fn use_foo (&static dyn Foo + static) -> ()], [], {Foo}, {}) }` failed, most specifically because:
+                  judgment `prove_dyn_compatible_associated_ty { associated_ty: alias <ty, lt> wf <^ty0_0 as Foo>::Item<^lt0_1>, _assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "no parameters" failed at step #1 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `item_arity == 0`
+                    the rule "requires Sized" failed at step #1 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `requires_sized_self(&_decls, &vars[0], &where_clause)`
+                  via prove > prove_wc_list ("some") > prove_wc ("dyn compatible") > prove_dyn_compatible ("dyn compatible")"#]]
+    )
+}
+
+/// A trait is not dyn-compatible if one of its supertraits is not.
+#[test]
+fn incompatible_supertrait() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Super {
+                    fn make() -> u32;
+                }
+                trait Sub where Self: Super {}
+
+                fn use_sub(&static dyn Sub + static) -> () { trusted }
+            }
+        ]
+
+        [ "the trait `Sub` is not dyn compatible", "trait_fn: fn Super :: make", ]
+
+        expect_test::expect![[r#"
+            the trait `Sub` is not dyn compatible
+
+            Caused by:
+                judgment `prove { goal: {@ DynCompatible(Sub)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Super <ty> , trait Sub <ty> where {Super(^ty0_0)}], [], [], [], [], [], [], [fn use_sub (&static dyn Sub + static) -> ()], [fn Super :: make <ty> () -> u32], {Sub, Super}, {}) }` failed, most specifically because:
+                  judgment `prove_dyn_compatible_fn { trait_fn: fn Super :: make <ty> () -> u32, _assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "dispatchable" failed at step #3 (src/file.rs:LL:CC) because
+                      pattern `Some((receiver, others))` did not match value `None`
+                    the rule "requires Sized" failed at step #2 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `requires_sized_self(&_decls, &vars[0], &where_clause)`
+                  via prove > prove_wc_list ("some") > prove_wc ("dyn compatible") > prove_dyn_compatible ("dyn compatible") > prove_dyn_compatible ("dyn compatible")"#]]
+    )
+}
+
+/// A trait is not dyn-compatible if a supertrait takes `Self` as a parameter.
+#[test]
+fn self_in_supertrait() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Eq<ty T> {}
+                trait Foo where Self: Eq<Self> {}
+
+                fn use_foo(&static dyn Foo + static) -> () { trusted }
+            }
+        ]
+
+        [ "the trait `Foo` is not dyn compatible", "mentions(&t.parameters[1..], &vars[0])", ]
+
+        expect_test::expect![[r#"
+            the trait `Foo` is not dyn compatible
+
+            Caused by:
+                judgment `prove { goal: {@ DynCompatible(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Eq <ty, ty> , trait Foo <ty> where {Eq(^ty0_0, ^ty0_0)}], [], [], [], [], [], [], [fn use_foo (&static dyn Foo + static) -> ()], [], {Eq, Foo}, {}) }` failed, most specifically because:
+                  judgment `prove_dyn_compatible { trait_id: Foo, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "dyn compatible" failed at step #3 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `!supertrait_refs.iter().any(|t| mentions(&t.parameters[1..], &vars[0]))`
+                  via prove > prove_wc_list ("some") > prove_wc ("dyn compatible")"#]]
+    )
+}
+
+/// Trait object types of incompatible traits are reported in impl headers too.
+#[test]
+fn impl_for_dyn_incompatible_trait() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Foo {
+                    fn make() -> u32;
+                }
+                trait Bar {}
+
+                impl Bar for dyn Foo + static {}
+            }
+        ]
+
+        [ "the trait `Foo` is not dyn compatible", ]
+
+        expect_test::expect![[r#"
+            check_trait_impl(impl Bar for dyn Foo + static { })
+
+            Caused by:
+                0: the trait `Foo` is not dyn compatible
+                1: judgment `prove { goal: {@ DynCompatible(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> , trait Bar <ty> ], [impl Bar(dyn Foo + static)], [], [], [], [], [], [], [fn Foo :: make <ty> () -> u32], {Bar, Foo}, {}) }` failed, most specifically because:
+                     judgment `prove_dyn_compatible_fn { trait_fn: fn Foo :: make <ty> () -> u32, _assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "dispatchable" failed at step #3 (src/file.rs:LL:CC) because
+                         pattern `Some((receiver, others))` did not match value `None`
+                       the rule "requires Sized" failed at step #2 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `requires_sized_self(&_decls, &vars[0], &where_clause)`
+                     via prove > prove_wc_list ("some") > prove_wc ("dyn compatible") > prove_dyn_compatible ("dyn compatible")"#]]
+    )
+}
+
+/// ...and in the fields of ADTs.
+#[test]
+fn field_of_dyn_incompatible_trait() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Foo {
+                    fn make() -> u32;
+                }
+
+                struct Wrapper {
+                    value: &static dyn Foo + static,
+                }
+            }
+        ]
+
+        [ "the trait `Foo` is not dyn compatible", ]
+
+        expect_test::expect![[r#"
+            the trait `Foo` is not dyn compatible
+
+            Caused by:
+                judgment `prove { goal: {@ DynCompatible(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [], [], [], [], [], [adt Wrapper fields [&static dyn Foo + static]], [], [fn Foo :: make <ty> () -> u32], {Foo}, {Wrapper}) }` failed, most specifically because:
+                  judgment `prove_dyn_compatible_fn { trait_fn: fn Foo :: make <ty> () -> u32, _assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "dispatchable" failed at step #3 (src/file.rs:LL:CC) because
+                      pattern `Some((receiver, others))` did not match value `None`
+                    the rule "requires Sized" failed at step #2 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `requires_sized_self(&_decls, &vars[0], &where_clause)`
+                  via prove > prove_wc_list ("some") > prove_wc ("dyn compatible") > prove_dyn_compatible ("dyn compatible")"#]]
+    )
+}
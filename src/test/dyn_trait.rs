@@ -0,0 +1,181 @@
+/// A trait object implements its principal trait, the supertraits of that trait,
+/// and the auto traits it names.
+#[test]
+fn dyn_implements_its_traits() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                auto trait Send {}
+                trait Super {}
+                trait Sub where Self: Super {}
+
+                test {
+                    dyn Sub + static: Sub,
+                    dyn Sub + static: Super,
+                    dyn Sub + Send + static: Send,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn dyn_does_not_implement_unnamed_auto_traits() {
+    crate::assert_err!(
+        [
+            crate core {
+                auto trait Send {}
+                trait Foo {}
+
+                test {
+                    dyn Foo + static: Send,
+                }
+            }
+        ]
+
+        [ "the rule \"auto trait\" failed", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Send(dyn Foo + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [auto trait Send <ty> , trait Foo <ty> ], [], [], [], [], [], [], [], [], {Foo, Send}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Send(dyn Foo + static), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "auto trait" failed at step #3 (src/file.rs:LL:CC) because
+                  pattern `Some(components)` did not match value `None`
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+/// Trait objects are unsized.
+#[test]
+fn dyn_is_not_sized() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+                trait Foo {}
+
+                test {
+                    dyn Foo + static: Sized,
+                }
+            }
+        ]
+
+        [ "Sized(dyn Foo + static)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Sized(dyn Foo + static)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(sized)] trait Sized <ty> , trait Foo <ty> ], [], [], [], [], [], [], [], [], {Foo, Sized}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Sized(dyn Foo + static), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+/// The associated types of a trait object, including those of its supertraits,
+/// normalize to the values given in the type.
+#[test]
+fn dyn_projections_normalize() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Super { type Output : []; }
+                trait Iterator where Self: Super { type Item : []; }
+
+                fn next<lt a>(&mut a dyn Iterator<Item = u32, Output = ()> + a) -> <dyn Iterator<Item = u32, Output = ()> + a as Iterator>::Item { trusted }
+
+                test <lt a> {
+                    <dyn Iterator<Item = u32, Output = ()> + a as Iterator>::Item => u32,
+                    <dyn Iterator<Item = u32, Output = ()> + a as Super>::Output => (),
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+/// A trait object type must give the value of every associated type (cf. E0191).
+#[test]
+fn dyn_must_give_associated_types() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Iterator { type Item : []; }
+
+                fn next<lt a>(&mut a dyn Iterator + a) -> () { trusted }
+            }
+        ]
+
+        [ "names_associated_tys", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {@ wf(&mut !lt_0 dyn Iterator + !lt_0)}, assumptions: {dyn Iterator + !lt_0 : !lt_0}, env: Env { variables: [!lt_0], bias: Soundness }, decls: decls(222, [trait Iterator <ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Iterator>::Item], [], [fn next <lt> (&mut ^lt0_0 dyn Iterator + ^lt0_0) -> ()], [], {Iterator}, {}) }` failed, most specifically because:
+              judgment `prove_wf { goal: dyn Iterator + !lt_0, assumptions: {dyn Iterator + !lt_0 : !lt_0}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait objects" failed at step #2 (src/file.rs:LL:CC) because
+                  condition evaluted to false: `names_associated_tys(&decls, &dyn_name, &parameters)`
+              via prove > prove_wc_list ("some") > prove_wc ("parameter well formed") > prove_wf ("references") > "flat_map"
+              judgment `prove_wc { goal: @ wf(&mut !lt_0 dyn Iterator + !lt_0), assumptions: {dyn Iterator + !lt_0 : !lt_0}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_via { goal: @ wf(&mut !lt_0 dyn Iterator + !lt_0), via: dyn Iterator + !lt_0 : !lt_0, assumptions: {dyn Iterator + !lt_0 : !lt_0}, env: Env { variables: [!lt_0], bias: Soundness } }`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+/// The associated type values of a trait object type must meet the bounds of the associated types.
+#[test]
+fn dyn_projections_must_meet_item_bounds() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Copy {}
+                impl Copy for u32 {}
+                trait Tr { type A : [Copy]; }
+
+                fn ok<lt a>(&a dyn Tr<A = u32> + a) -> () { trusted }
+                fn not_copy<lt a>(&a dyn Tr<A = i32> + a) -> () { trusted }
+            }
+        ]
+
+        [ "judgment `prove_wc { goal: i32 = u32", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {@ wf(&!lt_0 dyn Tr<A = i32> + !lt_0)}, assumptions: {dyn Tr<A = i32> + !lt_0 : !lt_0}, env: Env { variables: [!lt_0], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Tr <ty> ], [impl Copy(u32)], [], [], [alias <ty> <^ty0_0 as Tr>::A : <ty> Copy(^ty0_0) where {Tr(^ty0_0)}], [alias <ty> wf <^ty0_0 as Tr>::A], [], [fn ok <lt> (&^lt0_0 dyn Tr<A = u32> + ^lt0_0) -> (), fn not_copy <lt> (&^lt0_0 dyn Tr<A = i32> + ^lt0_0) -> ()], [], {Copy, Tr}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: i32 = u32, assumptions: {dyn Tr<A = i32> + !lt_0 : !lt_0}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_via { goal: i32 = u32, via: dyn Tr<A = i32> + !lt_0 : !lt_0, assumptions: {dyn Tr<A = i32> + !lt_0 : !lt_0}, env: Env { variables: [!lt_0], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("parameter well formed") > prove_wf ("references") > "flat_map" > prove_wf ("trait objects") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove > prove_wc_list ("some")
+              judgment `prove_normalize { p: i32, assumptions: {dyn Tr<A = i32> + !lt_0 : !lt_0}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_normalize_via { goal: i32, via: dyn Tr<A = i32> + !lt_0 : !lt_0, assumptions: {dyn Tr<A = i32> + !lt_0 : !lt_0}, env: Env { variables: [!lt_0], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("parameter well formed") > prove_wf ("references") > "flat_map" > prove_wf ("trait objects") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("normalize-l")
+              judgment `prove_normalize { p: u32, assumptions: {dyn Tr<A = i32> + !lt_0 : !lt_0}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_normalize_via { goal: u32, via: dyn Tr<A = i32> + !lt_0 : !lt_0, assumptions: {dyn Tr<A = i32> + !lt_0 : !lt_0}, env: Env { variables: [!lt_0], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("parameter well formed") > prove_wf ("references") > "flat_map" > prove_wf ("trait objects") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("symmetric") > prove_eq ("normalize-l")
+              ...and 2 less specific failure(s)"#]]
+    )
+}
+
+/// An impl for a trait object can be local by way of its principal trait.
+#[test]
+fn impl_for_local_dyn() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate std {
+                trait Display {}
+            },
+            crate core {
+                trait Foo {}
+                impl Display for dyn Foo + static {}
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
@@ -0,0 +1,243 @@
+#![allow(non_snake_case)]
+
+/// The rustc error code of the error from checking the program.
+macro_rules! error_code {
+    ($program:tt) => {
+        formality_check::error_code(&crate::test_program_ok(stringify!($program)).unwrap_err())
+    };
+}
+
+#[test]
+fn E0119_overlapping_impls() {
+    let code = error_code!([
+        crate core {
+            trait Foo {}
+            impl<ty T> Foo for T {}
+            impl Foo for u32 {}
+        }
+    ]);
+    assert_eq!(code, Some("E0119"));
+}
+
+#[test]
+fn E0117_orphan_impl() {
+    let code = error_code!([
+        crate core {
+            trait CoreTrait {}
+        },
+        crate foo {
+            impl CoreTrait for u32 {}
+        }
+    ]);
+    assert_eq!(code, Some("E0117"));
+}
+
+#[test]
+fn E0198_unsafe_negative_impl() {
+    let code = error_code!([
+        crate core {
+            unsafe trait Foo {}
+            unsafe impl !Foo for u32 {}
+        }
+    ]);
+    assert_eq!(code, Some("E0198"));
+}
+
+#[test]
+fn E0199_unsafe_impl_of_safe_trait() {
+    let code = error_code!([
+        crate core {
+            trait Foo {}
+            unsafe impl Foo for u32 {}
+        }
+    ]);
+    assert_eq!(code, Some("E0199"));
+}
+
+#[test]
+fn E0200_safe_impl_of_unsafe_trait() {
+    let code = error_code!([
+        crate core {
+            unsafe trait Foo {}
+            impl Foo for u32 {}
+        }
+    ]);
+    assert_eq!(code, Some("E0200"));
+}
+
+#[test]
+fn E0407_fn_not_in_trait() {
+    let code = error_code!([
+        crate core {
+            trait Foo {}
+            impl Foo for u32 {
+                fn bar() -> () { trusted }
+            }
+        }
+    ]);
+    assert_eq!(code, Some("E0407"));
+}
+
+#[test]
+fn E0046_missing_trait_item() {
+    let code = error_code!([
+        crate core {
+            trait Foo {
+                fn foo() -> ();
+            }
+            impl Foo for u32 {}
+        }
+    ]);
+    assert_eq!(code, Some("E0046"));
+}
+
+#[test]
+fn E0116_inherent_impl_for_foreign_type() {
+    let code = error_code!([
+        crate core {
+            struct Foo {}
+        },
+        crate foo {
+            impl Foo {}
+        }
+    ]);
+    assert_eq!(code, Some("E0116"));
+}
+
+#[test]
+fn E0390_inherent_impl_for_scalar() {
+    let code = error_code!([
+        crate core {
+            impl u32 {}
+        }
+    ]);
+    assert_eq!(code, Some("E0390"));
+}
+
+#[test]
+fn E0592_duplicate_inherent_fn() {
+    let code = error_code!([
+        crate core {
+            struct Foo<ty T> {}
+            impl<ty T> Foo<T> { fn get() -> () { trusted } }
+            impl Foo<u32> { fn get() -> () { trusted } }
+        }
+    ]);
+    assert_eq!(code, Some("E0592"));
+}
+
+#[test]
+fn E0049_wrong_number_of_type_parameters() {
+    let code = error_code!([
+        crate core {
+            trait Foo { type Item<ty T> : []; }
+            impl Foo for u32 { type Item = u32; }
+        }
+    ]);
+    assert_eq!(code, Some("E0049"));
+}
+
+#[test]
+fn E0195_wrong_number_of_lifetime_parameters() {
+    let code = error_code!([
+        crate core {
+            trait Foo { fn get<lt a>(&a u32) -> (); }
+            impl Foo for u32 { fn get(&static u32) -> () { trusted } }
+        }
+    ]);
+    assert_eq!(code, Some("E0195"));
+}
+
+#[test]
+fn E0276_impl_stricter_than_trait() {
+    let code = error_code!([
+        crate core {
+            trait Copy {}
+            trait Foo { type Item<ty T> : []; }
+            impl Foo for u32 { type Item<ty T> = T where T: Copy; }
+        }
+    ]);
+    assert_eq!(code, Some("E0276"));
+}
+
+#[test]
+fn E0038_dyn_incompatible_trait() {
+    let code = error_code!([
+        crate core {
+            trait Foo {
+                fn make() -> u32;
+            }
+            fn use_foo(&static dyn Foo + static) -> () { trusted }
+        }
+    ]);
+    assert_eq!(code, Some("E0038"));
+
+    let code = error_code!([
+        crate core {
+            trait Foo {
+                fn make() -> u32;
+            }
+            trait Bar {}
+            impl Bar for dyn Foo + static {}
+        }
+    ]);
+    assert_eq!(code, Some("E0038"));
+}
+
+#[test]
+fn E0204_copy_impl_with_non_copy_field() {
+    let code = error_code!([
+        crate core {
+            #[lang(copy)] trait Copy {}
+
+            struct Foo {}
+            struct Bar { foo: Foo }
+            impl Copy for Bar {}
+        }
+    ]);
+    assert_eq!(code, Some("E0204"));
+}
+
+/// Errors without a corresponding rustc error code have none.
+#[test]
+fn no_code_for_unproven_goal() {
+    let code = error_code!([
+        crate core {
+            trait Foo {}
+            test {
+                u32: Foo,
+            }
+        }
+    ]);
+    assert_eq!(code, None);
+}
+
+/// As in rustc, a missing required bound on a generic associated type has no code.
+#[test]
+fn no_code_for_missing_required_bound() {
+    let error = crate::test_program_ok(stringify!([
+        crate core {
+            trait LendingIterator {
+                type Item<lt a> : [];
+
+                fn next<lt a>(&mut a Self) -> <Self as LendingIterator>::Item<a>;
+            }
+        }
+    ]))
+    .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref(),
+        Some(formality_check::CheckError::MissingRequiredBound { .. })
+    ));
+    assert_eq!(formality_check::error_code(&error), None);
+}
+
+/// The code is found below the context describing where the error occurred.
+#[test]
+fn code_with_item_location() {
+    let error = crate::test_program_with_spans(
+        "[crate core { trait Foo {} impl Foo for u32 {} impl Foo for u32 {} }]",
+    )
+    .unwrap_err();
+    assert_eq!(formality_check::error_code(&error), Some("E0119"));
+}
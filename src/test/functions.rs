@@ -30,8 +30,9 @@ fn ok() {
 
 #[test]
 fn lifetime() {
-    crate::assert_err!(
+    crate::assert_ok!(
         // Test lifetimes on function
+        //@check-pass
         [
             crate Foo {
                 // fn one_lt_arg<'a, T>(_: &'a T) -> () {}
@@ -39,15 +40,76 @@ fn lifetime() {
             }
         ]
 
-        [ /* TODO */ ]
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn impl_fn_with_more_general_signature() {
+    crate::assert_ok!(
+        // An impl fn may accept more and return less than the trait fn promises
+        //@check-pass
+        [
+            crate Foo {
+                trait Get {
+                    fn get<lt a>(&a u32) -> &a u32;
+                }
+
+                struct Bar {}
+
+                impl Get for Bar {
+                    // fn get<'a>(_: &'a u32) -> &'static u32 {}
+                    fn get<lt a>(&a u32) -> &static u32 { trusted }
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn impl_fn_with_less_general_signature() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                trait Get {
+                    fn get<lt a>(&a u32) -> &static u32;
+                }
+
+                struct Bar {}
+
+                impl Get for Bar {
+                    fn get<lt a>(&a u32) -> &a u32 { trusted }
+                }
+            }
+        ]
+
+        [ "judgment `prove_outlives { a: !lt_0, b: static", ]
 
         expect_test::expect![[r#"
-            judgment `prove { goal: {@ wf(&!lt_0 !ty_1)}, assumptions: {}, env: Env { variables: [!lt_0, !ty_1], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], {}, {}) }` failed at the following rule(s):
-              failed at (src/file.rs:LL:CC) because
-                judgment `prove_wc_list { goal: {@ wf(&!lt_0 !ty_1)}, assumptions: {}, env: Env { variables: [!lt_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
-                  the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                    judgment `prove_wc { goal: @ wf(&!lt_0 !ty_1), assumptions: {}, env: Env { variables: [!lt_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
-                      the rule "parameter well formed" failed at step #0 (src/file.rs:LL:CC) because
-                        judgment had no applicable rules: `prove_wf { goal: &!lt_0 !ty_1, assumptions: {}, env: Env { variables: [!lt_0, !ty_1], bias: Soundness } }`"#]]
+            check_trait_impl(impl Get for Bar { fn get <lt> (&^lt0_0 u32) -> &^lt0_0 u32 { trusted} })
+
+            Caused by:
+                judgment `prove { goal: {&!lt_0 u32 <: &static u32}, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness }, decls: decls(222, [trait Get <ty> ], [impl Get(Bar)], [], [], [], [], [adt Bar ], [], [fn Get :: get <ty> <lt> (&^lt0_0 u32) -> &static u32], {Get}, {Bar}) }` failed, most specifically because:
+                  judgment `prove_outlives { a: !lt_0, b: static, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                    the rule "existential lifetimes" failed at step #0 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `is_existential(&a, ParameterKind::Lt) ||
+                      (is_universal(&a) && is_existential(&b, ParameterKind::Lt))`
+                    the rule "existential types" failed at step #0 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `is_existential(&a, ParameterKind::Ty)`
+                        &a = !lt_0
+                        ParameterKind::Ty = ty
+                    the rule "transitive" failed at step #0 (src/file.rs:LL:CC) because
+                      expression evaluated to an empty collection: `&assumptions`
+                  via prove > prove_wc_list ("some") > prove_wc ("subtype") > prove_sub ("rigid") > prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("subtype") > prove_sub ("lifetimes")
+                  judgment `prove_sub { a: !lt_0, b: static, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                    the rule "equate" failed at step #0 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `a.kind() != ParameterKind::Lt`
+                  via prove > prove_wc_list ("some") > prove_wc ("subtype") > prove_sub ("rigid") > prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("subtype")
+                  judgment `prove_sub { a: &!lt_0 u32, b: &static u32, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                    the rule "equate" failed at step #1 (src/file.rs:LL:CC) because
+                      condition evaluted to false: `!(is_rigid(&a) && is_rigid(&b))`
+                  via prove > prove_wc_list ("some") > prove_wc ("subtype")"#]]
     )
 }
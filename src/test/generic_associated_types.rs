@@ -0,0 +1,289 @@
+/// The classic lending iterator: the GAT declares the bound that `next` relies on.
+#[test]
+fn lending_iterator() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait LendingIterator {
+                    type Item<lt a> : [] where Self: a;
+
+                    fn next<lt a>(&mut a Self) -> <Self as LendingIterator>::Item<a>;
+                }
+
+                struct WindowsMut<ty T> {}
+
+                impl<ty T> LendingIterator for WindowsMut<T> {
+                    type Item<lt a> = &mut a T where WindowsMut<T>: a;
+
+                    fn next<lt a>(&mut a WindowsMut<T>) -> &mut a T { trusted }
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+/// The value `&'a mut T` is only well-formed if `T: 'a`, which the impl item
+/// must require, as rustc does (E0309).
+#[test]
+fn gat_value_not_well_formed() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait LendingIterator {
+                    type Item<lt a> : [] where Self: a;
+
+                    fn next<lt a>(&mut a Self) -> <Self as LendingIterator>::Item<a>;
+                }
+
+                struct WindowsMut<ty T> {}
+
+                impl<ty T> LendingIterator for WindowsMut<T> {
+                    type Item<lt a> = &mut a T;
+
+                    fn next<lt a>(&mut a WindowsMut<T>) -> &mut a T { trusted }
+                }
+            }
+        ]
+
+        [ "prove_outlives { a: !ty_0, b: !lt_1", ]
+
+        expect_test::expect![[r#"
+            check_trait_impl(impl <ty> LendingIterator for WindowsMut<^ty0_0> { type Item <lt> = &mut ^lt0_0 ^ty1_0 ; fn next <lt> (&mut ^lt0_0 WindowsMut<^ty1_0>) -> &mut ^lt0_0 ^ty1_0 { trusted} })
+
+            Caused by:
+                0: check_associated_ty_value(type Item <lt> = &mut ^lt0_0 !ty_1 ;)
+                1: judgment `prove { goal: {@ wf(&mut !lt_0 !ty_1)}, assumptions: {}, env: Env { variables: [!ty_1, !lt_0], bias: Soundness }, decls: decls(222, [trait LendingIterator <ty> ], [impl <ty> LendingIterator(WindowsMut<^ty0_0>)], [], [alias <ty, lt> <WindowsMut<^ty0_0> as LendingIterator>::Item<^lt0_1> = &mut ^lt0_1 ^ty0_0], [], [alias <ty, lt> wf <^ty0_0 as LendingIterator>::Item<^lt0_1> where {^ty0_0 : ^lt0_1}], [adt WindowsMut <ty>  variances [=]], [], [fn LendingIterator :: next <ty> <lt> (&mut ^lt0_0 ^ty1_0) -> <^ty1_0 as LendingIterator>::Item<^lt0_0>], {LendingIterator}, {WindowsMut}) }` failed, most specifically because:
+                     judgment `prove_outlives { a: !ty_0, b: !lt_1, assumptions: {}, env: Env { variables: [!ty_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                       the rule "existential lifetimes" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `is_existential(&a, ParameterKind::Lt) ||
+                         (is_universal(&a) && is_existential(&b, ParameterKind::Lt))`
+                       the rule "existential types" failed at step #0 (src/file.rs:LL:CC) because
+                         condition evaluted to false: `is_existential(&a, ParameterKind::Ty)`
+                           &a = !ty_0
+                           ParameterKind::Ty = ty
+                       the rule "transitive" failed at step #0 (src/file.rs:LL:CC) because
+                         expression evaluated to an empty collection: `&assumptions`
+                     via prove > prove_wc_list ("some") > prove_wc ("parameter well formed") > prove_wf ("references") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("outlives")"#]]
+    )
+}
+
+/// Without `where Self: 'a`, an impl could not give `Item<'a>` a value that borrows from `Self`
+/// (cf. `self-outlives-lint.rs`).
+#[test]
+fn missing_required_bound() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait LendingIterator {
+                    type Item<lt a> : [];
+
+                    fn next<lt a>(&mut a Self) -> <Self as LendingIterator>::Item<a>;
+                }
+            }
+        ]
+
+        [ "missing required bound on `Item`", ]
+
+        expect_test::expect![[r#"
+            check_trait(LendingIterator)
+
+            Caused by:
+                missing required bound on `Item`: the signature of `next` implies `!ty_1 : !lt_2`, which must be a where-clause of `Item`"#]]
+    )
+}
+
+/// Bounds are only required when the GAT is applied to generic parameters of the fn.
+#[test]
+fn no_required_bound_for_concrete_args() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Foo {
+                    type Item<lt a> : [];
+
+                    fn get(&static Self) -> <Self as Foo>::Item<static>;
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+/// A bound between parameters of the GAT itself is required as well.
+#[test]
+fn missing_required_bound_between_gat_parameters() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Deserializer {
+                    type Out<lt x, ty T> : [];
+
+                    fn deserialize<lt x, ty T>(&x T) -> <Self as Deserializer>::Out<x, T>;
+                }
+            }
+        ]
+
+        [ "missing required bound on `Out`", ]
+
+        expect_test::expect![[r#"
+            check_trait(Deserializer)
+
+            Caused by:
+                missing required bound on `Out`: the signature of `deserialize` implies `!ty_3 : !lt_2`, which must be a where-clause of `Out`"#]]
+    )
+}
+
+#[test]
+fn required_bound_between_gat_parameters() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Deserializer {
+                    type Out<lt x, ty T> : [] where T: x;
+
+                    fn deserialize<lt x, ty T>(&x T) -> <Self as Deserializer>::Out<x, T>;
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+/// The where-clauses of an impl's associated type must be implied by the trait's
+/// (cf. `impl_bounds.rs`).
+#[test]
+fn impl_where_clauses_stricter_than_trait() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Copy {}
+
+                trait Foo {
+                    type Item<ty T> : [];
+                }
+
+                impl Foo for u32 {
+                    type Item<ty T> = T where T: Copy;
+                }
+            }
+        ]
+
+        [ "impl has stricter requirements than trait for `Item`", ]
+
+        expect_test::expect![[r#"
+            check_trait_impl(impl Foo for u32 { type Item <ty> = ^ty0_0 where ^ty0_0 : Copy ; })
+
+            Caused by:
+                0: check_associated_ty_value(type Item <ty> = ^ty0_0 where ^ty0_0 : Copy ;)
+                1: impl has stricter requirements than trait for `Item`
+                2: judgment `prove { goal: {Copy(!ty_0)}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Foo <ty> ], [impl Foo(u32)], [], [alias <ty> <u32 as Foo>::Item<^ty0_0> = ^ty0_0 where {Copy(^ty0_0)}], [], [alias <ty, ty> wf <^ty0_0 as Foo>::Item<^ty0_1>], [], [], [], {Copy, Foo}, {}) }` failed, most specifically because:
+                     judgment `prove_wc { goal: Copy(!ty_0), assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                       the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                         expression evaluated to an empty collection: `decls.trait_invariants()`
+                     via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn impl_where_clauses_implied_by_trait() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Copy {}
+                trait Clone where Self: Copy {}
+
+                trait Foo {
+                    type Item<ty T> : [] where T: Clone;
+                }
+
+                impl Foo for u32 {
+                    type Item<ty T> = T where T: Copy;
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+/// The impl must have the same generic parameters as the trait's declaration
+/// (cf. `parameter_number_and_kind_impl.rs`).
+#[test]
+fn impl_with_wrong_generic_parameters() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Foo {
+                    type Item<lt a> : [];
+                }
+
+                impl Foo for u32 {
+                    type Item<ty T> = T;
+                }
+            }
+        ]
+
+        [ "`Item` has generic parameters [ty] but its trait declaration has [lt]", ]
+
+        expect_test::expect![[r#"
+            check_trait_impl(impl Foo for u32 { type Item <ty> = ^ty0_0 ; })
+
+            Caused by:
+                0: check_associated_ty_value(type Item <ty> = ^ty0_0 ;)
+                1: `Item` has generic parameters [ty] but its trait declaration has [lt]"#]]
+    )
+}
+
+/// A GAT whose parameters are bound by a `for<..>` normalizes under the binder.
+#[test]
+fn normalize_higher_ranked_gat() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Family {
+                    type Member<lt a> : [];
+                }
+
+                impl Family for u32 {
+                    type Member<lt a> = &a u32;
+                }
+
+                test {
+                    for<lt a> <u32 as Family>::Member<a> => &a u32,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+/// A higher-ranked normalization assumed for a generic type applies to each of its instances.
+#[test]
+fn assume_higher_ranked_gat_normalization() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Family {
+                    type Member<lt a> : [];
+                }
+
+                test <ty T> where T: Family, for<lt a> <T as Family>::Member<a> => &a u32 {
+                    <T as Family>::Member<static> => &static u32,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
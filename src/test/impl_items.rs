@@ -0,0 +1,286 @@
+#[test]
+fn missing_fn() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Foo {
+                    fn foo() -> ();
+                }
+
+                impl Foo for u32 {}
+            }
+        ]
+
+        [ "missing: `foo`", ]
+
+        expect_test::expect![[r#"
+            check_trait_impl(impl Foo for u32 { })
+
+            Caused by:
+                not all items of the trait `Foo` are implemented, missing: `foo`"#]]
+    )
+}
+
+#[test]
+fn missing_associated_ty() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Foo {
+                    type Item : [];
+                    fn foo() -> ();
+                }
+
+                impl Foo for u32 {
+                    fn foo() -> () { trusted }
+                }
+            }
+        ]
+
+        [ "missing: `Item`", ]
+
+        expect_test::expect![[r#"
+            check_trait_impl(impl Foo for u32 { fn foo () -> () { trusted} })
+
+            Caused by:
+                not all items of the trait `Foo` are implemented, missing: `Item`"#]]
+    )
+}
+
+#[test]
+fn all_missing_items_are_reported() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Foo {
+                    type Item : [];
+                    fn foo() -> ();
+                    fn bar() -> () { trusted }
+                    fn baz() -> ();
+                }
+
+                impl Foo for u32 {
+                    fn baz() -> () { trusted }
+                }
+            }
+        ]
+
+        [ "missing: `Item`, `foo`", ]
+
+        expect_test::expect![[r#"
+            check_trait_impl(impl Foo for u32 { fn baz () -> () { trusted} })
+
+            Caused by:
+                not all items of the trait `Foo` are implemented, missing: `Item`, `foo`"#]]
+    )
+}
+
+/// Items with a default body or type may be omitted.
+#[test]
+fn defaults_may_be_omitted() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Foo {
+                    type Item : [] = u32;
+                    fn foo() -> () { trusted }
+                }
+
+                impl Foo for u32 {}
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+/// Items with defaults may also be given by the impl.
+#[test]
+fn defaults_may_be_overridden() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Foo {
+                    type Item : [] = u32;
+                    fn foo() -> () { trusted }
+                }
+
+                impl Foo for u32 {
+                    type Item = i32;
+                    fn foo() -> () { trusted }
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn default_ty_must_satisfy_bounds() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Bar {}
+
+                trait Foo {
+                    type Item : [Bar] = u32;
+                }
+            }
+        ]
+
+        [ "goal: {Bar(u32)}", ]
+
+        expect_test::expect![[r#"
+            check_trait(Foo)
+
+            Caused by:
+                judgment `prove { goal: {Bar(u32)}, assumptions: {Foo(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait Bar <ty> , trait Foo <ty> ], [], [], [], [alias <ty> <^ty0_0 as Foo>::Item : <ty> Bar(^ty0_0) where {Foo(^ty0_0)}], [alias <ty> wf <^ty0_0 as Foo>::Item], [], [], [], {Bar, Foo}, {}) }` failed, most specifically because:
+                  judgment `prove_wc { goal: Bar(u32), assumptions: {Foo(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                    the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                      expression evaluated to an empty collection: `decls.trait_invariants()`
+                  via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn default_ty_may_rely_on_where_clauses() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Bar {}
+
+                trait Foo {
+                    type Item<ty T> : [Bar] where T: Bar = T;
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn omitted_associated_ty_normalizes_to_default() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Foo {
+                    type Item : [] = Vec<Self>;
+                }
+
+                struct Vec<ty T> {}
+
+                impl Foo for u32 {}
+
+                test {
+                    <u32 as Foo>::Item => Vec<u32>,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn default_from_another_crate() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                trait Foo {
+                    type Item : [] = u32;
+                }
+            },
+            crate foo {
+                struct Bar {}
+
+                impl Foo for Bar {}
+
+                test {
+                    <Bar as Foo>::Item => u32,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn overridden_default_does_not_apply() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Foo {
+                    type Item : [] = u32;
+                }
+
+                impl Foo for u32 {
+                    type Item = i32;
+                }
+
+                test {
+                    <u32 as Foo>::Item => u32,
+                }
+            }
+        ]
+
+        [ "[alias <u32 as Foo>::Item = i32]", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {<u32 as Foo>::Item, u32}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [impl Foo(u32)], [], [alias <u32 as Foo>::Item = i32], [], [alias <ty> wf <^ty0_0 as Foo>::Item], [], [], [], {Foo}, {}) }` failed, most specifically because:
+              judgment `prove_eq { a: u32, b: <u32 as Foo>::Item, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_normalize { p: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("alias eq") > prove_eq ("symmetric")
+              judgment `prove_eq { a: i32, b: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_normalize { p: i32, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("alias eq") > prove_eq ("normalize-l") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("eq")
+              judgment `prove_eq { a: u32, b: i32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_normalize { p: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("alias eq") > prove_eq ("normalize-l") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("symmetric")"#]]
+    )
+}
+
+/// An impl might override the default, so generic code cannot assume it.
+#[test]
+fn default_cannot_be_assumed_generically() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Foo {
+                    type Item : [] = u32;
+                }
+
+                test <ty T> where T: Foo {
+                    <T as Foo>::Item => u32,
+                }
+            }
+        ]
+
+        [ "alias_eq_decls(&decls, &a)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {<!ty_0 as Foo>::Item, u32}, assumptions: {Foo(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait Foo <ty> ], [], [], [], [], [alias <ty> wf <^ty0_0 as Foo>::Item], [], [], [], {Foo}, {}) }` failed, most specifically because:
+              judgment `prove_normalize { p: <!ty_0 as Foo>::Item, assumptions: {Foo(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-via-impl" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `alias_eq_decls(&decls, &a)`
+              via prove > prove_wc_list ("some") > prove_wc ("alias eq") > prove_eq ("normalize-l")
+              judgment `prove_normalize { p: u32, assumptions: {Foo(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-via-assumption" failed at step #1 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_normalize_via { goal: u32, via: Foo(!ty_0), assumptions: {Foo(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_wc ("alias eq") > prove_eq ("symmetric") > prove_eq ("normalize-l")
+              judgment `prove_wc { goal: <!ty_0 as Foo>::Item, u32, assumptions: {Foo(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "assumption - predicate" failed at step #1 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_via { goal: <!ty_0 as Foo>::Item, u32, via: Foo(!ty_0), assumptions: {Foo(!ty_0)}, env: Env { variables: [!ty_0], bias: Soundness } }`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
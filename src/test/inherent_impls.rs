@@ -0,0 +1,183 @@
+#[test]
+fn inherent_impl_with_items() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                struct Foo<ty T> {
+                    value: T,
+                }
+
+                impl<ty T> Foo<T> {
+                    fn get(Foo<T>) -> T { trusted }
+                    type Value = T;
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn inherent_impl_for_foreign_adt() {
+    crate::assert_err!(
+        [
+            crate core {
+                struct Foo {}
+            },
+            crate foo {
+                impl Foo {}
+            }
+        ]
+
+        [ "not a type of the current crate", ]
+
+        expect_test::expect![[r#"
+            check_inherent_impl(impl Foo { })
+
+            Caused by:
+                cannot define an inherent impl for `Foo`, which is not a type of the current crate"#]]
+    )
+}
+
+#[test]
+fn inherent_impl_for_scalar() {
+    crate::assert_err!(
+        [
+            crate core {
+                impl u32 {}
+            }
+        ]
+
+        [ "not a type of the current crate", ]
+
+        expect_test::expect![[r#"
+            check_inherent_impl(impl u32 { })
+
+            Caused by:
+                cannot define an inherent impl for `u32`, which is not a type of the current crate"#]]
+    )
+}
+
+#[test]
+fn inherent_impl_not_well_formed() {
+    crate::assert_err!(
+        [
+            crate core {
+                trait Bar {}
+
+                struct Foo<ty T> where T: Bar {}
+
+                impl<ty T> Foo<T> {}
+            }
+        ]
+
+        [ "check_inherent_impl", ]
+
+        expect_test::expect![[r#"
+            check_inherent_impl(impl <ty> Foo<^ty0_0> { })
+
+            Caused by:
+                judgment `prove { goal: {@ wf(Foo<!ty_0>)}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait Bar <ty> ], [], [], [], [], [], [adt Foo <ty> where {Bar(^ty0_0)} variances [=]], [], [], {Bar}, {Foo}) }` failed, most specifically because:
+                  judgment `prove_wc { goal: Bar(!ty_0), assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                    the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                      expression evaluated to an empty collection: `decls.trait_invariants()`
+                  via prove > prove_wc_list ("some") > prove_wc ("parameter well formed") > prove_wf ("ADT") > prove_after ("prove_after") > prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn duplicate_fn_in_inherent_impl() {
+    crate::assert_err!(
+        [
+            crate core {
+                struct Foo {}
+
+                impl Foo {
+                    fn get() -> () { trusted }
+                    fn get() -> () { trusted }
+                }
+            }
+        ]
+
+        [ "the function name `get` is defined multiple times", ]
+
+        expect_test::expect![[r#"
+            check_inherent_impl(impl Foo { fn get () -> () { trusted} fn get () -> () { trusted} })
+
+            Caused by:
+                the function name `get` is defined multiple times"#]]
+    )
+}
+
+#[test]
+fn overlapping_inherent_impls_with_same_fn() {
+    crate::assert_err!(
+        [
+            crate core {
+                struct Foo<ty T> {}
+
+                impl<ty T> Foo<T> {
+                    fn get() -> () { trusted }
+                }
+
+                impl Foo<u32> {
+                    fn get() -> () { trusted }
+                }
+            }
+        ]
+
+        [ "duplicate definitions with name `get`", ]
+
+        expect_test::expect![[r#"
+            duplicate definitions with name `get`:
+            impl <ty> Foo<^ty0_0> { fn get () -> () { trusted} }
+            impl Foo<u32> { fn get () -> () { trusted} }"#]]
+    )
+}
+
+/// Overlapping inherent impls are fine as long as their items have distinct names.
+#[test]
+fn overlapping_inherent_impls_with_distinct_fns() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                struct Foo<ty T> {}
+
+                impl<ty T> Foo<T> {
+                    fn get() -> () { trusted }
+                }
+
+                impl Foo<u32> {
+                    fn set() -> () { trusted }
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn disjoint_inherent_impls_with_same_fn() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                struct Foo<ty T> {}
+
+                impl Foo<u32> {
+                    fn get() -> () { trusted }
+                }
+
+                impl Foo<i32> {
+                    fn get() -> () { trusted }
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
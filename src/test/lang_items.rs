@@ -0,0 +1,262 @@
+#![allow(non_snake_case)]
+
+#[test]
+fn builtin_copy_and_clone() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(copy)] trait Copy {}
+                #[lang(clone)] trait Clone {}
+
+                test {
+                    u32: Copy,
+                    &static u32: Copy,
+                    (u32, bool): Copy,
+                    (): Copy,
+                    (u32, &static u32): Clone,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn builtin_copy_for_tuple_of_parameters() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(copy)] trait Copy {}
+
+                test <ty T> where T: Copy {
+                    (T, u32): Copy,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn mut_ref_not_copy() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(copy)] trait Copy {}
+
+                test {
+                    &mut static u32: Copy,
+                }
+            }
+        ]
+
+        [ "judgment `prove_wc { goal: Copy(&mut static u32)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Copy(&mut static u32)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(copy)] trait Copy <ty> ], [], [], [], [], [], [], [], [], {Copy}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Copy(&mut static u32), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn tuple_with_non_copy_element() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(copy)] trait Copy {}
+
+                struct Foo {}
+
+                test {
+                    (u32, Foo): Copy,
+                }
+            }
+        ]
+
+        [ "judgment `prove_wc { goal: Copy(Foo)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Copy((u32, Foo))}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(copy)] trait Copy <ty> ], [], [], [], [], [], [adt Foo ], [], [], {Copy}, {Foo}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Copy(Foo), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_wc ("built-in impl") > prove > prove_wc_list ("some")
+              judgment `prove_wc { goal: Copy((u32, Foo)), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn tuple_with_copy_adt() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(copy)] trait Copy {}
+
+                struct Foo {}
+                impl Copy for Foo {}
+
+                test {
+                    (u32, Foo): Copy,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn impl_copy_for_mut_ref() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(copy)] trait Copy {}
+
+                impl<lt a> Copy for &mut a u32 {}
+            }
+        ]
+
+        [ "cannot be implemented for", ]
+
+        expect_test::expect![[r#"
+            check_trait_impl(impl <lt> Copy for &mut ^lt0_0 u32 { })
+
+            Caused by:
+                the trait `Copy` cannot be implemented for `&mut !lt_1 u32`"#]]
+    )
+}
+
+/// An ADT can only be `Copy` if its fields are.
+#[test]
+fn impl_copy_with_non_copy_field() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(copy)] trait Copy {}
+
+                struct Foo {}
+                struct Bar { foo: Foo, x: u32 }
+                impl Copy for Bar {}
+            }
+        ]
+
+        [ "the field `foo` does not implement `Copy`", ]
+
+        expect_test::expect![[r#"
+            check_trait_impl(impl Copy for Bar { })
+
+            Caused by:
+                0: the trait `Copy` cannot be implemented for `Bar`: the field `foo` does not implement `Copy`
+                1: judgment `prove { goal: {Copy(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(copy)] trait Copy <ty> ], [impl Copy(Bar)], [], [], [], [], [adt Foo , adt Bar fields [Foo, u32]], [], [], {Copy}, {Bar, Foo}) }` failed, most specifically because:
+                     judgment `prove_eq { a: Foo, b: Bar, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                         judgment had no applicable rules: `prove_normalize { p: Foo, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                     via prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove > prove_wc_list ("some") > prove_wc ("eq")
+                     judgment `prove_eq { a: Bar, b: Foo, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                         judgment had no applicable rules: `prove_normalize { p: Bar, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                     via prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("symmetric")
+                     judgment `prove_wc { goal: Copy(Foo), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                       the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                         expression evaluated to an empty collection: `decls.trait_invariants()`
+                     via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+/// The fields of a generic ADT are `Copy` given the where-clauses of the impl.
+#[test]
+fn impl_copy_with_generic_field() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(copy)] trait Copy {}
+
+                struct Wrapper<ty T> { value: T }
+                impl<ty T> Copy for Wrapper<T> where T: Copy {}
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn builtin_sized() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+
+                struct Foo<ty T> { t: T }
+
+                test {
+                    Foo<u32>: Sized,
+                    (u32, &static Foo<u32>): Sized,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn infinitely_sized_adt() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+
+                struct Infinite { i: Infinite }
+
+                test {
+                    Infinite: Sized,
+                }
+            }
+        ]
+
+        [ "judgment `prove_wc { goal: Sized(Infinite)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Sized(Infinite)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(sized)] trait Sized <ty> ], [], [], [], [], [], [adt Infinite fields [Infinite]], [], [], {Sized}, {Infinite}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Sized(Infinite), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn impl_sized() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+
+                struct Foo {}
+                impl Sized for Foo {}
+            }
+        ]
+
+        [ "explicit impls for the `Sized` trait are not permitted", ]
+
+        expect_test::expect![[r#"
+            check_trait_impl(impl Sized for Foo { })
+
+            Caused by:
+                explicit impls for the `Sized` trait are not permitted"#]]
+    )
+}
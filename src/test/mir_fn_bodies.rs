@@ -0,0 +1,289 @@
+#![allow(non_snake_case)]
+
+#[test]
+fn ok() {
+    crate::assert_ok!(
+        // Test assignments, arithmetic, and aggregates in MIR bodies
+        //@check-pass
+        [
+            crate Foo {
+                struct Pair<ty T> { a: T, b: T }
+
+                // fn add_one(a: u32) -> u32 { a + 1 }
+                fn add_one(u32) -> u32 = mir(locals_and_blocks(
+                    [(mut ret: u32), (shared a: u32)],
+                    [basic_block_decl(bb0, [((ret) = apply(copy((a)), +, const(number(1, u32))))], return)]
+                ));
+
+                // fn first<T>(p: Pair<T>) -> T { p.a }
+                fn first<ty T>(Pair<T>) -> T = mir(locals_and_blocks(
+                    [(mut ret: T), (shared p: Pair<T>)],
+                    [basic_block_decl(bb0, [((ret) = use(move((p a))))], return)]
+                ));
+
+                // fn make(a: u32) -> (Pair<u32>, bool) { (Pair { a, b: a }, true) }
+                fn make(u32) -> (Pair<u32>, bool) = mir(locals_and_blocks(
+                    [(mut ret: (Pair<u32>, bool)), (shared a: u32), (mut p: Pair<u32>)],
+                    [basic_block_decl(bb0, [
+                        ((p) = aggregate(struct(Pair, [u32]), [copy((a)), copy((a))])),
+                        ((ret) = aggregate(tuple, [move((p)), const(true)]))
+                    ], return)]
+                ));
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn call() {
+    crate::assert_ok!(
+        // Test calling functions from a MIR body
+        //@check-pass
+        [
+            crate Foo {
+                fn is_zero(u32) -> bool { trusted }
+
+                // fn is_one(a: u32) -> bool { is_zero(a - 1) }
+                fn is_one(u32) -> bool = mir(locals_and_blocks(
+                    [(mut ret: bool), (shared a: u32), (mut b: u32)],
+                    [
+                        basic_block_decl(bb0, [((b) = apply(copy((a)), -, const(number(1, u32))))], call(const(fn_ptr(is_zero, [])), [copy((b))], (ret), [bb1])),
+                        basic_block_decl(bb1, [], return)
+                    ]
+                ));
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn assign_wrong_type() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                fn f(u32) -> bool = mir(locals_and_blocks(
+                    [(mut ret: bool), (shared a: u32)],
+                    [basic_block_decl(bb0, [((ret) = use(copy((a))))], return)]
+                ));
+            }
+        ]
+
+        [ "u32 <: bool", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {u32 <: u32, u32 <: bool, bool <: bool, @ wf(u32), @ wf(bool)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [fn f (u32) -> bool], [], {}, {}) }` failed, most specifically because:
+              judgment `prove_sub { a: u32, b: bool, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "equate" failed at step #1 (src/file.rs:LL:CC) because
+                  condition evaluted to false: `!(is_rigid(&a) && is_rigid(&b))`
+              via prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("subtype")"#]]
+    )
+}
+
+#[test]
+fn return_wrong_type() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                fn f(u32) -> bool = mir(locals_and_blocks(
+                    [(mut ret: u32), (shared a: u32)],
+                    [basic_block_decl(bb0, [((ret) = use(copy((a))))], return)]
+                ));
+            }
+        ]
+
+        [ "u32 <: bool", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {u32 <: u32, u32 <: bool, @ wf(u32)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [fn f (u32) -> bool], [], {}, {}) }` failed, most specifically because:
+              judgment `prove_sub { a: u32, b: bool, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "equate" failed at step #1 (src/file.rs:LL:CC) because
+                  condition evaluted to false: `!(is_rigid(&a) && is_rigid(&b))`
+              via prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("subtype")"#]]
+    )
+}
+
+#[test]
+fn call_wrong_argument_count() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                fn is_zero(u32) -> bool { trusted }
+
+                fn f(u32) -> bool = mir(locals_and_blocks(
+                    [(mut ret: bool), (shared a: u32)],
+                    [
+                        basic_block_decl(bb0, [], call(const(fn_ptr(is_zero, [])), [copy((a)), copy((a))], (ret), [bb1])),
+                        basic_block_decl(bb1, [], return)
+                    ]
+                ));
+            }
+        ]
+
+        [ "expects 1 argument(s) but 2 were supplied", ]
+
+        expect_test::expect!["`const(fn_ptr(is_zero, []))` expects 1 argument(s) but 2 were supplied"]
+    )
+}
+
+#[test]
+fn call_unknown_block() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                fn is_zero(u32) -> bool { trusted }
+
+                fn f(u32) -> bool = mir(locals_and_blocks(
+                    [(mut ret: bool), (shared a: u32)],
+                    [basic_block_decl(bb0, [], call(const(fn_ptr(is_zero, [])), [copy((a))], (ret), [bb1]))]
+                ));
+            }
+        ]
+
+        [ "no basic block named `bb1`", ]
+
+        expect_test::expect!["no basic block named `bb1`"]
+    )
+}
+
+#[test]
+fn lifetimes_flow_through_locals() {
+    crate::assert_ok!(
+        // The lifetimes of the locals can be chosen so that the argument flows to the return value
+        //@check-pass
+        [
+            crate Foo {
+                // fn f<'a>(x: &'a u32) -> &'a u32 { let y = x; y }
+                fn f<lt a>(&a u32) -> &a u32 = mir(<lt b, lt c> locals_and_blocks(
+                    [(mut ret: &c u32), (shared x: &b u32)],
+                    [basic_block_decl(bb0, [((ret) = use(copy((x))))], return)]
+                ));
+
+                // fn g<'a, 'b>(x: &'a u32) -> &'b u32 where 'a: 'b { x }
+                fn g<lt a, lt b>(&a u32) -> &b u32 where a : b = mir(<lt c> locals_and_blocks(
+                    [(mut ret: &b u32), (shared x: &c u32)],
+                    [basic_block_decl(bb0, [((ret) = use(copy((x))))], return)]
+                ));
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn return_shorter_lifetime() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                // fn f<'a>(x: &'a u32) -> &'static u32 { x }
+                fn f<lt a>(&a u32) -> &static u32 = mir(<lt b> locals_and_blocks(
+                    [(mut ret: &static u32), (shared x: &b u32)],
+                    [basic_block_decl(bb0, [((ret) = use(copy((x))))], return)]
+                ));
+            }
+        ]
+
+        [ "judgment `prove { goal: {!lt_0 : static}", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {!lt_0 : static}, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [fn f <lt> (&^lt0_0 u32) -> &static u32], [], {}, {}) }` failed, most specifically because:
+              judgment `prove_outlives { a: !lt_0, b: static, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "existential lifetimes" failed at step #0 (src/file.rs:LL:CC) because
+                  condition evaluted to false: `is_existential(&a, ParameterKind::Lt) ||
+                  (is_universal(&a) && is_existential(&b, ParameterKind::Lt))`
+                the rule "existential types" failed at step #0 (src/file.rs:LL:CC) because
+                  condition evaluted to false: `is_existential(&a, ParameterKind::Ty)`
+                    &a = !lt_0
+                    ParameterKind::Ty = ty
+                the rule "transitive" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `&assumptions`
+              via prove > prove_wc_list ("some") > prove_wc ("outlives")"#]]
+    )
+}
+
+#[test]
+fn return_unrelated_lifetime() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                // fn f<'a, 'b>(x: &'a u32) -> &'b u32 { x }
+                fn f<lt a, lt b>(&a u32) -> &b u32 = mir(<lt c> locals_and_blocks(
+                    [(mut ret: &b u32), (shared x: &c u32)],
+                    [basic_block_decl(bb0, [((ret) = use(copy((x))))], return)]
+                ));
+            }
+        ]
+
+        [ "judgment `prove { goal: {!lt_0 : !lt_1}", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {!lt_0 : !lt_1}, assumptions: {}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [], [fn f <lt, lt> (&^lt0_0 u32) -> &^lt0_1 u32], [], {}, {}) }` failed, most specifically because:
+              judgment `prove_outlives { a: !lt_0, b: !lt_1, assumptions: {}, env: Env { variables: [!lt_0, !lt_1], bias: Soundness } }` failed at the following rule(s):
+                the rule "existential lifetimes" failed at step #0 (src/file.rs:LL:CC) because
+                  condition evaluted to false: `is_existential(&a, ParameterKind::Lt) ||
+                  (is_universal(&a) && is_existential(&b, ParameterKind::Lt))`
+                the rule "existential types" failed at step #0 (src/file.rs:LL:CC) because
+                  condition evaluted to false: `is_existential(&a, ParameterKind::Ty)`
+                    &a = !lt_0
+                    ParameterKind::Ty = ty
+                the rule "transitive" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `&assumptions`
+              via prove > prove_wc_list ("some") > prove_wc ("outlives")"#]]
+    )
+}
+
+#[test]
+fn copy_requires_copy() {
+    crate::assert_ok!(
+        // Operands that are copied must have a type that implements `Copy`
+        //@check-pass
+        [
+            crate Foo {
+                #[lang(copy)] trait Copy {}
+
+                struct Meters { value: u32 }
+                impl Copy for Meters {}
+
+                // fn double(m: Meters) -> (Meters, Meters) { (m, m) }
+                fn double(Meters) -> (Meters, Meters) = mir(locals_and_blocks(
+                    [(mut ret: (Meters, Meters)), (shared m: Meters)],
+                    [basic_block_decl(bb0, [((ret) = aggregate(tuple, [copy((m)), copy((m))]))], return)]
+                ));
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn copy_of_non_copy_type() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                #[lang(copy)] trait Copy {}
+
+                struct Name { len: u32 }
+
+                // fn double(n: Name) -> (Name, Name) { (n, n) }
+                fn double(Name) -> (Name, Name) = mir(locals_and_blocks(
+                    [(mut ret: (Name, Name)), (shared n: Name)],
+                    [basic_block_decl(bb0, [((ret) = aggregate(tuple, [copy((n)), copy((n))]))], return)]
+                ));
+            }
+        ]
+
+        [ "Copy(Name)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Name <: Name, (Name, Name) <: (Name, Name), @ wf(Name), @ wf((Name, Name)), Copy(Name)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [#[lang(copy)] trait Copy <ty> ], [], [], [], [], [], [adt Name fields [u32]], [fn double (Name) -> (Name, Name)], [], {Copy}, {Name}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Copy(Name), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some")"#]]
+    )
+}
@@ -1,10 +1,27 @@
 #![allow(non_snake_case)]
 
+mod alias_bounds;
+mod alias_wf;
+mod all_errors;
+mod auto_traits;
+mod borrow_check;
+mod closures;
 mod coherence_orphan;
 mod coherence_overlap;
 mod consts;
 mod decl_safety;
+mod dyn_compatibility;
+mod dyn_trait;
+mod error_codes;
 mod functions;
+mod generic_associated_types;
+mod impl_items;
+mod inherent_impls;
+mod lang_items;
+mod mir_fn_bodies;
+mod sized;
+mod spans;
+mod variance;
 
 #[test]
 fn parser() {
@@ -46,19 +63,15 @@ fn hello_world_fail() {
 
             Caused by:
                 0: prove_where_clauses_well_formed([!ty_2 : Bar <!ty_1>])
-                1: judgment `prove { goal: {@ WellFormedTraitRef(Bar(!ty_0, !ty_1))}, assumptions: {Bar(!ty_0, !ty_1)}, env: Env { variables: [!ty_1, !ty_0], bias: Soundness }, decls: decls(222, [trait Foo <ty, ty> where {Bar(^ty0_1, ^ty0_0)}, trait Bar <ty, ty> where {Baz(^ty0_1)}, trait Baz <ty> ], [], [], [], [], [], {Bar, Baz, Foo}, {}) }` failed at the following rule(s):
-                     failed at (src/file.rs:LL:CC) because
-                       judgment `prove_wc_list { goal: {@ WellFormedTraitRef(Bar(!ty_0, !ty_1))}, assumptions: {Bar(!ty_0, !ty_1)}, env: Env { variables: [!ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
-                         the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                           judgment `prove_wc { goal: @ WellFormedTraitRef(Bar(!ty_0, !ty_1)), assumptions: {Bar(!ty_0, !ty_1)}, env: Env { variables: [!ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
-                             the rule "trait well formed" failed at step #2 (src/file.rs:LL:CC) because
-                               judgment `prove { goal: {Baz(!ty_1)}, assumptions: {Bar(!ty_0, !ty_1)}, env: Env { variables: [!ty_1, !ty_0], bias: Soundness }, decls: decls(222, [trait Foo <ty, ty> where {Bar(^ty0_1, ^ty0_0)}, trait Bar <ty, ty> where {Baz(^ty0_1)}, trait Baz <ty> ], [], [], [], [], [], {Bar, Baz, Foo}, {}) }` failed at the following rule(s):
-                                 failed at (src/file.rs:LL:CC) because
-                                   judgment `prove_wc_list { goal: {Baz(!ty_1)}, assumptions: {Bar(!ty_0, !ty_1)}, env: Env { variables: [!ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
-                                     the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                                       judgment `prove_wc { goal: Baz(!ty_1), assumptions: {Bar(!ty_0, !ty_1)}, env: Env { variables: [!ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
-                                         the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
-                                           expression evaluated to an empty collection: `decls.trait_invariants()`"#]]
+                1: judgment `prove { goal: {@ WellFormedTraitRef(Bar(!ty_0, !ty_1))}, assumptions: {Bar(!ty_0, !ty_1)}, env: Env { variables: [!ty_1, !ty_0], bias: Soundness }, decls: decls(222, [trait Foo <ty, ty> where {Bar(^ty0_1, ^ty0_0)}, trait Bar <ty, ty> where {Baz(^ty0_1)}, trait Baz <ty> ], [], [], [], [], [], [], [], [], {Bar, Baz, Foo}, {}) }` failed, most specifically because:
+                     judgment `prove_wc { goal: Baz(!ty_1), assumptions: {Bar(!ty_0, !ty_1)}, env: Env { variables: [!ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
+                       the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                         expression evaluated to an empty collection: `decls.trait_invariants()`
+                     via prove > prove_wc_list ("some") > prove_wc ("trait well formed") > prove > prove_wc_list ("some")
+                     judgment `prove_wc { goal: @ WellFormedTraitRef(Bar(!ty_0, !ty_1)), assumptions: {Bar(!ty_0, !ty_1)}, env: Env { variables: [!ty_1, !ty_0], bias: Soundness } }` failed at the following rule(s):
+                       the rule "assumption - predicate" failed at step #1 (src/file.rs:LL:CC) because
+                         judgment had no applicable rules: `prove_via { goal: @ WellFormedTraitRef(Bar(!ty_0, !ty_1)), via: Bar(!ty_0, !ty_1), assumptions: {Bar(!ty_0, !ty_1)}, env: Env { variables: [!ty_1, !ty_0], bias: Soundness } }`
+                     via prove > prove_wc_list ("some")"#]]
     )
 }
 
@@ -125,21 +138,19 @@ fn basic_where_clauses_fail() {
 
             Caused by:
                 0: prove_where_clauses_well_formed([for <ty> u32 : A <^ty0_0>])
-                1: judgment `prove { goal: {for <ty> @ WellFormedTraitRef(A(u32, ^ty0_0))}, assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait A <ty, ty> where {B(^ty0_1)}, trait B <ty> , trait WellFormed <ty> where {for <ty> A(u32, ^ty0_0)}], [], [], [], [], [], {A, B, WellFormed}, {}) }` failed at the following rule(s):
-                     failed at (src/file.rs:LL:CC) because
-                       judgment `prove_wc_list { goal: {for <ty> @ WellFormedTraitRef(A(u32, ^ty0_0))}, assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                         the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                           judgment `prove_wc { goal: for <ty> @ WellFormedTraitRef(A(u32, ^ty0_0)), assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
-                             the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
-                               judgment `prove_wc { goal: @ WellFormedTraitRef(A(u32, !ty_1)), assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
-                                 the rule "trait well formed" failed at step #2 (src/file.rs:LL:CC) because
-                                   judgment `prove { goal: {B(!ty_0)}, assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait A <ty, ty> where {B(^ty0_1)}, trait B <ty> , trait WellFormed <ty> where {for <ty> A(u32, ^ty0_0)}], [], [], [], [], [], {A, B, WellFormed}, {}) }` failed at the following rule(s):
-                                     failed at (src/file.rs:LL:CC) because
-                                       judgment `prove_wc_list { goal: {B(!ty_0)}, assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
-                                         the rule "some" failed at step #0 (src/file.rs:LL:CC) because
-                                           judgment `prove_wc { goal: B(!ty_0), assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
-                                             the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
-                                               expression evaluated to an empty collection: `decls.trait_invariants()`"#]]
+                1: judgment `prove { goal: {for <ty> @ WellFormedTraitRef(A(u32, ^ty0_0))}, assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait A <ty, ty> where {B(^ty0_1)}, trait B <ty> , trait WellFormed <ty> where {for <ty> A(u32, ^ty0_0)}], [], [], [], [], [], [], [], [], {A, B, WellFormed}, {}) }` failed, most specifically because:
+                     judgment `prove_via { goal: B(!ty_0), via: for <ty> A(u32, ^ty0_0), assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                       the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
+                         judgment had no applicable rules: `prove_via { goal: B(!ty_0), via: A(u32, ?ty_1), assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0, ?ty_1], bias: Soundness } }`
+                     via prove > prove_wc_list ("some") > prove_wc ("forall") > prove_wc ("trait well formed") > prove > prove_wc_list ("some") > prove_wc ("assumption - predicate")
+                     judgment `prove_wc { goal: B(!ty_0), assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                       the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                         expression evaluated to an empty collection: `decls.trait_invariants()`
+                     via prove > prove_wc_list ("some") > prove_wc ("forall") > prove_wc ("trait well formed") > prove > prove_wc_list ("some")
+                     judgment `prove_via { goal: @ WellFormedTraitRef(A(u32, !ty_1)), via: for <ty> A(u32, ^ty0_0), assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_1], bias: Soundness } }` failed at the following rule(s):
+                       the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
+                         judgment had no applicable rules: `prove_via { goal: @ WellFormedTraitRef(A(u32, !ty_1)), via: A(u32, ?ty_2), assumptions: {for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_1, ?ty_2], bias: Soundness } }`
+                     via prove > prove_wc_list ("some") > prove_wc ("forall") > prove_wc ("assumption - predicate")"#]]
     )
 }
 
@@ -0,0 +1,157 @@
+#![allow(non_snake_case)]
+
+#[test]
+fn type_parameters_are_implicitly_sized() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+
+                trait NeedsSized<ty T> where T: Sized {}
+
+                struct Wrapper<ty T> {
+                    value: T,
+                }
+
+                // fn f<T>(_: T) -> Wrapper<T> {}
+                fn f<ty T>(T) -> Wrapper<T> { trusted }
+
+                test <ty T> {
+                    T: Sized,
+                    Wrapper<T>: Sized,
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn maybe_sized_parameter_is_not_sized() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+
+                test <ty T> where T: ?Sized {
+                    T: Sized,
+                }
+            }
+        ]
+
+        [ "judgment `prove_wc { goal: Sized(!ty_0)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Sized(!ty_0)}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [#[lang(sized)] trait Sized <ty> ], [], [], [], [], [], [], [], [], {Sized}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Sized(!ty_0), assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn maybe_sized_parameter_behind_reference() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+
+                // fn f<T: ?Sized>(_: &T) {}
+                fn f<lt a, ty T>(&a T) -> () where T: ?Sized { trusted }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn trait_self_is_not_sized() {
+    crate::assert_ok!(
+        // `Self` is not `Sized` in a trait, but only a body needs it to be.
+        //@check-pass
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+
+                trait Foo {
+                    // fn by_value() -> Self;
+                    fn by_value() -> Self;
+
+                    // fn take(self);
+                    fn take(Self) -> ();
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn trait_self_sized_bound() {
+    crate::assert_ok!(
+        //@check-pass
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+
+                trait Foo {
+                    // fn by_value() -> Self where Self: Sized { ... }
+                    fn by_value() -> Self where Self: Sized { trusted }
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn unsized_fn_argument() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+
+                // fn f<T: ?Sized>(_: T) {}
+                fn f<ty T>(T) -> () where T: ?Sized { trusted }
+            }
+        ]
+
+        [ "judgment `prove_wc { goal: Sized(!ty_0)", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {Sized(()), Sized(!ty_0)}, assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [#[lang(sized)] trait Sized <ty> ], [], [], [], [], [], [], [fn f <ty> (^ty0_0) -> ()], [], {Sized}, {}) }` failed, most specifically because:
+              judgment `prove_wc { goal: Sized(!ty_0), assumptions: {}, env: Env { variables: [!ty_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                  expression evaluated to an empty collection: `decls.trait_invariants()`
+              via prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some")"#]]
+    )
+}
+
+#[test]
+fn maybe_bound_on_other_trait() {
+    crate::assert_err!(
+        [
+            crate core {
+                #[lang(sized)] trait Sized {}
+                trait Foo {}
+
+                fn f<ty T>(T) -> () where T: ?Foo { trusted }
+            }
+        ]
+
+        [ "bounds are only permitted for the `Sized` trait", ]
+
+        expect_test::expect![[r#"
+            prove_where_clauses_well_formed([!ty_1 :? Foo, !ty_1 : Sized])
+
+            Caused by:
+                `?Foo` bounds are only permitted for the `Sized` trait"#]]
+    )
+}
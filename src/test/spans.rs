@@ -0,0 +1,78 @@
+use formality_core::test_util::ResultTestExt;
+
+#[test]
+fn error_reports_item_location() {
+    crate::test_program_with_spans(
+        "[
+    crate core {
+        trait Foo {}
+        trait Bar where Self: Foo {}
+
+        impl Foo for u8 {}
+        impl Bar for u8 {}
+        impl Bar for u32 {}
+    }
+]",
+    )
+    .assert_err(expect_test::expect![[r#"
+        in the item at 8:9
+
+        Caused by:
+            0: check_trait_impl(impl Bar for u32 { })
+            1: judgment `prove { goal: {Bar(u32)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Foo <ty> , trait Bar <ty> where {Foo(^ty0_0)}], [impl Foo(u8), impl Bar(u8), impl Bar(u32)], [], [], [], [], [], [], [], {Bar, Foo}, {}) }` failed, most specifically because:
+                 judgment `prove_wc { goal: Bar(u32), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                   the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                     judgment had no applicable rules: `prove_via { goal: Bar(u32), via: Foo(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`
+                 via prove > prove_wc_list ("some")
+                 judgment `prove_eq { a: u32, b: u8, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                   the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                     judgment had no applicable rules: `prove_normalize { p: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                 via prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove > prove_wc_list ("some") > prove_wc ("eq")
+                 judgment `prove_eq { a: u8, b: u32, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                   the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                     judgment had no applicable rules: `prove_normalize { p: u8, assumptions: {}, env: Env { variables: [], bias: Soundness } }`
+                 via prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("symmetric")"#]]);
+}
+
+#[test]
+fn error_reports_where_clause_location() {
+    crate::test_program_with_spans(
+        "[
+    crate core {
+        trait A<ty T> where T: B {}
+        trait B {}
+        trait Foo {}
+
+        impl<ty T> Foo for T
+        where
+            T: B,
+            u32: A<T>,
+            for<ty U> u32: A<U>,
+        {}
+    }
+]",
+    )
+    .assert_err(expect_test::expect![[r#"
+        in the item at 7:9
+
+        Caused by:
+            0: check_trait_impl(impl <ty> Foo for ^ty0_0 where ^ty0_0 : B, u32 : A <^ty0_0>, for <ty> u32 : A <^ty0_0> { })
+            1: prove_where_clauses_well_formed([!ty_1 : B, u32 : A <!ty_1>, for <ty> u32 : A <^ty0_0>])
+            2: in the where-clause at 11:13
+            3: judgment `prove { goal: {for <ty> @ WellFormedTraitRef(A(u32, ^ty0_0))}, assumptions: {A(u32, !ty_0), B(!ty_0), for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0], bias: Soundness }, decls: decls(222, [trait A <ty, ty> where {B(^ty0_1)}, trait B <ty> , trait Foo <ty> ], [impl <ty> Foo(^ty0_0) where {A(u32, ^ty0_0), B(^ty0_0), for <ty> A(u32, ^ty0_0)}], [], [], [], [], [], [], [], {A, B, Foo}, {}) }` failed, most specifically because:
+                 judgment `prove_via { goal: B(!ty_1), via: for <ty> A(u32, ^ty0_0), assumptions: {A(u32, !ty_0), B(!ty_0), for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
+                   the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
+                     judgment had no applicable rules: `prove_via { goal: B(!ty_1), via: A(u32, ?ty_2), assumptions: {A(u32, !ty_0), B(!ty_0), for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0, !ty_1, ?ty_2], bias: Soundness } }`
+                 via prove > prove_wc_list ("some") > prove_wc ("forall") > prove_wc ("trait well formed") > prove > prove_wc_list ("some") > prove_wc ("assumption - predicate")
+                 judgment `prove_wc { goal: !ty_0 = !ty_1, assumptions: {A(u32, !ty_0), B(!ty_0), for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
+                   the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                     judgment had no applicable rules: `prove_via { goal: !ty_0 = !ty_1, via: A(u32, !ty_0), assumptions: {A(u32, !ty_0), B(!ty_0), for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0, !ty_1], bias: Soundness } }`
+                   the rule "assumption - relation" failed at step #1 (src/file.rs:LL:CC) because
+                     judgment had no applicable rules: `prove_via { goal: !ty_0 = !ty_1, via: B(!ty_0), assumptions: {A(u32, !ty_0), B(!ty_0), for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0, !ty_1], bias: Soundness } }`
+                 via prove > prove_wc_list ("some") > prove_wc ("forall") > prove_wc ("trait well formed") > prove > prove_wc_list ("some") > prove_wc ("assumption - predicate") > prove_via ("predicate-congruence-axiom") > prove > prove_wc_list ("some")
+                 judgment `prove_via { goal: !ty_0 = !ty_1, via: for <ty> A(u32, ^ty0_0), assumptions: {A(u32, !ty_0), B(!ty_0), for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0, !ty_1], bias: Soundness } }` failed at the following rule(s):
+                   the rule "forall" failed at step #2 (src/file.rs:LL:CC) because
+                     judgment had no applicable rules: `prove_via { goal: !ty_0 = !ty_1, via: A(u32, ?ty_2), assumptions: {A(u32, !ty_0), B(!ty_0), for <ty> A(u32, ^ty0_0)}, env: Env { variables: [!ty_0, !ty_1, ?ty_2], bias: Soundness } }`
+                 via prove > prove_wc_list ("some") > prove_wc ("forall") > prove_wc ("trait well formed") > prove > prove_wc_list ("some") > prove_wc ("assumption - predicate") > prove_via ("predicate-congruence-axiom") > prove > prove_wc_list ("some") > prove_wc ("assumption - relation")
+                 ...and 7 less specific failure(s)"#]]);
+}
@@ -0,0 +1,102 @@
+#![allow(non_snake_case)]
+
+#[test]
+fn covariant_struct() {
+    crate::assert_ok!(
+        // `Ref<'static>` can be used where `Ref<'a>` is expected, as `Ref` is covariant in `'a`
+        //@check-pass
+        [
+            crate Foo {
+                struct Ref<lt a> { r: &a u32 }
+
+                // fn shorten<'a>(x: Ref<'static>) -> Ref<'a> { x }
+                fn shorten<lt a>(Ref<static>) -> Ref<a> = mir(locals_and_blocks(
+                    [(mut ret: Ref<a>), (shared x: Ref<static>)],
+                    [basic_block_decl(bb0, [((ret) = use(move((x))))], return)]
+                ));
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn covariant_recursive_enum() {
+    crate::assert_ok!(
+        // Variance is inferred through recursive references to the ADT itself
+        //@check-pass
+        [
+            crate Foo {
+                enum List<lt a> { Nil {}, Cons { head: &a u32, tail: &a List<a> } }
+
+                // fn shorten<'a>(x: List<'static>) -> List<'a> { x }
+                fn shorten<lt a>(List<static>) -> List<a> = mir(locals_and_blocks(
+                    [(mut ret: List<a>), (shared x: List<static>)],
+                    [basic_block_decl(bb0, [((ret) = use(move((x))))], return)]
+                ));
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
+
+#[test]
+fn invariant_struct() {
+    crate::assert_err!(
+        [
+            crate Foo {
+                struct RefMut<lt a> { r: &mut a &a u32 }
+
+                // fn shorten<'a>(x: RefMut<'static>) -> RefMut<'a> { x }
+                fn shorten<lt a>(RefMut<static>) -> RefMut<a> = mir(locals_and_blocks(
+                    [(mut ret: RefMut<a>), (shared x: RefMut<static>)],
+                    [basic_block_decl(bb0, [((ret) = use(move((x))))], return)]
+                ));
+            }
+        ]
+
+        [ "judgment `prove_sub { a: RefMut<static>, b: RefMut<!lt_0>", ]
+
+        expect_test::expect![[r#"
+            judgment `prove { goal: {RefMut<static> <: RefMut<static>, RefMut<static> <: RefMut<!lt_0>, RefMut<!lt_0> <: RefMut<!lt_0>, @ wf(RefMut<static>), @ wf(RefMut<!lt_0>)}, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness }, decls: decls(222, [], [], [], [], [], [], [adt RefMut <lt> fields [&mut ^lt0_0 &^lt0_0 u32] variances [=]], [fn shorten <lt> (RefMut<static>) -> RefMut<^lt0_0>], [], {}, {RefMut}) }` failed, most specifically because:
+              judgment `prove_eq { a: static, b: !lt_0, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_normalize { p: static, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("subtype") > prove_sub ("rigid") > prove > prove_wc_list ("some") > prove_wc ("eq")
+              judgment `prove_eq { a: !lt_0, b: static, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "normalize-l" failed at step #0 (src/file.rs:LL:CC) because
+                  judgment had no applicable rules: `prove_normalize { p: !lt_0, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }`
+              via prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("subtype") > prove_sub ("rigid") > prove > prove_wc_list ("some") > prove_wc ("eq") > prove_eq ("symmetric")
+              judgment `prove_sub { a: RefMut<static>, b: RefMut<!lt_0>, assumptions: {}, env: Env { variables: [!lt_0], bias: Soundness } }` failed at the following rule(s):
+                the rule "equate" failed at step #1 (src/file.rs:LL:CC) because
+                  condition evaluted to false: `!(is_rigid(&a) && is_rigid(&b))`
+              via prove > prove_wc_list ("some") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("subtype")"#]]
+    )
+}
+
+#[test]
+fn covariant_struct_in_impl_signature() {
+    crate::assert_ok!(
+        // An impl fn may return a `Ref<'static>` where the trait fn returns a `Ref<'a>`
+        //@check-pass
+        [
+            crate Foo {
+                struct Ref<lt a> { r: &a u32 }
+
+                trait Get {
+                    fn get<lt a>(Ref<a>) -> Ref<a>;
+                }
+
+                struct Bar {}
+
+                impl Get for Bar {
+                    fn get<lt a>(Ref<a>) -> Ref<static> { trusted }
+                }
+            }
+        ]
+
+        expect_test::expect!["()"]
+    )
+}
@@ -16,6 +16,6 @@ const MIRROR: &str = "[
 #[test]
 fn test_mirror_normalizes_u32_to_u32() {
     test_where_clause(MIRROR, "exists<ty T> {} => {<u32 as Mirror>::Assoc = T}").assert_ok(
-        expect_test::expect!["{Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => u32} }, Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => <u32 as Mirror>::Assoc} }}"],
+        expect_test::expect!["{Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => u32}, outlives: {} }, Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => <u32 as Mirror>::Assoc}, outlives: {} }}"],
     );
 }
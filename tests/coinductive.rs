@@ -0,0 +1,207 @@
+use a_mir_formality::{test_program_ok, test_where_clause};
+use formality_core::test_util::ResultTestExt;
+
+// `Magic` is implemented in terms of itself and extends `Copy`. Since `Magic` is
+// inductive, the cycle does not let us conclude `Foo: Magic` (and hence `Foo: Copy`).
+const MAGIC_COPY: &str = "[
+    crate core {
+        struct Foo {}
+        trait Copy {}
+        trait Magic where Self: Copy {}
+
+        impl<ty T> Magic for T where T: Magic {}
+    }
+]";
+
+#[test]
+fn magic_copy() {
+    test_program_ok(MAGIC_COPY).assert_ok(expect_test::expect!["()"]);
+
+    test_where_clause(MAGIC_COPY, "{} => { Magic(Foo) }").assert_err(expect_test::expect![[r#"
+        judgment `prove { goal: {Magic(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Magic <ty> where {Copy(^ty0_0)}], [impl <ty> Magic(^ty0_0) where {Magic(^ty0_0)}], [], [], [], [], [adt Foo ], [], [], {Copy, Magic}, {Foo}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {Magic(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: Magic(Foo), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                    judgment had no applicable rules: `prove_via { goal: Magic(Foo), via: Copy(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`"#]]);
+}
+
+const MAGIC_COPY_IMPL_FOR_ALL_COPY: &str = "[
+    crate core {
+        struct Foo {}
+        struct Vec<ty T> {}
+
+        trait Copy {}
+        trait Magic where Self: Copy {}
+
+        impl<ty T> Magic for T where T: Copy {}
+    }
+]";
+
+#[test]
+fn magic_copy_impl_for_all_copy() {
+    test_program_ok(MAGIC_COPY_IMPL_FOR_ALL_COPY).assert_ok(expect_test::expect!["()"]);
+
+    // no copy impl, so this doesn't hold
+    test_where_clause(MAGIC_COPY_IMPL_FOR_ALL_COPY, "{} => { Magic(Foo) }")
+        .assert_err(expect_test::expect![[r#"
+            judgment `prove { goal: {Magic(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Copy <ty> , trait Magic <ty> where {Copy(^ty0_0)}], [impl <ty> Magic(^ty0_0) where {Copy(^ty0_0)}], [], [], [], [], [adt Foo , adt Vec <ty>  variances [=]], [], [], {Copy, Magic}, {Foo, Vec}) }` failed at the following rule(s):
+              failed at (src/file.rs:LL:CC) because
+                judgment `prove_wc_list { goal: {Magic(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                    judgment `prove_wc { goal: Magic(Foo), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                      the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                        judgment had no applicable rules: `prove_via { goal: Magic(Foo), via: Copy(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`"#]]);
+
+    // ...but it does if we assume `Foo: Copy`
+    test_where_clause(MAGIC_COPY_IMPL_FOR_ALL_COPY, "{ Copy(Foo) } => { Magic(Foo) }")
+        .assert_ok(expect_test::expect!["{Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }}"]);
+}
+
+#[test]
+fn magic_vec_t() {
+    test_program_ok(
+        "[
+            crate core {
+                struct Foo {}
+                struct Vec<ty T> {}
+
+                trait Copy {}
+                trait Magic where Self: Copy {}
+
+                impl<ty T> Magic for Vec<T> where T: Magic {}
+                impl<ty T> Copy for Vec<T> where T: Magic {}
+            }
+        ]",
+    )
+    .assert_ok(expect_test::expect!["()"]);
+}
+
+// `Left` and `Right` each require the other as a supertrait, so proving either
+// one for `Foo` cycles back to itself. Since both are coinductive, the cycle succeeds.
+const MUTUAL_COINDUCTIVE_SUPERTRAITS: &str = "[
+    crate core {
+        struct Foo {}
+
+        coinductive trait Left where Self: Right {}
+        coinductive trait Right where Self: Left {}
+
+        impl Left for Foo {}
+        impl Right for Foo {}
+    }
+]";
+
+// `Left` and `Right` are each implemented for the types that implement the other,
+// so proving either one for `Foo` cycles back to itself through the impls.
+const MUTUAL_IMPLS: &str = "[
+    crate core {
+        struct Foo {}
+
+        trait Left {}
+        trait Right {}
+
+        impl<ty T> Left for T where T: Right {}
+        impl<ty T> Right for T where T: Left {}
+    }
+]";
+
+#[test]
+fn inductive_cycle_fails() {
+    test_program_ok(MUTUAL_IMPLS).assert_ok(expect_test::expect!["()"]);
+
+    // Only the cycle through the impls could prove `Foo: Left`. Failures due to cycles
+    // are left out of the summary in favor of the other rules that failed.
+    test_where_clause(MUTUAL_IMPLS, "{} => { Left(Foo) }").assert_err(expect_test::expect![[r#"
+        judgment `prove { goal: {Left(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Left <ty> , trait Right <ty> ], [impl <ty> Left(^ty0_0) where {Right(^ty0_0)}, impl <ty> Right(^ty0_0) where {Left(^ty0_0)}], [], [], [], [], [adt Foo ], [], [], {Left, Right}, {Foo}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {Left(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: Left(Foo), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "positive impl" failed at step #5 (src/file.rs:LL:CC) because
+                    judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => Foo}, outlives: {} }, goal: {Right(?ty_1)}, assumptions: {} }` failed at the following rule(s):
+                      the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                        judgment `prove { goal: {Right(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [trait Left <ty> , trait Right <ty> ], [impl <ty> Left(^ty0_0) where {Right(^ty0_0)}, impl <ty> Right(^ty0_0) where {Left(^ty0_0)}], [], [], [], [], [adt Foo ], [], [], {Left, Right}, {Foo}) }` failed at the following rule(s):
+                          failed at (src/file.rs:LL:CC) because
+                            judgment `prove_wc_list { goal: {Right(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment `prove_wc { goal: Right(Foo), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                                    expression evaluated to an empty collection: `decls.trait_invariants()`
+                  the rule "trait implied bound" failed at step #0 (src/file.rs:LL:CC) because
+                    expression evaluated to an empty collection: `decls.trait_invariants()`"#]]);
+}
+
+#[test]
+fn coinductive_cycle_succeeds() {
+    test_program_ok(MUTUAL_COINDUCTIVE_SUPERTRAITS).assert_ok(expect_test::expect!["()"]);
+
+    test_where_clause(MUTUAL_COINDUCTIVE_SUPERTRAITS, "{} => { Left(Foo) }")
+        .assert_ok(expect_test::expect!["{Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }}"]);
+
+    test_where_clause(
+        &MUTUAL_IMPLS.replace("trait", "coinductive trait"),
+        "{} => { Left(Foo) }",
+    )
+    .assert_ok(expect_test::expect!["{Constraints { env: Env { variables: [], bias: Soundness }, known_true: true, substitution: {}, outlives: {} }}"]);
+}
+
+// `Send` is coinductive but `Bar` is not, so the cycle between them is inductive:
+// it fails even though it starts (and ends) at a coinductive goal.
+const MIXED_CYCLE: &str = "[
+    crate core {
+        struct Foo {}
+
+        coinductive trait Send {}
+        trait Bar {}
+        trait Need where Self: Send {}
+
+        impl<ty T> Send for T where T: Bar {}
+        impl<ty T> Bar for T where T: Send {}
+    }
+]";
+
+#[test]
+fn mixed_cycle_fails() {
+    test_where_clause(MIXED_CYCLE, "{} => { Send(Foo) }").assert_err(expect_test::expect![[r#"
+        judgment `prove { goal: {Send(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [coinductive trait Send <ty> , trait Bar <ty> , trait Need <ty> where {Send(^ty0_0)}], [impl <ty> Send(^ty0_0) where {Bar(^ty0_0)}, impl <ty> Bar(^ty0_0) where {Send(^ty0_0)}], [], [], [], [], [adt Foo ], [], [], {Bar, Need, Send}, {Foo}) }` failed at the following rule(s):
+          failed at (src/file.rs:LL:CC) because
+            judgment `prove_wc_list { goal: {Send(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                judgment `prove_wc { goal: Send(Foo), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                  the rule "positive impl" failed at step #5 (src/file.rs:LL:CC) because
+                    judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => Foo}, outlives: {} }, goal: {Bar(?ty_1)}, assumptions: {} }` failed at the following rule(s):
+                      the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                        judgment `prove { goal: {Bar(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [coinductive trait Send <ty> , trait Bar <ty> , trait Need <ty> where {Send(^ty0_0)}], [impl <ty> Send(^ty0_0) where {Bar(^ty0_0)}, impl <ty> Bar(^ty0_0) where {Send(^ty0_0)}], [], [], [], [], [adt Foo ], [], [], {Bar, Need, Send}, {Foo}) }` failed at the following rule(s):
+                          failed at (src/file.rs:LL:CC) because
+                            judgment `prove_wc_list { goal: {Bar(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment `prove_wc { goal: Bar(Foo), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                                    judgment had no applicable rules: `prove_via { goal: Bar(Foo), via: Send(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`
+                  the rule "trait implied bound" failed at step #4 (src/file.rs:LL:CC) because
+                    judgment `prove_after { constraints: Constraints { env: Env { variables: [?ty_1], bias: Soundness }, known_true: true, substitution: {?ty_1 => Foo}, outlives: {} }, goal: {Need(?ty_1)}, assumptions: {} }` failed at the following rule(s):
+                      the rule "prove_after" failed at step #1 (src/file.rs:LL:CC) because
+                        judgment `prove { goal: {Need(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [coinductive trait Send <ty> , trait Bar <ty> , trait Need <ty> where {Send(^ty0_0)}], [impl <ty> Send(^ty0_0) where {Bar(^ty0_0)}, impl <ty> Bar(^ty0_0) where {Send(^ty0_0)}], [], [], [], [], [adt Foo ], [], [], {Bar, Need, Send}, {Foo}) }` failed at the following rule(s):
+                          failed at (src/file.rs:LL:CC) because
+                            judgment `prove_wc_list { goal: {Need(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                              the rule "some" failed at step #0 (src/file.rs:LL:CC) because
+                                judgment `prove_wc { goal: Need(Foo), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                                  the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                                    judgment had no applicable rules: `prove_via { goal: Need(Foo), via: Send(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`"#]]);
+
+    test_program_ok(&MIXED_CYCLE.replace("trait Need", "impl Need for Foo {} trait Need"))
+        .assert_err(expect_test::expect![[r#"
+            check_trait_impl(impl Need for Foo { })
+
+            Caused by:
+                judgment `prove { goal: {Need(Foo)}, assumptions: {}, env: Env { variables: [], bias: Soundness }, decls: decls(222, [coinductive trait Send <ty> , trait Bar <ty> , trait Need <ty> where {Send(^ty0_0)}], [impl Need(Foo), impl <ty> Send(^ty0_0) where {Bar(^ty0_0)}, impl <ty> Bar(^ty0_0) where {Send(^ty0_0)}], [], [], [], [], [adt Foo ], [], [], {Bar, Need, Send}, {Foo}) }` failed, most specifically because:
+                  judgment `prove_wc { goal: Need(Foo), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                      judgment had no applicable rules: `prove_via { goal: Need(Foo), via: Send(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`
+                  via prove > prove_wc_list ("some")
+                  judgment `prove_wc { goal: Bar(Foo), assumptions: {}, env: Env { variables: [], bias: Soundness } }` failed at the following rule(s):
+                    the rule "trait implied bound" failed at step #3 (src/file.rs:LL:CC) because
+                      judgment had no applicable rules: `prove_via { goal: Bar(Foo), via: Send(?ty_1), assumptions: {}, env: Env { variables: [?ty_1], bias: Soundness } }`
+                  via prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove_after ("prove_after") > prove > prove_wc_list ("some") > prove_wc ("positive impl") > prove_after ("prove_after") > prove > prove_wc_list ("some")"#]]);
+}
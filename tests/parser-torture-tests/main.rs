@@ -4,6 +4,7 @@ mod left_associative;
 mod none_associative;
 mod path;
 mod right_associative;
+mod spans;
 
 formality_core::declare_language! {
     mod ptt {
@@ -0,0 +1,36 @@
+use std::any::TypeId;
+
+use formality_core::parse::{Location, Span};
+
+use crate::path::{Id, Path};
+
+#[test]
+fn spans_of_recorded_types() {
+    let (path, spans): (Path, _) =
+        crate::ptt::try_term_with_spans("a.b[\n  c.a\n].c", &[TypeId::of::<Id>()]).unwrap();
+
+    assert_eq!(
+        spans.spans_of(&[Id::new("c"), Id::new("a")]),
+        vec![
+            Some(Span { start: 7, end: 8 }),
+            Some(Span { start: 9, end: 10 })
+        ]
+    );
+    assert_eq!(
+        spans.location(Span { start: 9, end: 10 }),
+        Location { line: 2, column: 5 }
+    );
+    assert_eq!(spans.spans_of(&[Id::new("z")]), vec![None]);
+
+    // Equal terms are matched in order, each with its own span.
+    assert_eq!(
+        spans.spans_of(&[Id::new("c"), Id::new("c")]),
+        vec![
+            Some(Span { start: 7, end: 8 }),
+            Some(Span { start: 13, end: 14 })
+        ]
+    );
+
+    // Only the requested types are recorded.
+    assert_eq!(spans.spans_of(&[path]), vec![None]);
+}